@@ -0,0 +1,127 @@
+//! Measures the error of `exp` and `tanh`, and of the `fast` functions, for every `f32` against
+//! the `f64` functions of the standard library, reporting the maximum error in ULP.
+//!
+//! Run with `cargo run --release --example f32_accuracy`, it takes several minutes. The documented
+//! bounds are asserted by the ignored tests, run with `cargo test --release -- --ignored`.
+
+use carbon_simd::*;
+
+const CHUNK: usize = 1 << 16;
+
+/// Returns the error of `got` in ULP of `expected`. Mismatched special values count as infinite.
+fn ulp_error(got: f32, expected: f64) -> f64 {
+    if expected.is_nan() || got.is_nan() {
+        return if expected.is_nan() && got.is_nan() {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    if (expected as f32).is_infinite() || got.is_infinite() {
+        return if got == expected as f32 {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+
+    let rounded = (expected as f32).abs();
+    let ulp = if rounded == f32::MAX {
+        f32::MAX - f32::from_bits(f32::MAX.to_bits() - 1)
+    } else {
+        f32::from_bits(rounded.to_bits() + 1) - rounded
+    };
+    // Below the smallest normal power of two the spacing is the smallest subnormal.
+    let ulp = (ulp as f64).max(f32::from_bits(1) as f64);
+    (got as f64 - expected).abs() / ulp
+}
+
+struct Report {
+    name: &'static str,
+    reference: fn(f64) -> f64,
+    apply: fn(&mut SimdMut<f32>),
+    max_error: f64,
+    worst_input: f32,
+}
+
+fn main() {
+    let mut reports = [
+        Report {
+            name: "exp",
+            reference: f64::exp,
+            apply: |x| x.exp(),
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "tanh",
+            reference: f64::tanh,
+            apply: |x| x.tanh(),
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "fast::exp",
+            reference: f64::exp,
+            apply: fast::exp,
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "fast::tanh",
+            reference: f64::tanh,
+            apply: fast::tanh,
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "fast::ln",
+            reference: f64::ln,
+            apply: fast::ln,
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "fast::sin",
+            reference: f64::sin,
+            apply: fast::sin,
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+        Report {
+            name: "fast::cos",
+            reference: f64::cos,
+            apply: fast::cos,
+            max_error: 0.0,
+            worst_input: 0.0,
+        },
+    ];
+
+    let mut inputs = vec![0.0f32; CHUNK];
+    let mut outputs = vec![0.0f32; CHUNK];
+    for chunk in 0..(1usize << 32) / CHUNK {
+        for (i, x) in inputs.iter_mut().enumerate() {
+            *x = f32::from_bits((chunk * CHUNK + i) as u32);
+        }
+
+        for report in &mut reports {
+            outputs.copy_from_slice(&inputs);
+            (report.apply)(&mut SimdMut::new(&mut outputs));
+
+            for (&x, &y) in inputs.iter().zip(&outputs) {
+                let error = ulp_error(y, (report.reference)(x as f64));
+                if error > report.max_error || error.is_nan() {
+                    report.max_error = error;
+                    report.worst_input = x;
+                }
+            }
+        }
+    }
+
+    for report in &reports {
+        println!(
+            "{}: max error {:.3} ULP at {:e}",
+            report.name, report.max_error, report.worst_input
+        );
+    }
+}
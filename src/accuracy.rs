@@ -0,0 +1,1138 @@
+//! Checks the documented error bounds of the floating point functions against the `f64` functions
+//! of the standard library, a double-double reference where those are not accurate enough, or
+//! tables of high precision values for the special functions.
+
+use crate::*;
+
+mod reference;
+
+/// Returns the error of `got` in ULP of `expected`. Mismatched special values count as infinite.
+fn ulp_error_f32(got: f32, expected: f64) -> f64 {
+    if expected.is_nan() || got.is_nan() {
+        return if expected.is_nan() && got.is_nan() {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    if (expected as f32).is_infinite() || got.is_infinite() {
+        return if got == expected as f32 {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+
+    let rounded = (expected as f32).abs();
+    let ulp = if rounded == f32::MAX {
+        f32::MAX - f32::from_bits(f32::MAX.to_bits() - 1)
+    } else {
+        f32::from_bits(rounded.to_bits() + 1) - rounded
+    };
+    // Below the smallest normal power of two the spacing is the smallest subnormal.
+    let ulp = (ulp as f64).max(f32::from_bits(1) as f64);
+    (got as f64 - expected).abs() / ulp
+}
+
+/// Returns the error of `got` in ULP of `expected`. Mismatched special values count as infinite.
+///
+/// The reference is itself rounded to `f64`, so this counts the distance to the rounded result
+/// rather than the exact one.
+fn ulp_error_f64(got: f64, expected: f64) -> f64 {
+    if expected.is_nan() || got.is_nan() {
+        return if expected.is_nan() && got.is_nan() {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    if expected.is_infinite() || got.is_infinite() {
+        return if got == expected { 0.0 } else { f64::INFINITY };
+    }
+
+    let rounded = expected.abs();
+    let ulp = if rounded == f64::MAX {
+        f64::MAX - f64::from_bits(f64::MAX.to_bits() - 1)
+    } else {
+        f64::from_bits(rounded.to_bits() + 1) - rounded
+    };
+    (got - expected).abs() / ulp
+}
+
+/// Returns tanh of `x` computed in double-double, since [`f64::tanh`] can be more than 1 ULP off.
+fn tanh_reference(x: f64) -> f64 {
+    let a = x.abs();
+    if x.is_nan() || !(2f64.powi(-27)..=20.0).contains(&a) {
+        // tanh(x) rounds to x below 2^-27 and to 1 above 20.
+        return if a > 20.0 { x.signum() } else { x };
+    }
+
+    // e^2a = (e^(2a / 2^10))^(2^10), with the Taylor series of the small power in Horner form.
+    let r = 2.0 * a / 1024.0;
+    let mut e = (1.0, 0.0);
+    for i in (1..=16).rev() {
+        let (hi, lo) = two_prod(e.0, r);
+        let (hi, lo) = fast_two_sum(hi, lo + e.1 * r);
+        let q = hi / i as f64;
+        let q_lo = (q.mul_add(-(i as f64), hi) + lo) / i as f64;
+        e = fast_two_sum(1.0, q);
+        e = fast_two_sum(e.0, e.1 + q_lo);
+    }
+    for _ in 0..10 {
+        let (hi, lo) = two_prod(e.0, e.0);
+        e = fast_two_sum(hi, lo + 2.0 * e.0 * e.1);
+    }
+
+    // (e^2a - 1) / (e^2a + 1), with one correction of the quotient.
+    let (num, num_lo) = two_sum(e.0, -1.0);
+    let (num, num_lo) = fast_two_sum(num, num_lo + e.1);
+    let (den, den_lo) = two_sum(e.0, 1.0);
+    let (den, den_lo) = fast_two_sum(den, den_lo + e.1);
+    let q = num / den;
+    let (p, p_lo) = two_prod(q, den);
+    let residual = num - p - p_lo + num_lo - q * den_lo;
+    (q + residual / den).copysign(x)
+}
+
+/// Returns `a + b` and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    (sum, (a - (sum - b_virtual)) + (b - b_virtual))
+}
+
+/// Returns `a + b` and its rounding error, for `|a| >= |b|`.
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    (sum, b - (sum - a))
+}
+
+/// Returns `a * b` and its rounding error.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// Returns `count` inputs spread evenly over `[start, end]`, followed by `count` inputs with
+/// pseudorandom bit patterns.
+fn inputs_f32(start: f32, end: f32, count: usize) -> Vec<f32> {
+    let step = (end as f64 - start as f64) / (count - 1) as f64;
+    let even = (0..count).map(|i| (start as f64 + i as f64 * step) as f32);
+    let random = random_bits(count).map(|bits| f32::from_bits((bits >> 32) as u32));
+    even.chain(random).collect()
+}
+
+/// Returns `count` inputs spread evenly over `[start, end]`, followed by `count` inputs with
+/// pseudorandom bit patterns.
+fn inputs_f64(start: f64, end: f64, count: usize) -> Vec<f64> {
+    let step = (end - start) / (count - 1) as f64;
+    let even = (0..count).map(|i| start + i as f64 * step);
+    let random = random_bits(count).map(f64::from_bits);
+    even.chain(random).collect()
+}
+
+/// Returns `count` pseudorandom `u64`s from a fixed seed.
+fn random_bits(count: usize) -> impl Iterator<Item = u64> {
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    (0..count).map(move |_| {
+        // xorshift64*
+        state ^= state >> 12;
+        state ^= state << 25;
+        state ^= state >> 27;
+        state.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    })
+}
+
+/// Returns the largest error of `f` over `inputs` in ULP, and the input it occurs at.
+fn max_error_f32(
+    f: fn(&mut SimdMut<f32>),
+    reference: fn(f64) -> f64,
+    inputs: &[f32],
+) -> (f64, f32) {
+    let mut outputs = inputs.to_vec();
+    f(&mut SimdMut::new(&mut outputs));
+
+    let mut worst = (0.0, 0.0);
+    for (&x, &y) in inputs.iter().zip(&outputs) {
+        let error = ulp_error_f32(y, reference(x as f64));
+        if error > worst.0 {
+            worst = (error, x);
+        }
+    }
+    worst
+}
+
+/// Returns the largest error of `f` over `inputs` in ULP, and the input it occurs at.
+fn max_error_f64(
+    f: fn(&mut SimdMut<f64>),
+    reference: fn(f64) -> f64,
+    inputs: &[f64],
+) -> (f64, f64) {
+    let mut outputs = inputs.to_vec();
+    f(&mut SimdMut::new(&mut outputs));
+
+    let mut worst = (0.0, 0.0);
+    for (&x, &y) in inputs.iter().zip(&outputs) {
+        let error = ulp_error_f64(y, reference(x));
+        if error > worst.0 {
+            worst = (error, x);
+        }
+    }
+    worst
+}
+
+/// Asserts that `f` is within `bound` ULP of `reference` over `inputs`.
+fn check_f32(
+    name: &str,
+    f: fn(&mut SimdMut<f32>),
+    reference: fn(f64) -> f64,
+    inputs: &[f32],
+    bound: f64,
+) {
+    let (error, x) = max_error_f32(f, reference, inputs);
+    assert!(
+        error <= bound,
+        "{name}: {error} ULP at {x:e}, above {bound} ULP"
+    );
+}
+
+/// Asserts that `f` is within `bound` ULP of `reference` over `inputs`.
+fn check_f64(
+    name: &str,
+    f: fn(&mut SimdMut<f64>),
+    reference: fn(f64) -> f64,
+    inputs: &[f64],
+    bound: f64,
+) {
+    let (error, x) = max_error_f64(f, reference, inputs);
+    assert!(
+        error <= bound,
+        "{name}: {error} ULP at {x:e}, above {bound} ULP"
+    );
+}
+
+/// Asserts that `f` is within `bound` ULP of `reference` over the pairs of `x` and `y`.
+fn check2_f32(
+    name: &str,
+    f: fn(&mut SimdMut<f32>, &SimdRef<f32>),
+    reference: fn(f64, f64) -> f64,
+    x: &[f32],
+    y: &[f32],
+    bound: f64,
+) {
+    let mut outputs = x.to_vec();
+    f(&mut SimdMut::new(&mut outputs), &SimdRef::new(y));
+
+    let mut worst = (0.0, 0.0, 0.0);
+    for ((&x, &y), &z) in x.iter().zip(y).zip(&outputs) {
+        let error = ulp_error_f32(z, reference(x as f64, y as f64));
+        if error > worst.0 {
+            worst = (error, x, y);
+        }
+    }
+    let (error, x, y) = worst;
+    assert!(
+        error <= bound,
+        "{name}: {error} ULP at ({x:e}, {y:e}), above {bound} ULP"
+    );
+}
+
+/// Asserts that `f` is within `bound` ULP of `reference` over the pairs of `x` and `y`.
+fn check2_f64(
+    name: &str,
+    f: fn(&mut SimdMut<f64>, &SimdRef<f64>),
+    reference: fn(f64, f64) -> f64,
+    x: &[f64],
+    y: &[f64],
+    bound: f64,
+) {
+    let mut outputs = x.to_vec();
+    f(&mut SimdMut::new(&mut outputs), &SimdRef::new(y));
+
+    let mut worst = (0.0, 0.0, 0.0);
+    for ((&x, &y), &z) in x.iter().zip(y).zip(&outputs) {
+        let error = ulp_error_f64(z, reference(x, y));
+        if error > worst.0 {
+            worst = (error, x, y);
+        }
+    }
+    let (error, x, y) = worst;
+    assert!(
+        error <= bound,
+        "{name}: {error} ULP at ({x:e}, {y:e}), above {bound} ULP"
+    );
+}
+
+/// Asserts that `f` is within `bound` ULP of `reference` for every `f32`.
+fn check_exhaustive_f32(
+    name: &str,
+    f: fn(&mut SimdMut<f32>),
+    reference: fn(f64) -> f64,
+    bound: f64,
+) {
+    const CHUNK: u32 = 1 << 16;
+
+    let mut inputs = vec![0.0f32; CHUNK as usize];
+    let mut worst = (0.0, 0.0);
+    for chunk in 0..=u32::MAX / CHUNK {
+        for (i, x) in inputs.iter_mut().enumerate() {
+            *x = f32::from_bits(chunk * CHUNK + i as u32);
+        }
+
+        let (error, x) = max_error_f32(f, reference, &inputs);
+        if error > worst.0 {
+            worst = (error, x);
+        }
+    }
+
+    assert!(
+        worst.0 <= bound,
+        "{name}: {} ULP at {:e}, above {bound} ULP",
+        worst.0,
+        worst.1
+    );
+}
+
+#[test]
+#[ignore = "checks every f32, run with `cargo test --release -- --ignored`"]
+fn exhaustive_f32_exp() {
+    check_exhaustive_f32("exp", |x| x.exp(), f64::exp, 1.0);
+}
+
+#[test]
+#[ignore = "checks every f32, run with `cargo test --release -- --ignored`"]
+fn exhaustive_f32_tanh() {
+    check_exhaustive_f32("tanh", |x| x.tanh(), f64::tanh, 1.0);
+}
+
+const COUNT: usize = 1 << 18;
+
+#[test]
+fn precise_f32() {
+    let exp = inputs_f32(-104.0, 89.0, COUNT);
+    let tanh = inputs_f32(-10.0, 10.0, COUNT);
+    let ln = inputs_f32(0.0, 1000.0, COUNT);
+    let ln_1p = inputs_f32(-1.0, 1.0, COUNT);
+    let trig = inputs_f32(-10000.0, 10000.0, COUNT);
+    check_f32("exp", precise::exp, f64::exp, &exp, 1.0);
+    check_f32("tanh", precise::tanh, f64::tanh, &tanh, 1.0);
+    check_f32("ln", precise::ln, f64::ln, &ln, 1.0);
+    check_f32("log2", |x| x.log2(), f64::log2, &ln, 1.0);
+    check_f32("log10", |x| x.log10(), f64::log10, &ln, 1.0);
+    check_f32("ln_1p", |x| x.ln_1p(), f64::ln_1p, &ln_1p, 1.0);
+    check_f32("sin", precise::sin, f64::sin, &trig, 1.0);
+    check_f32("cos", precise::cos, f64::cos, &trig, 1.0);
+}
+
+/// Returns `1 / x`.
+fn recip_reference(x: f64) -> f64 {
+    1.0 / x
+}
+
+/// Returns `1 / sqrt(x)` with one Newton-Raphson step in double-double, since the two roundings
+/// of `1.0 / x.sqrt()` can be more than 1 ULP off.
+fn rsqrt_reference(x: f64) -> f64 {
+    // Scales extreme inputs so that r^2 neither overflows nor underflows.
+    let scale = 2f64.powi(1000);
+    if x < 2f64.powi(-900) && x > 0.0 {
+        return rsqrt_reference(x * scale) * 2f64.powi(500);
+    }
+    if x > 2f64.powi(900) && x.is_finite() {
+        return rsqrt_reference(x / scale) / 2f64.powi(500);
+    }
+
+    let r = 1.0 / x.sqrt();
+    if !r.is_normal() {
+        return r;
+    }
+    // r + r * (1 - x * r^2) / 2, with x * r^2 - 1 kept exact up to its rounding.
+    let (square, square_lo) = two_prod(r, r);
+    let error = x.mul_add(square, -1.0) + x * square_lo;
+    r - r * error * 0.5
+}
+
+#[test]
+fn reciprocals_f32() {
+    // The approximations give infinity for subnormal inputs and zero for subnormal results, so
+    // the sweep stays where both are normal.
+    let inputs: Vec<f32> = inputs_f32(-1000.0, 1000.0, COUNT)
+        .into_iter()
+        .filter(|x| x.is_normal() && x.recip().is_normal())
+        .collect();
+    let recip: fn(f64) -> f64 = recip_reference;
+    let rsqrt: fn(f64) -> f64 = rsqrt_reference;
+    check_f32("recip", |x| x.recip(), recip, &inputs, 0.5);
+    check_f32("rsqrt", |x| x.rsqrt(), rsqrt, &inputs, 1.0);
+    check_f32(
+        "recip_approx(1)",
+        |x| x.recip_approx(1),
+        recip,
+        &inputs,
+        2.0,
+    );
+    check_f32(
+        "recip_approx(2)",
+        |x| x.recip_approx(2),
+        recip,
+        &inputs,
+        1.0,
+    );
+    check_f32(
+        "rsqrt_approx(1)",
+        |x| x.rsqrt_approx(1),
+        rsqrt,
+        &inputs,
+        4.0,
+    );
+    check_f32(
+        "rsqrt_approx(2)",
+        |x| x.rsqrt_approx(2),
+        rsqrt,
+        &inputs,
+        2.0,
+    );
+
+    // The bare estimates are only bounded in relative error.
+    for (name, f, reference) in [
+        (
+            "recip_approx(0)",
+            (|x| x.recip_approx(0)) as fn(&mut SimdMut<f32>),
+            recip,
+        ),
+        ("rsqrt_approx(0)", |x| x.rsqrt_approx(0), rsqrt),
+    ] {
+        let mut outputs = inputs.clone();
+        f(&mut SimdMut::new(&mut outputs));
+        for (&x, &y) in inputs.iter().zip(&outputs) {
+            let expected = reference(x as f64);
+            if expected.is_nan() {
+                assert!(y.is_nan(), "{name}({x:e}) = {y:e}");
+                continue;
+            }
+            let error = (y as f64 - expected).abs() / expected.abs();
+            assert!(
+                error < 2f64.powi(-11),
+                "{name}({x:e}): relative error {error:e}"
+            );
+        }
+    }
+
+    // Zeros, infinities, NaN and the NaN of negative square roots are exact whatever the number
+    // of steps.
+    let special = [
+        0.0f32,
+        -0.0,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        -4.0,
+    ];
+    let recip: fn(f32) -> f32 = |x| 1.0 / x;
+    let rsqrt: fn(f32) -> f32 = |x| 1.0 / x.sqrt();
+    check_special_f32("recip", |x| x.recip(), recip, &special);
+    check_special_f32("rsqrt", |x| x.rsqrt(), rsqrt, &special);
+    check_special_f32(
+        "recip_approx(0)",
+        |x| x.recip_approx(0),
+        recip,
+        &special[..5],
+    );
+    check_special_f32(
+        "recip_approx(1)",
+        |x| x.recip_approx(1),
+        recip,
+        &special[..5],
+    );
+    check_special_f32(
+        "recip_approx(2)",
+        |x| x.recip_approx(2),
+        recip,
+        &special[..5],
+    );
+    check_special_f32("rsqrt_approx(0)", |x| x.rsqrt_approx(0), rsqrt, &special);
+    check_special_f32("rsqrt_approx(1)", |x| x.rsqrt_approx(1), rsqrt, &special);
+    check_special_f32("rsqrt_approx(2)", |x| x.rsqrt_approx(2), rsqrt, &special);
+
+    // Subnormal inputs give infinity and subnormal results give zero.
+    for steps in 0..3 {
+        let mut values = [1e-40f32, f32::MAX];
+        SimdMut::new(&mut values).recip_approx(steps);
+        assert_eq!(values, [f32::INFINITY, 0.0], "recip_approx({steps})");
+        let mut values = [1e-40f32];
+        SimdMut::new(&mut values).rsqrt_approx(steps);
+        assert_eq!(values, [f32::INFINITY], "rsqrt_approx({steps})");
+    }
+
+    // The precise functions handle subnormals.
+    let subnormal = [1e-40f32, -1e-40, f32::from_bits(1)];
+    check_f32("recip", |x| x.recip(), recip_reference, &subnormal, 0.5);
+    check_f32("rsqrt", |x| x.rsqrt(), rsqrt_reference, &subnormal, 1.0);
+}
+
+#[test]
+fn reciprocals_f64() {
+    // `f64` has no estimate instructions, so the approximations are the precise functions.
+    let inputs = inputs_f64(-1000.0, 1000.0, COUNT);
+    check_f64("recip", |x| x.recip(), recip_reference, &inputs, 0.0);
+    check_f64("rsqrt", |x| x.rsqrt(), rsqrt_reference, &inputs, 1.0);
+    check_f64(
+        "recip_approx(0)",
+        |x| x.recip_approx(0),
+        recip_reference,
+        &inputs,
+        0.0,
+    );
+    check_f64(
+        "recip_approx(1)",
+        |x| x.recip_approx(1),
+        recip_reference,
+        &inputs,
+        0.0,
+    );
+    check_f64(
+        "rsqrt_approx(0)",
+        |x| x.rsqrt_approx(0),
+        rsqrt_reference,
+        &inputs,
+        1.0,
+    );
+    check_f64(
+        "rsqrt_approx(2)",
+        |x| x.rsqrt_approx(2),
+        rsqrt_reference,
+        &inputs,
+        1.0,
+    );
+
+    let special = [
+        0.0f64,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -4.0,
+    ];
+    let recip: fn(f64) -> f64 = |x| 1.0 / x;
+    let rsqrt: fn(f64) -> f64 = |x| 1.0 / x.sqrt();
+    check_special_f64("recip", |x| x.recip(), recip, &special);
+    check_special_f64("rsqrt", |x| x.rsqrt(), rsqrt, &special);
+    check_special_f64("recip_approx", |x| x.recip_approx(1), recip, &special);
+    check_special_f64("rsqrt_approx", |x| x.rsqrt_approx(1), rsqrt, &special);
+
+    let subnormal = [1e-310f64, -1e-310, f64::from_bits(1), f64::MAX];
+    check_f64("recip", |x| x.recip(), recip_reference, &subnormal, 0.0);
+    check_f64("rsqrt", |x| x.rsqrt(), rsqrt_reference, &subnormal, 1.0);
+}
+
+/// Returns the cube root of `x` with one Newton-Raphson step in double-double, since
+/// [`f64::cbrt`] can be more than 1 ULP off.
+fn cbrt_reference(x: f64) -> f64 {
+    // Scales extreme inputs by powers of 2 with integral cube roots, so that r^3 stays normal.
+    let a = x.abs();
+    if a != 0.0 && a < 2f64.powi(-900) {
+        return cbrt_reference(x * 2f64.powi(999)) / 2f64.powi(333);
+    }
+    if a > 2f64.powi(900) && a.is_finite() {
+        return cbrt_reference(x / 2f64.powi(999)) * 2f64.powi(333);
+    }
+
+    let r = x.cbrt();
+    if r == 0.0 || !r.is_finite() {
+        return r;
+    }
+    // r - (r^3 - x) / (3 r^2), with r^3 - x kept exact up to its rounding.
+    let (square, square_lo) = two_prod(r, r);
+    let (cube, cube_lo) = two_prod(square, r);
+    let error = (cube - x) + cube_lo.mul_add(1.0, square_lo * r);
+    r - error / (3.0 * square)
+}
+
+/// Returns `sqrt(x^2 + y^2)` with one correction of the square root in double-double, since
+/// [`f64::hypot`] can be more than 1 ULP off.
+fn hypot_reference(x: f64, y: f64) -> f64 {
+    if x.is_infinite() || y.is_infinite() {
+        return f64::INFINITY;
+    }
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+    let (a, b) = (x.abs().max(y.abs()), x.abs().min(y.abs()));
+    if a == 0.0 {
+        return 0.0;
+    }
+
+    // Scales the larger side to [1, 2), where the smaller square can only underflow if it is
+    // negligible.
+    let exponent = a.log2().floor() as i32;
+    let scale = |x: f64, n: i32| x * 2f64.powi(n / 2) * 2f64.powi(n - n / 2);
+    let (a, b) = (scale(a, -exponent), scale(b, -exponent));
+
+    let (a2, a2_lo) = two_prod(a, a);
+    let (b2, b2_lo) = two_prod(b, b);
+    let (sum, sum_lo) = two_sum(a2, b2);
+    let sum_lo = sum_lo + a2_lo + b2_lo;
+    let h = sum.sqrt();
+    let (h2, h2_lo) = two_prod(h, h);
+    let h = h + ((sum - h2) - h2_lo + sum_lo) / (2.0 * h);
+    scale(h, exponent)
+}
+
+#[test]
+fn roots() {
+    let cbrt = inputs_f32(-1000.0, 1000.0, COUNT);
+    check_f32("cbrt", |x| x.cbrt(), cbrt_reference, &cbrt, 1.0);
+    let cbrt = inputs_f64(-1000.0, 1000.0, COUNT);
+    check_f64("cbrt", |x| x.cbrt(), cbrt_reference, &cbrt, 1.0);
+    let special = [
+        0.0,
+        -0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+        -8.0,
+        1e-310,
+    ];
+    check_special_f64("cbrt", |x| x.cbrt(), cbrt_reference, &special);
+    let special = special.map(|x| x as f32);
+    check_special_f32("cbrt", |x| x.cbrt(), f32::cbrt, &special);
+
+    // Rotating one operand pairs evenly spread with pseudorandom inputs, and each with a
+    // different half of its own kind.
+    let x = inputs_f32(-1000.0, 1000.0, COUNT);
+    let mut y = inputs_f32(-10.0, 10.0, COUNT);
+    y.rotate_left(COUNT / 2);
+    check2_f32("hypot", |x, y| x.hypot(y), hypot_reference, &x, &y, 1.0);
+    let x = inputs_f64(-1000.0, 1000.0, COUNT);
+    let mut y = inputs_f64(-10.0, 10.0, COUNT);
+    y.rotate_left(COUNT / 2);
+    check2_f64("hypot", |x, y| x.hypot(y), hypot_reference, &x, &y, 1.0);
+
+    // Sides whose squares overflow or underflow, and infinities that win over NaN.
+    let x = [
+        1e300,
+        -1e300,
+        f64::MAX,
+        f64::MAX,
+        1e-300,
+        5e-324,
+        3e-320,
+        1e200,
+        f64::INFINITY,
+        f64::NAN,
+        0.0,
+    ];
+    let y = [
+        1e300,
+        1e-300,
+        f64::MAX,
+        1.0,
+        -1e-300,
+        5e-324,
+        4e-320,
+        1e-200,
+        f64::NAN,
+        f64::NEG_INFINITY,
+        -0.0,
+    ];
+    check2_f64("hypot", |x, y| x.hypot(y), hypot_reference, &x, &y, 1.0);
+    let x = [
+        3e38f32,
+        f32::MAX,
+        f32::MAX,
+        2e19,
+        1e-40,
+        1e-45,
+        f32::INFINITY,
+        f32::NAN,
+    ];
+    let y = [
+        1e38f32,
+        f32::MAX,
+        1.0,
+        -2e19,
+        -1e-40,
+        1e-45,
+        f32::NAN,
+        f32::NEG_INFINITY,
+    ];
+    check2_f32("hypot", |x, y| x.hypot(y), hypot_reference, &x, &y, 1.0);
+}
+
+/// Asserts that `f` is within `bound` ULP of each `(x, f(x))` pair of `table`.
+fn check_table_f32(name: &str, f: fn(&mut SimdMut<f32>), table: &[(f64, f64)], bound: f64) {
+    let mut outputs: Vec<f32> = table.iter().map(|&(x, _)| x as f32).collect();
+    f(&mut SimdMut::new(&mut outputs));
+    for (&(x, expected), &y) in table.iter().zip(&outputs) {
+        let error = ulp_error_f32(y, expected);
+        assert!(
+            error <= bound,
+            "{name}({x:e}) = {y:e}, {error} ULP from {expected:e}, above {bound} ULP"
+        );
+    }
+}
+
+/// Asserts that `f` is within `bound` ULP of each `(x, f(x))` pair of `table`.
+fn check_table_f64(name: &str, f: fn(&mut SimdMut<f64>), table: &[(f64, f64)], bound: f64) {
+    let mut outputs: Vec<f64> = table.iter().map(|&(x, _)| x).collect();
+    f(&mut SimdMut::new(&mut outputs));
+    for (&(x, expected), &y) in table.iter().zip(&outputs) {
+        let error = ulp_error_f64(y, expected);
+        assert!(
+            error <= bound,
+            "{name}({x:e}) = {y:e}, {error} ULP from {expected:e}, above {bound} ULP"
+        );
+    }
+}
+
+// The tables cover the interval boundaries of the approximations, the underflow of erfc, the
+// overflow of lgamma and tgamma, negative non-integers and both sides of the poles.
+
+#[test]
+fn special_functions_f32() {
+    use reference::*;
+    check_table_f32("erf", |x| x.erf(), &ERF_F32, 1.0);
+    check_table_f32("erfc", |x| x.erfc(), &ERFC_F32, 1.0);
+    check_table_f32("lgamma", |x| x.lgamma(), &LGAMMA_F32, 1.0);
+    check_table_f32("tgamma", |x| x.tgamma(), &TGAMMA_F32, 1.0);
+    check_table_f32("tgamma", |x| x.tgamma(), &TGAMMA_NEGATIVE_F32, 2.0);
+}
+
+#[test]
+fn special_functions_f64() {
+    use reference::*;
+    check_table_f64("erf", |x| x.erf(), &ERF_F64, 1.0);
+    check_table_f64("erfc", |x| x.erfc(), &ERFC_F64, 1.0);
+    check_table_f64("lgamma", |x| x.lgamma(), &LGAMMA_F64, 1.0);
+    check_table_f64("tgamma", |x| x.tgamma(), &TGAMMA_F64, 1.0);
+    check_table_f64("tgamma", |x| x.tgamma(), &TGAMMA_NEGATIVE_F64, 2.0);
+}
+
+#[test]
+fn exp_variants_f32() {
+    let exp2 = inputs_f32(-150.0, 128.0, COUNT);
+    let exp10 = inputs_f32(-46.0, 39.0, COUNT);
+    let expm1 = inputs_f32(-104.0, 89.0, COUNT);
+    let small = inputs_f32(-1.0, 1.0, COUNT);
+    check_f32("exp2", |x| x.exp2(), f64::exp2, &exp2, 1.0);
+    check_f32("exp10", |x| x.exp10(), |x| 10f64.powf(x), &exp10, 1.0);
+    check_f32("expm1", |x| x.expm1(), f64::exp_m1, &expm1, 1.0);
+    check_f32("expm1", |x| x.expm1(), f64::exp_m1, &small, 1.0);
+}
+
+#[test]
+fn powf_f32() {
+    // Rotating one operand pairs evenly spread with pseudorandom inputs, and each with a
+    // different half of its own kind.
+    let x = inputs_f32(0.0, 100.0, COUNT);
+    let mut y = inputs_f32(-20.0, 20.0, COUNT);
+    y.rotate_left(COUNT / 2);
+    check2_f32("powf", |x, y| x.powf(y), f64::powf, &x, &y, 1.0);
+
+    let x = inputs_f32(-100.0, 100.0, COUNT);
+    check_f32(
+        "powf_scalar",
+        |x| x.powf_scalar(0.5),
+        |x| x.powf(0.5),
+        &x,
+        1.0,
+    );
+    check_f32(
+        "powf_scalar",
+        |x| x.powf_scalar(-2.5),
+        |x| x.powf(-2.5),
+        &x,
+        1.0,
+    );
+    check_f32(
+        "powf_scalar",
+        |x| x.powf_scalar(3.0),
+        |x| x.powf(3.0),
+        &x,
+        1.0,
+    );
+    check_f32(
+        "powf_scalar",
+        |x| x.powf_scalar(-7.0),
+        |x| x.powf(-7.0),
+        &x,
+        1.0,
+    );
+}
+
+#[test]
+fn inverse_trig_f32() {
+    let unit = inputs_f32(-1.0, 1.0, COUNT);
+    let atan = inputs_f32(-100.0, 100.0, COUNT);
+    check_f32("asin", |x| x.asin(), f64::asin, &unit, 1.0);
+    check_f32("acos", |x| x.acos(), f64::acos, &unit, 1.0);
+    check_f32("atan", |x| x.atan(), f64::atan, &atan, 1.0);
+
+    // Rotating one operand pairs evenly spread with pseudorandom inputs, and each with a
+    // different half of its own kind.
+    let y = inputs_f32(-100.0, 100.0, COUNT);
+    let mut x = inputs_f32(-30.0, 70.0, COUNT);
+    x.rotate_left(COUNT / 2);
+    check2_f32("atan2", |y, x| y.atan2(x), f64::atan2, &y, &x, 2.0);
+}
+
+#[test]
+fn precise_f64() {
+    let exp = inputs_f64(-745.0, 709.0, COUNT);
+    let tanh = inputs_f64(-20.0, 20.0, COUNT);
+    let ln = inputs_f64(0.0, 1000.0, COUNT);
+    let ln_1p = inputs_f64(-1.0, 1.0, COUNT);
+    let trig = inputs_f64(-10000.0, 10000.0, COUNT);
+    check_f64("exp", precise::exp, f64::exp, &exp, 1.0);
+    check_f64("tanh", precise::tanh, tanh_reference, &tanh, 1.0);
+    check_f64("ln", precise::ln, f64::ln, &ln, 1.0);
+    check_f64("log2", |x| x.log2(), f64::log2, &ln, 1.0);
+    check_f64("log10", |x| x.log10(), f64::log10, &ln, 1.0);
+    check_f64("ln_1p", |x| x.ln_1p(), f64::ln_1p, &ln_1p, 1.0);
+    check_f64("sin", precise::sin, f64::sin, &trig, 1.0);
+    check_f64("cos", precise::cos, f64::cos, &trig, 1.0);
+}
+
+#[test]
+fn exp_variants_f64() {
+    let exp2 = inputs_f64(-1075.0, 1024.0, COUNT);
+    let exp10 = inputs_f64(-324.0, 309.0, COUNT);
+    let expm1 = inputs_f64(-745.0, 709.0, COUNT);
+    let small = inputs_f64(-1.0, 1.0, COUNT);
+    check_f64("exp2", |x| x.exp2(), f64::exp2, &exp2, 1.0);
+    check_f64("exp10", |x| x.exp10(), |x| 10f64.powf(x), &exp10, 1.0);
+    check_f64("expm1", |x| x.expm1(), f64::exp_m1, &expm1, 1.0);
+    check_f64("expm1", |x| x.expm1(), f64::exp_m1, &small, 1.0);
+}
+
+#[test]
+fn powf_f64() {
+    // Rotating one operand pairs evenly spread with pseudorandom inputs, and each with a
+    // different half of its own kind.
+    let x = inputs_f64(0.0, 100.0, COUNT);
+    let mut y = inputs_f64(-20.0, 20.0, COUNT);
+    y.rotate_left(COUNT / 2);
+    check2_f64("powf", |x, y| x.powf(y), f64::powf, &x, &y, 1.0);
+
+    let x = inputs_f64(-100.0, 100.0, COUNT);
+    check_f64(
+        "powf_scalar",
+        |x| x.powf_scalar(0.5),
+        |x| x.powf(0.5),
+        &x,
+        1.0,
+    );
+    check_f64(
+        "powf_scalar",
+        |x| x.powf_scalar(-2.5),
+        |x| x.powf(-2.5),
+        &x,
+        1.0,
+    );
+    check_f64(
+        "powf_scalar",
+        |x| x.powf_scalar(3.0),
+        |x| x.powf(3.0),
+        &x,
+        1.0,
+    );
+    check_f64(
+        "powf_scalar",
+        |x| x.powf_scalar(-7.0),
+        |x| x.powf(-7.0),
+        &x,
+        1.0,
+    );
+}
+
+#[test]
+fn inverse_trig_f64() {
+    let unit = inputs_f64(-1.0, 1.0, COUNT);
+    let atan = inputs_f64(-100.0, 100.0, COUNT);
+    check_f64("asin", |x| x.asin(), f64::asin, &unit, 1.0);
+    check_f64("acos", |x| x.acos(), f64::acos, &unit, 1.0);
+    check_f64("atan", |x| x.atan(), f64::atan, &atan, 1.0);
+
+    // Rotating one operand pairs evenly spread with pseudorandom inputs, and each with a
+    // different half of its own kind.
+    let y = inputs_f64(-100.0, 100.0, COUNT);
+    let mut x = inputs_f64(-30.0, 70.0, COUNT);
+    x.rotate_left(COUNT / 2);
+    check2_f64("atan2", |y, x| y.atan2(x), f64::atan2, &y, &x, 2.0);
+}
+
+#[test]
+fn fast_f32() {
+    let exp = inputs_f32(-104.0, 89.0, COUNT);
+    let tanh = inputs_f32(-10.0, 10.0, COUNT);
+    let ln = inputs_f32(0.0, 1000.0, COUNT);
+    let trig = inputs_f32(-10000.0, 10000.0, COUNT);
+    check_f32("fast::exp", fast::exp, f64::exp, &exp, 3.0);
+    check_f32("fast::tanh", fast::tanh, f64::tanh, &tanh, 3.0);
+    check_f32("fast::ln", fast::ln, f64::ln, &ln, 3.0);
+    check_f32("fast::sin", fast::sin, f64::sin, &trig, 3.0);
+    check_f32("fast::cos", fast::cos, f64::cos, &trig, 3.0);
+}
+
+#[test]
+fn fast_f64() {
+    let exp = inputs_f64(-745.0, 709.0, COUNT);
+    let tanh = inputs_f64(-20.0, 20.0, COUNT);
+    let ln = inputs_f64(0.0, 1000.0, COUNT);
+    let trig = inputs_f64(-10000.0, 10000.0, COUNT);
+    check_f64("fast::exp", fast::exp, f64::exp, &exp, 3.0);
+    check_f64("fast::tanh", fast::tanh, tanh_reference, &tanh, 3.0);
+    check_f64("fast::ln", fast::ln, f64::ln, &ln, 3.0);
+    check_f64("fast::sin", fast::sin, f64::sin, &trig, 3.0);
+    check_f64("fast::cos", fast::cos, f64::cos, &trig, 3.0);
+}
+
+/// Asserts that `f` matches `reference` exactly on `inputs`, including the sign of zero. Any NaN
+/// matches any NaN.
+fn check_special_f32(
+    name: &str,
+    f: fn(&mut SimdMut<f32>),
+    reference: fn(f32) -> f32,
+    inputs: &[f32],
+) {
+    let mut outputs = inputs.to_vec();
+    f(&mut SimdMut::new(&mut outputs));
+    for (&x, &y) in inputs.iter().zip(&outputs) {
+        let expected = reference(x);
+        assert!(
+            y.to_bits() == expected.to_bits() || y.is_nan() && expected.is_nan(),
+            "{name}({x:e}) = {y:e}, expected {expected:e}"
+        );
+    }
+}
+
+/// Asserts that `f` matches `reference` exactly on `inputs`, including the sign of zero. Any NaN
+/// matches any NaN.
+fn check_special_f64(
+    name: &str,
+    f: fn(&mut SimdMut<f64>),
+    reference: fn(f64) -> f64,
+    inputs: &[f64],
+) {
+    let mut outputs = inputs.to_vec();
+    f(&mut SimdMut::new(&mut outputs));
+    for (&x, &y) in inputs.iter().zip(&outputs) {
+        let expected = reference(x);
+        assert!(
+            y.to_bits() == expected.to_bits() || y.is_nan() && expected.is_nan(),
+            "{name}({x:e}) = {y:e}, expected {expected:e}"
+        );
+    }
+}
+
+#[test]
+fn atan2_special() {
+    // Every pair of signed zeros, signed infinities, NaN and a finite value in each quadrant.
+    let values = [
+        0.0f64,
+        -0.0,
+        2.0,
+        -2.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let (y, x): (Vec<f64>, Vec<f64>) = values
+        .iter()
+        .flat_map(|&y| values.iter().map(move |&x| (y, x)))
+        .unzip();
+
+    let mut outputs = y.clone();
+    SimdMut::new(&mut outputs).atan2(&SimdRef::new(&x));
+    for ((&y, &x), &z) in y.iter().zip(&x).zip(&outputs) {
+        let expected = y.atan2(x);
+        assert!(
+            z.to_bits() == expected.to_bits() || z.is_nan() && expected.is_nan(),
+            "atan2({y:e}, {x:e}) = {z:e}, expected {expected:e}"
+        );
+
+        let (y, x) = (y as f32, x as f32);
+        let mut output = [y];
+        SimdMut::new(&mut output).atan2(&SimdRef::new(&[x]));
+        let expected = y.atan2(x);
+        assert!(
+            output[0].to_bits() == expected.to_bits() || output[0].is_nan() && expected.is_nan(),
+            "atan2({y:e}, {x:e}) = {:e}, expected {expected:e}",
+            output[0]
+        );
+    }
+}
+
+#[test]
+fn powf_special() {
+    // Every pair of signed zeros and infinities, NaN, +-1, and finite bases and exponents that
+    // are odd, even and non-integral.
+    let values = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -1.0,
+        0.5,
+        -0.5,
+        2.0,
+        -2.0,
+        3.0,
+        -3.0,
+        2.5,
+        -2.5,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    let (x, y): (Vec<f64>, Vec<f64>) = values
+        .iter()
+        .flat_map(|&x| values.iter().map(move |&y| (x, y)))
+        .unzip();
+
+    let mut outputs = x.clone();
+    SimdMut::new(&mut outputs).powf(&SimdRef::new(&y));
+    for ((&x, &y), &z) in x.iter().zip(&y).zip(&outputs) {
+        let expected = x.powf(y);
+        assert!(
+            z.to_bits() == expected.to_bits() || z.is_nan() && expected.is_nan(),
+            "powf({x:e}, {y:e}) = {z:e}, expected {expected:e}"
+        );
+
+        let (x, y) = (x as f32, y as f32);
+        let mut output = [x];
+        SimdMut::new(&mut output).powf(&SimdRef::new(&[y]));
+        let expected = x.powf(y);
+        assert!(
+            output[0].to_bits() == expected.to_bits() || output[0].is_nan() && expected.is_nan(),
+            "powf({x:e}, {y:e}) = {:e}, expected {expected:e}",
+            output[0]
+        );
+
+        let mut output = [x];
+        SimdMut::new(&mut output).powf_scalar(y);
+        assert!(
+            output[0].to_bits() == expected.to_bits() || output[0].is_nan() && expected.is_nan(),
+            "powf_scalar({x:e}, {y:e}) = {:e}, expected {expected:e}",
+            output[0]
+        );
+    }
+}
+
+#[test]
+fn log_special() {
+    let inputs = [
+        0.0f32,
+        -0.0,
+        1.0,
+        -1.0,
+        -1e-30,
+        f32::from_bits(1),
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    check_special_f32("ln", |x| x.ln(), f32::ln, &inputs);
+    check_special_f32("log2", |x| x.log2(), f32::log2, &inputs);
+    check_special_f32("log10", |x| x.log10(), f32::log10, &inputs);
+    check_special_f32(
+        "ln_1p",
+        |x| x.ln_1p(),
+        f32::ln_1p,
+        &[&inputs[..], &[-2.0]].concat(),
+    );
+
+    let inputs = [
+        0.0f64,
+        -0.0,
+        1.0,
+        -1.0,
+        -1e-300,
+        f64::from_bits(1),
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    check_special_f64("ln", |x| x.ln(), f64::ln, &inputs);
+    check_special_f64("log2", |x| x.log2(), f64::log2, &inputs);
+    check_special_f64("log10", |x| x.log10(), f64::log10, &inputs);
+    check_special_f64(
+        "ln_1p",
+        |x| x.ln_1p(),
+        f64::ln_1p,
+        &[&inputs[..], &[-2.0]].concat(),
+    );
+}
+
+#[test]
+#[ignore = "checks every f32, run with `cargo test --release -- --ignored`"]
+fn exhaustive_f32_fast() {
+    check_exhaustive_f32("fast::exp", fast::exp, f64::exp, 3.0);
+    check_exhaustive_f32("fast::tanh", fast::tanh, f64::tanh, 3.0);
+    check_exhaustive_f32("fast::ln", fast::ln, f64::ln, 3.0);
+    check_exhaustive_f32("fast::sin", fast::sin, f64::sin, 3.0);
+    check_exhaustive_f32("fast::cos", fast::cos, f64::cos, 3.0);
+}
+
+/// Asserts that `f` gives the same result for each element of `inputs` as for the element alone,
+/// so that lanes in range are unaffected by out of range lanes in the same vector.
+fn check_lanes_independent<T: SimdElement + Copy + core::fmt::Debug>(
+    name: &str,
+    f: fn(&mut SimdMut<T>),
+    inputs: &[T],
+    to_bits: fn(T) -> u64,
+) {
+    let mut values = inputs.to_vec();
+    f(&mut SimdMut::new(&mut values));
+    for (&x, &y) in inputs.iter().zip(&values) {
+        let mut alone = [x];
+        f(&mut SimdMut::new(&mut alone));
+        assert_eq!(to_bits(y), to_bits(alone[0]), "{name}({x:?})");
+    }
+}
+
+#[test]
+fn trig_large_lanes() {
+    let inputs = [
+        0.5f32,
+        3e6,
+        -1.0,
+        f32::INFINITY,
+        100.0,
+        -1e30,
+        f32::NAN,
+        2.0,
+        1e7,
+        -0.25,
+    ];
+    for (name, f) in [
+        ("sin", precise::sin as fn(&mut SimdMut<f32>)),
+        ("cos", precise::cos),
+        ("tan", |x| x.tan()),
+        ("fast::sin", fast::sin),
+        ("fast::cos", fast::cos),
+    ] {
+        check_lanes_independent(name, f, &inputs, |x| x.to_bits() as u64);
+    }
+
+    let inputs = [
+        0.5f64,
+        3e6,
+        -1.0,
+        f64::INFINITY,
+        100.0,
+        -1e300,
+        f64::NAN,
+        2.0,
+        1e7,
+    ];
+    for (name, f) in [
+        ("sin", precise::sin as fn(&mut SimdMut<f64>)),
+        ("cos", precise::cos),
+        ("tan", |x| x.tan()),
+        ("fast::sin", fast::sin),
+        ("fast::cos", fast::cos),
+    ] {
+        check_lanes_independent(name, f, &inputs, f64::to_bits);
+    }
+}
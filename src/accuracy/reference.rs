@@ -0,0 +1,939 @@
+//! Reference values of the special functions, correctly rounded to `f64` from 400 bit mpmath
+//! evaluations. The `F32` tables have inputs representable as `f32`.
+
+pub(super) const ERF_F32: [(f64, f64); 115] = [
+    (-6.0, -1.0),
+    (-5.800000190734863, -0.9999999999999998),
+    (-5.599999904632568, -0.9999999999999977),
+    (-5.400000095367432, -0.9999999999999777),
+    (-5.199999809265137, -0.9999999999998075),
+    (-5.0, -0.9999999999984626),
+    (-4.800000190734863, -0.9999999999886479),
+    (-4.599999904632568, -0.9999999999225039),
+    (-4.400000095367432, -0.9999999995108294),
+    (-4.199999809265137, -0.9999999971445012),
+    (-4.0, -0.9999999845827421),
+    (-3.799999952316284, -0.9999999229960437),
+    (-3.5999999046325684, -0.9999996441367538),
+    (-3.4000000953674316, -0.9999984780076637),
+    (-3.200000047683716, -0.9999939742407697),
+    (-3.0, -0.9999779095030014),
+    (-2.799999952316284, -0.9999249867841531),
+    (-2.5999999046325684, -0.9997639654587253),
+    (-2.4000000953674316, -0.9993114864424479),
+    (-2.200000047683716, -0.9981371541274596),
+    (-2.0, -0.9953222650189527),
+    (-1.7999999523162842, -0.9890904995285049),
+    (-1.600000023841858, -0.9763483854243468),
+    (-1.399999976158142, -0.9522851159731831),
+    (-1.2000000476837158, -0.9103139909776066),
+    (-1.0, -0.8427007929497149),
+    (-0.800000011920929, -0.7421009718004437),
+    (-0.6000000238418579, -0.6038561096172717),
+    (-0.4000000059604645, -0.4283923607779012),
+    (-0.20000000298023224, -0.2227025924414519),
+    (0.0, 0.0),
+    (0.20000000298023224, 0.2227025924414519),
+    (0.4000000059604645, 0.4283923607779012),
+    (0.6000000238418579, 0.6038561096172717),
+    (0.800000011920929, 0.7421009718004437),
+    (1.0, 0.8427007929497149),
+    (1.2000000476837158, 0.9103139909776066),
+    (1.399999976158142, 0.9522851159731831),
+    (1.600000023841858, 0.9763483854243468),
+    (1.7999999523162842, 0.9890904995285049),
+    (2.0, 0.9953222650189527),
+    (2.200000047683716, 0.9981371541274596),
+    (2.4000000953674316, 0.9993114864424479),
+    (2.5999999046325684, 0.9997639654587253),
+    (2.799999952316284, 0.9999249867841531),
+    (3.0, 0.9999779095030014),
+    (3.200000047683716, 0.9999939742407697),
+    (3.4000000953674316, 0.9999984780076637),
+    (3.5999999046325684, 0.9999996441367538),
+    (3.799999952316284, 0.9999999229960437),
+    (4.0, 0.9999999845827421),
+    (4.199999809265137, 0.9999999971445012),
+    (4.400000095367432, 0.9999999995108294),
+    (4.599999904632568, 0.9999999999225039),
+    (4.800000190734863, 0.9999999999886479),
+    (5.0, 0.9999999999984626),
+    (5.199999809265137, 0.9999999999998075),
+    (5.400000095367432, 0.9999999999999777),
+    (5.599999904632568, 0.9999999999999977),
+    (5.800000190734863, 0.9999999999999998),
+    (6.0, 1.0),
+    (-1.4093378782272339, -0.9537500210692694),
+    (-2.7932066917419434, -0.9999219110774029),
+    (1.2074757814407349, 0.9122947267656359),
+    (-3.4205098152160645, -0.9999986840605747),
+    (0.287056028842926, 0.3152269281474899),
+    (-1.074488639831543, -0.8713774988813847),
+    (-3.536008596420288, -0.9999994286896202),
+    (0.05948586389422417, 0.06704352089200141),
+    (-3.7000346183776855, -0.9999998328932228),
+    (-0.5308345556259155, -0.5471748599930027),
+    (-3.4411566257476807, -0.9999988642552254),
+    (-3.2742958068847656, -0.9999963531053034),
+    (-0.6038464903831482, -0.606877222404337),
+    (2.614816904067993, 0.9997826176664252),
+    (-3.009584426879883, -0.9999792064738443),
+    (-2.214088201522827, -0.9982590262871243),
+    (1.0194658041000366, 0.8506249330098726),
+    (3.5816714763641357, 0.9999995921318011),
+    (0.6168236136436462, 0.6169663446852848),
+    (-0.8265562057495117, -0.757566954301836),
+    (3.8100409507751465, 0.9999999288377539),
+    (-3.6273386478424072, -0.9999997099983695),
+    (2.8677477836608887, 0.9999499958983112),
+    (-1.6831257343292236, -0.9827014158598967),
+    (-2.845959424972534, -0.9999429748894383),
+    (-3.057662010192871, -0.999984690466282),
+    (-1.532145380973816, -0.969748426143309),
+    (2.529010772705078, 0.99965185633936),
+    (-2.5541889667510986, -0.9996963530598179),
+    (0.6528013348579407, 0.6440972723290763),
+    (1.1113077402114868, 0.8839627979481299),
+    (-1.0208196640014648, -0.8511645211704326),
+    (0.38195571303367615, 0.41091809683190267),
+    (-3.4976882934570312, -0.9999992443182224),
+    (-3.52319073677063, -0.9999993724632487),
+    (-2.352330207824707, -0.9991211821340045),
+    (1.4431997537612915, 0.9587484438183653),
+    (-0.5792615413665771, -0.587327415810991),
+    (-1.4868226051330566, -0.9645066590158443),
+    (0.6844949126243591, 0.6669666118830851),
+    (1.000000013351432e-10, 1.1283791821609903e-10),
+    (-9.99999993922529e-09, -1.1283791602378209e-08),
+    (0.84375, 0.7672256612323416),
+    (-0.84375, -0.7672256612323416),
+    (1.25, 0.9229001282564583),
+    (-1.25, -0.9229001282564583),
+    (2.857142925262451, 0.9999466877105128),
+    (5.900000095367432, 0.9999999999999999),
+    (6.099999904632568, 1.0),
+    (7.0, 1.0),
+    (-7.0, -1.0),
+    (9.99994610111476e-41, 1.128373085257589e-40),
+    (3.9000000953674316, 0.9999999652077781),
+    (3.950000047683716, 0.9999999767832768),
+];
+
+pub(super) const ERFC_F32: [(f64, f64); 81] = [
+    (-6.0, 2.0),
+    (-5.5, 1.9999999999999927),
+    (-5.0, 1.9999999999984626),
+    (-4.5, 1.999999999803384),
+    (-4.0, 1.999999984582742),
+    (-3.5, 1.9999992569016276),
+    (-3.0, 1.9999779095030015),
+    (-2.5, 1.999593047982555),
+    (-2.0, 1.9953222650189528),
+    (-1.5, 1.9661051464753108),
+    (-1.0, 1.8427007929497148),
+    (-0.5, 1.5204998778130465),
+    (0.0, 1.0),
+    (0.5, 0.4795001221869535),
+    (1.0, 0.15729920705028513),
+    (1.5, 0.033894853524689274),
+    (2.0, 0.004677734981047266),
+    (2.5, 0.0004069520174449589),
+    (3.0, 2.209049699858544e-05),
+    (3.5, 7.430983723414128e-07),
+    (4.0, 1.541725790028002e-08),
+    (4.5, 1.9661604415428876e-10),
+    (5.0, 1.537459794428035e-12),
+    (5.5, 7.357847917974398e-15),
+    (6.0, 2.1519736712498913e-17),
+    (6.5, 3.8421483271206475e-20),
+    (7.0, 4.183825607779414e-23),
+    (7.5, 2.776649386030569e-26),
+    (8.0, 1.1224297172982926e-29),
+    (8.5, 2.7623240713337716e-33),
+    (9.0, 4.13703174651381e-37),
+    (9.5, 3.7692144856548797e-41),
+    (10.0, 2.088487583762545e-45),
+    (10.5, 7.035928090177523e-50),
+    (3.4382126331329346, 1.1599034973803167e-06),
+    (1.5972039699554443, 0.023896605446332382),
+    (7.532553672790527, 1.6948978793308953e-26),
+    (6.387933254241943, 1.656573051695784e-19),
+    (0.9291581511497498, 0.1888367619282445),
+    (4.893084526062012, 4.520694662911523e-12),
+    (4.302358150482178, 1.1688950421566104e-09),
+    (8.501649856567383, 2.685402468557498e-33),
+    (6.75334358215332, 1.288600893953922e-21),
+    (1.4552531242370605, 0.03958641811631485),
+    (9.76209831237793, 2.355006904961631e-43),
+    (-0.5832106471061707, 1.5905059990561536),
+    (3.0174739360809326, 1.978064151689763e-05),
+    (7.085690975189209, 1.2364891028381508e-23),
+    (-0.17618557810783386, 1.1967661011261497),
+    (3.8675572872161865, 4.5117462077804616e-08),
+    (-1.529512882232666, 1.9694632704559747),
+    (6.018590450286865, 1.7159057188077395e-17),
+    (7.1748504638671875, 3.425093121544813e-24),
+    (4.876311302185059, 5.343244058030905e-12),
+    (8.505733489990234, 2.5040386695602396e-33),
+    (1.7649701833724976, 0.012558674979339286),
+    (6.3435444831848145, 2.934967734479216e-19),
+    (5.132438659667969, 3.9179899784432434e-13),
+    (4.958742618560791, 2.3372714221473245e-12),
+    (3.474463939666748, 8.940356660602533e-07),
+    (8.07961368560791, 3.0899922626633303e-30),
+    (9.336173057556152, 8.392669999833085e-40),
+    (3.6891801357269287, 1.8156127270945595e-07),
+    (5.969826698303223, 3.1032946726652e-17),
+    (-1.271966814994812, 1.9279548542710172),
+    (6.417904376983643, 1.1234144075905534e-19),
+    (5.7655463218688965, 3.529154879129047e-16),
+    (9.91715145111084, 1.0965837633856225e-44),
+    (7.863097667694092, 1.0016037929399057e-28),
+    (1.4151463508605957, 0.04535800600463056),
+    (2.6294972896575928, 0.0002002739098398718),
+    (6.02383279800415, 1.609562412799033e-17),
+    (-1.7292448282241821, 1.9855357171194186),
+    (3.5403435230255127, 5.53418530043368e-07),
+    (1.000000013351432e-10, 0.999999999887162),
+    (0.84375, 0.23277433876765838),
+    (1.25, 0.07709987174354177),
+    (2.857142925262451, 5.331228948722176e-05),
+    (9.1899995803833, 1.2786762247193743e-38),
+    (10.050000190734863, 7.626143627405051e-46),
+    (10.100000381469727, 2.770875527891737e-46),
+];
+
+pub(super) const LGAMMA_F32: [(f64, f64); 101] = [
+    (1.0000000031710769e-30, 69.07755278665029),
+    (9.999999682655225e-21, 46.051701891615394),
+    (1.000000013351432e-10, 23.025850916531304),
+    (1.0, 0.0),
+    (10000000000.0, 220258509288.81058),
+    (1.0000000200408773e+20, 4.5051702782797425e+21),
+    (1.0000000150474662e+30, 6.807755382926351e+31),
+    (5.041451454162598, 3.2406741624110658),
+    (3.512873888015747, 1.215202855254538),
+    (1.7686325311660767, -0.07965804752391416),
+    (23.04698944091797, 48.61753658627115),
+    (3.88020658493042, 1.6433446647634515),
+    (7.428444862365723, 7.395430089773078),
+    (11.728490829467773, 16.842331358910332),
+    (26.14266014099121, 58.466042950739094),
+    (2.4174389839172363, 0.2283233600004814),
+    (13.475622177124023, 21.197565130903488),
+    (16.483198165893555, 29.231176028621125),
+    (26.501514434814453, 59.632780408131275),
+    (24.57839584350586, 53.43977428829727),
+    (25.91953468322754, 57.74312585243162),
+    (8.352631568908691, 9.24409149937628),
+    (12.458895683288574, 18.632265681069274),
+    (10.763134956359863, 14.550054522428178),
+    (26.525785446166992, 59.711871938233585),
+    (28.731935501098633, 66.99300354598377),
+    (4.527626991271973, 2.49220159999933),
+    (5.286531925201416, 3.6185045020400417),
+    (6.958705902099609, 6.502047462166863),
+    (7.000082492828369, 6.579405703809269),
+    (14.548881530761719, 23.99186258657949),
+    (17.6737060546875, 32.57417044116727),
+    (7.88239860534668, 8.289044596132499),
+    (0.12280809879302979, 2.0379640054489583),
+    (12.568394660949707, 18.904516054334533),
+    (11.077607154846191, 15.287211285251303),
+    (16.990236282348633, 30.644490281990795),
+    (0.5, 0.5723649429247001),
+    (0.6000000238418579, 0.39823382133801155),
+    (0.699999988079071, 0.2608672610754808),
+    (0.800000011920929, 0.1520596668960392),
+    (0.8999999761581421, 0.06637625773360459),
+    (1.100000023841858, -0.049872451362944395),
+    (1.2000000476837158, -0.0853741037858107),
+    (1.2999999523162842, -0.10817480144020891),
+    (1.399999976158142, -0.11961291270884941),
+    (1.5, -0.12078223763524522),
+    (1.600000023841858, -0.11259176269155008),
+    (1.7000000476837158, -0.09580768746272736),
+    (1.7999999523162842, -0.07108388650382184),
+    (1.899999976158142, -0.0389842844151753),
+    (2.0, 0.0),
+    (2.0999999046325684, 0.04543769225924308),
+    (2.200000047683716, 0.09694749274457298),
+    (2.299999952316284, 0.15418942634750007),
+    (2.4000000953674316, 0.21685938471435165),
+    (2.5, 0.2846828704729192),
+    (2.5999999046325684, 0.3574117919235153),
+    (2.700000047683716, 0.43482059164868725),
+    (2.799999952316284, 0.5167027519073436),
+    (2.9000000953674316, 0.6028696944110671),
+    (3.0, core::f64::consts::LN_2),
+    (0.9999989867210388, 5.848813337646874e-07),
+    (1.0000009536743164, -5.504750066148867e-07),
+    (0.9990000128746033, 0.0005780310802754651),
+    (1.0010000467300415, -0.0005764204947818381),
+    (1.9999979734420776, -8.567956193925354e-07),
+    (2.000001907348633, 8.063982966523694e-07),
+    (1.9980000257492065, -0.0008442674097878275),
+    (2.002000093460083, 0.0008468976337788523),
+    (-0.5, 1.2655121234846454),
+    (-1.5, 0.860047015376481),
+    (-2.5, -0.056243716497674054),
+    (-3.5, -1.309006684993042),
+    (-10.5, -15.147270590717842),
+    (-100.5, -364.90096830942736),
+    (-170.5, -707.9984331450788),
+    (-10000000000.0, f64::INFINITY),
+    (-0.10000000149011612, 2.3689613189525565),
+    (-0.8999999761581421, 2.3580729453613305),
+    (-1.100000023841858, 2.2736509108303586),
+    (-1.899999976158142, 1.716219071737282),
+    (-3.0999999046325684, 0.40031274125774224),
+    (-5.699999809265137, -4.667768675663547),
+    (-20.299999237060547, -41.88728842316223),
+    (-150.25, -604.7825011297857),
+    (-1.0, f64::INFINITY),
+    (-1.0010000467300415, 6.907287068599136),
+    (-0.9990000128746033, 6.908192254926357),
+    (-2.0, f64::INFINITY),
+    (-2.002000093460083, 5.519574324622222),
+    (-1.9980000257492065, 5.52332512697978),
+    (-7.0, f64::INFINITY),
+    (-7.006999969482422, -3.5773439587595073),
+    (-6.993000030517578, -3.549125103116746),
+    (-100.0, f64::INFINITY),
+    (-100.0999984741211, -361.8813307940618),
+    (-99.9000015258789, -360.9593125256118),
+    (4.0800000084910683e+36, 3.3986057488749292e+38),
+    (4.0899998702509687e+36, 3.407035670819151e+38),
+    (9.99994610111476e-41, 92.10340910966488),
+];
+
+pub(super) const TGAMMA_F32: [(f64, f64); 51] = [
+    (0.009999999776482582, 99.43258735410775),
+    (2.9183051586151123, 1.8572420056391032),
+    (5.826610088348389, 89.51648022552972),
+    (8.734914779663086, 22955.728233723315),
+    (11.643219947814941, 16791844.308870967),
+    (14.551525115966797, 26458655446.79787),
+    (17.45983123779297, 76426446874230.73),
+    (20.368135452270508, 3.6435021794249325e+17),
+    (23.27644157409668, 2.662627052190913e+21),
+    (26.18474578857422, 2.8235345245024506e+25),
+    (29.093050003051758, 4.164684479874228e+29),
+    (32.0013542175293, 8.261346626481894e+33),
+    (34.90966033935547, 2.1443150059478486e+38),
+    (37.81796646118164, 7.118692398494512e+42),
+    (9.53097915649414, 127704.04125819565),
+    (6.904936790466309, 602.9989835857685),
+    (5.154914379119873, 30.386520102964962),
+    (6.175927639007568, 162.45836012734128),
+    (6.762001037597656, 463.09536523051725),
+    (0.5399289131164551, 1.6449814322692),
+    (8.995329856872559, 39918.9761429707),
+    (7.799695014953613, 3374.8738346182568),
+    (8.745131492614746, 23455.871195792322),
+    (7.978731155395508, 4828.644846971123),
+    (3.9237890243530273, 5.456784641336476),
+    (3.989788293838501, 5.923616616414668),
+    (1.035370945930481, 0.9807820734049575),
+    (6.3428955078125, 217.66513485078937),
+    (0.622478187084198, 1.4397993937226399),
+    (0.6734761595726013, 1.3421118973612676),
+    (2.087631940841675, 1.040271360762334),
+    (1.6230318546295166, 0.896315870352072),
+    (3.40053653717041, 2.9829178681864286),
+    (0.5257560610771179, 1.6877280305224718),
+    (0.002332818927243352, 428.09101885442436),
+    (1.5126492977142334, 0.8867021421046746),
+    (1.014643669128418, 0.9917567299083989),
+    (3.636099338531494, 3.873380426094931),
+    (0.25500887632369995, 3.5504018502766526),
+    (8.74332332611084, 23366.546846651807),
+    (6.140689849853516, 152.82671121570468),
+    (1.4855048656463623, 0.8858456652517623),
+    (2.5225775241851807, 1.3507809875585393),
+    (3.47389554977417, 3.2293768301430608),
+    (1.2589254150202046e-31, 7.943282326887895e+30),
+    (0.10000000149011612, 9.513507550899174),
+    (35.040000915527344, 3.401648619573705e+38),
+    (35.04999923706055, 3.52428378441274e+38),
+    (35.0, 2.9523279903960416e+38),
+    (34.5, 5.044620868349451e+37),
+    (1.0000002153053333e-39, 9.999997846947132e+38),
+];
+
+pub(super) const TGAMMA_NEGATIVE_F32: [(f64, f64); 23] = [
+    (-0.5, -3.544907701811032),
+    (-1.5, 2.363271801207355),
+    (-2.5, -0.9453087204829419),
+    (-3.5, 0.2700882058522691),
+    (-10.5, -2.640121820547716e-07),
+    (-0.10000000149011612, -10.686286873976423),
+    (-0.8999999761581421, -10.570561762642418),
+    (-1.100000023841858, 9.71480403100583),
+    (-5.699999809265137, 0.009393205448819057),
+    (-20.299999237060547, -6.435492313554986e-19),
+    (-1.0, f64::NAN),
+    (-1.0010000467300415, 999.5318992103755),
+    (-0.9990000128746033, -1000.4370714321159),
+    (-2.0, f64::NAN),
+    (-2.002000093460083, -249.52879631459805),
+    (-1.9980000257492065, 250.466486958907),
+    (-7.0, f64::NAN),
+    (-7.006999969482422, 0.027949835671854602),
+    (-6.993000030517578, -0.02874978174871182),
+    (-38.5, -9.649149400280067e-46),
+    (-41.5, 1.453412246308074e-50),
+    (-42.5, -3.41979352072488e-52),
+    (-45.5, 3.882748142001218e-57),
+];
+
+pub(super) const ERF_F64: [(f64, f64); 115] = [
+    (-6.0, -1.0),
+    (-5.8, -0.9999999999999998),
+    (-5.6, -0.9999999999999977),
+    (-5.4, -0.9999999999999777),
+    (-5.2, -0.9999999999998075),
+    (-5.0, -0.9999999999984626),
+    (-4.8, -0.9999999999886479),
+    (-4.6, -0.999999999922504),
+    (-4.4, -0.999999999510829),
+    (-4.2, -0.9999999971445058),
+    (-4.0, -0.9999999845827421),
+    (-3.8, -0.9999999229960725),
+    (-3.6, -0.999999644137007),
+    (-3.4, -0.9999984780066371),
+    (-3.2, -0.9999939742388483),
+    (-3.0, -0.9999779095030014),
+    (-2.8, -0.9999249868053346),
+    (-2.6, -0.9997639655834707),
+    (-2.4, -0.999311486103355),
+    (-2.2, -0.9981371537020182),
+    (-2.0, -0.9953222650189527),
+    (-1.7999999999999998, -0.9890905016357308),
+    (-1.5999999999999996, -0.976348383344644),
+    (-1.4000000000000004, -0.9522851197626488),
+    (-1.2000000000000002, -0.9103139782296354),
+    (-1.0, -0.8427007929497149),
+    (-0.7999999999999998, -0.7421009647076604),
+    (-0.5999999999999996, -0.6038560908479257),
+    (-0.40000000000000036, -0.4283923550466688),
+    (-0.20000000000000018, -0.22270258921047864),
+    (0.0, 0.0),
+    (0.20000000000000018, 0.22270258921047864),
+    (0.40000000000000036, 0.4283923550466688),
+    (0.5999999999999996, 0.6038560908479257),
+    (0.7999999999999998, 0.7421009647076604),
+    (1.0, 0.8427007929497149),
+    (1.2000000000000002, 0.9103139782296354),
+    (1.4000000000000004, 0.9522851197626488),
+    (1.5999999999999996, 0.976348383344644),
+    (1.7999999999999998, 0.9890905016357308),
+    (2.0, 0.9953222650189527),
+    (2.1999999999999993, 0.998137153702018),
+    (2.4000000000000004, 0.999311486103355),
+    (2.5999999999999996, 0.9997639655834707),
+    (2.8000000000000007, 0.9999249868053346),
+    (3.0, 0.9999779095030014),
+    (3.1999999999999993, 0.9999939742388483),
+    (3.4000000000000004, 0.9999984780066371),
+    (3.5999999999999996, 0.999999644137007),
+    (3.8000000000000007, 0.9999999229960725),
+    (4.0, 0.9999999845827421),
+    (4.199999999999999, 0.9999999971445058),
+    (4.4, 0.999999999510829),
+    (4.6, 0.999999999922504),
+    (4.800000000000001, 0.9999999999886479),
+    (5.0, 0.9999999999984626),
+    (5.199999999999999, 0.9999999999998075),
+    (5.4, 0.9999999999999777),
+    (5.6, 0.9999999999999977),
+    (5.800000000000001, 0.9999999999999998),
+    (6.0, 1.0),
+    (2.49208997418876, 0.9995754728015221),
+    (3.8794084047271262, 0.9999999589588191),
+    (2.8210303899732843, 0.9999337982878785),
+    (2.44862867828534, 0.9994655810350785),
+    (2.5466635466029857, 0.9996836453771674),
+    (1.918984163005713, 0.9933494415913913),
+    (-2.186084079747321, -0.9980091221495886),
+    (0.14110979394804435, 0.15817480467114273),
+    (-1.1554996531603345, -0.8977670326199578),
+    (-3.768158794069077, -0.9999999012348206),
+    (-3.776503396623484, -0.9999999074536716),
+    (-1.7646516876077616, -0.9874253690384172),
+    (-1.9266050938579475, -0.9935626622961828),
+    (1.540175533600987, 0.9706041708470168),
+    (3.6521206107307025, 0.9999997594048452),
+    (-0.4221785778662124, -0.4495264489238884),
+    (3.4961696102099387, 0.9999992359400577),
+    (3.9043044656228814, 0.9999999663921074),
+    (3.6400050505706654, 0.9999997363627633),
+    (-1.0829129171050713, -0.8743467578202146),
+    (-2.2363014160301002, -0.9984363716394375),
+    (-2.1852333861541764, -0.9980010394116796),
+    (-2.426350692645462, -0.9993994769474839),
+    (-2.365013093790216, -0.9991760866070507),
+    (0.9925311795025458, 0.839577216074147),
+    (3.202466703072914, 0.9999940728604361),
+    (2.7234842182343186, 0.9998826458091776),
+    (-0.16421258990769427, -0.18364192041206287),
+    (1.2238243427280722, 0.9165034554142152),
+    (2.3971499587972813, 0.9993012827528447),
+    (-3.321772108396959, -0.9999973688627343),
+    (1.284685201639153, 0.9307552035232457),
+    (3.2782171004137837, 0.9999964495417545),
+    (2.25842307278472, 0.9985963164210048),
+    (2.0011236786436672, 0.995345435946898),
+    (-0.17573804324799802, -0.1962765082910415),
+    (-2.5718262532994114, -0.9997242874721071),
+    (2.313083448162211, 0.9989290557690341),
+    (-1.3398624010831206, -0.9418879324049807),
+    (2.4065885511735283, 0.9993345457866977),
+    (1e-300, 1.1283791670955126e-300),
+    (-1e-300, -1.1283791670955126e-300),
+    (5e-324, 5e-324),
+    (1e-10, 1.1283791670955126e-10),
+    (-1e-08, -1.1283791670955126e-08),
+    (0.84375, 0.7672256612323416),
+    (-0.84375, -0.7672256612323416),
+    (1.25, 0.9229001282564583),
+    (-1.25, -0.9229001282564583),
+    (2.857142857142857, 0.9999466876886117),
+    (5.9, 0.9999999999999999),
+    (6.1, 1.0),
+    (7.0, 1.0),
+    (-7.0, -1.0),
+];
+
+pub(super) const ERFC_F64: [(f64, f64); 118] = [
+    (-6.0, 2.0),
+    (-5.5, 1.9999999999999927),
+    (-5.0, 1.9999999999984626),
+    (-4.5, 1.999999999803384),
+    (-4.0, 1.999999984582742),
+    (-3.5, 1.9999992569016276),
+    (-3.0, 1.9999779095030015),
+    (-2.5, 1.999593047982555),
+    (-2.0, 1.9953222650189528),
+    (-1.5, 1.9661051464753108),
+    (-1.0, 1.8427007929497148),
+    (-0.5, 1.5204998778130465),
+    (0.0, 1.0),
+    (0.5, 0.4795001221869535),
+    (1.0, 0.15729920705028513),
+    (1.5, 0.033894853524689274),
+    (2.0, 0.004677734981047266),
+    (2.5, 0.0004069520174449589),
+    (3.0, 2.209049699858544e-05),
+    (3.5, 7.430983723414128e-07),
+    (4.0, 1.541725790028002e-08),
+    (4.5, 1.9661604415428876e-10),
+    (5.0, 1.537459794428035e-12),
+    (5.5, 7.357847917974398e-15),
+    (6.0, 2.1519736712498913e-17),
+    (6.5, 3.8421483271206475e-20),
+    (7.0, 4.183825607779414e-23),
+    (7.5, 2.776649386030569e-26),
+    (8.0, 1.1224297172982926e-29),
+    (8.5, 2.7623240713337716e-33),
+    (9.0, 4.13703174651381e-37),
+    (9.5, 3.7692144856548797e-41),
+    (10.0, 2.088487583762545e-45),
+    (10.5, 7.035928090177523e-50),
+    (11.0, 1.4408661379436948e-54),
+    (11.5, 1.793309643576782e-59),
+    (12.0, 1.3562611692059042e-64),
+    (12.5, 6.231942781979911e-70),
+    (13.0, 1.7395573154667246e-75),
+    (13.5, 2.949433113257988e-81),
+    (14.0, 3.0372298477503115e-87),
+    (14.5, 1.899395941979503e-93),
+    (15.0, 7.212994172451207e-100),
+    (15.5, 1.6632016400488723e-106),
+    (16.0, 2.3284857515715308e-113),
+    (16.5, 1.979130575553268e-120),
+    (17.0, 1.0212280150942608e-127),
+    (17.5, 3.198863812343481e-135),
+    (18.0, 6.082369231816399e-143),
+    (18.5, 7.019961574985679e-151),
+    (19.0, 4.917722839256475e-159),
+    (19.5, 2.0909541479227294e-167),
+    (20.0, 5.395865611607901e-176),
+    (20.5, 8.450842369572458e-185),
+    (21.0, 8.032453871022456e-194),
+    (21.5, 4.633336539658446e-203),
+    (22.0, 1.6219058609334726e-212),
+    (22.5, 3.445348860464602e-222),
+    (23.0, 4.441265948088057e-232),
+    (23.5, 3.4740594956499716e-242),
+    (24.0, 1.6489825831519335e-252),
+    (24.5, 4.749361264067379e-263),
+    (25.0, 8.300172571196523e-274),
+    (25.5, 8.80166269072795e-285),
+    (26.0, 5.663192408856143e-296),
+    (26.5, 2.2109076642637343e-307),
+    (27.0, 5.23705e-319),
+    (9.6598874677859, 1.7324086751671226e-42),
+    (2.750061940833378, 0.00010058561380341916),
+    (2.8166418144124172, 6.795541441038482e-05),
+    (9.361564077578716, 5.206554756054997e-40),
+    (6.697583987610583, 2.750251226427245e-21),
+    (0.040043919662745786, 0.954839415138814),
+    (-0.47553959242562804, 1.4987438138369176),
+    (-0.18619159542212227, 1.207691953753056),
+    (8.858225148798873, 5.285280202614429e-36),
+    (7.678023784386355, 1.819774164753151e-27),
+    (-0.24590829507351009, 1.2719847096455308),
+    (7.918125742304644, 4.174151605106497e-29),
+    (9.763671321364367, 2.2834100801952833e-43),
+    (5.887219512832239, 8.377124733087077e-17),
+    (2.204890145890035, 0.0018196820441582656),
+    (4.58392052784135, 9.012984134465855e-11),
+    (-0.42819377588659524, 1.4551913322437247),
+    (-1.8290847421267333, 1.99031047079658),
+    (9.650682126853173, 2.0713831052322605e-42),
+    (5.796096036085967, 2.466293317605482e-16),
+    (4.318972565188666, 1.0091922336213582e-09),
+    (9.20349766068912, 9.96105204476627e-39),
+    (3.2057132410898275, 5.799691797585068e-06),
+    (8.460915135872849, 5.384553021206769e-33),
+    (7.913863021782653, 4.467945565143598e-29),
+    (0.5325080479377857, 0.45140177981211965),
+    (1.022017736385446, 0.1483592228870745),
+    (1.5155998320426272, 0.03208245027731785),
+    (0.8864727107000148, 0.20996498358308485),
+    (5.03724601799154, 1.0503585500903904e-12),
+    (1.112377543242522, 0.11568654168282719),
+    (3.0281506330545236, 1.8484526924730527e-05),
+    (-0.42711588195819994, 1.454178346051588),
+    (8.920204675786678, 1.7439280485865403e-36),
+    (2.2454082874391066, 0.0014958529959736855),
+    (3.497931837660804, 7.543464711525019e-07),
+    (5.0001852645022, 1.5345592268649886e-12),
+    (8.851561294504478, 5.951744257789786e-36),
+    (3.047539248508782, 1.6334955509683013e-05),
+    (9.012653012111972, 3.2893045220257722e-37),
+    (1e-300, 1.0),
+    (5e-324, 1.0),
+    (1e-10, 0.999999999887162),
+    (0.84375, 0.23277433876765838),
+    (1.25, 0.07709987174354177),
+    (2.857142857142857, 5.3312311388322795e-05),
+    (27.2, 1e-323),
+    (27.22, 5e-324),
+    (27.25, 0.0),
+    (27.3, 0.0),
+    (28.0, 0.0),
+];
+
+pub(super) const LGAMMA_F64: [(f64, f64); 165] = [
+    (1e-300, 690.7755278982137),
+    (1e-290, 667.7496769682732),
+    (1e-280, 644.7238260383328),
+    (1e-270, 621.6979751083924),
+    (1e-260, 598.6721241784519),
+    (1e-250, 575.6462732485114),
+    (1e-240, 552.620422318571),
+    (1e-230, 529.5945713886305),
+    (1e-220, 506.56872045869005),
+    (1e-210, 483.5428695287496),
+    (1e-200, 460.51701859880916),
+    (1e-190, 437.4911676688687),
+    (1e-180, 414.4653167389282),
+    (1e-170, 391.4394658089878),
+    (1e-160, 368.4136148790473),
+    (1e-150, 345.38776394910684),
+    (1e-140, 322.3619130191664),
+    (1e-130, 299.33606208922595),
+    (1e-120, 276.3102111592855),
+    (1e-110, 253.28436022934503),
+    (1e-100, 230.25850929940458),
+    (1e-90, 207.2326583694641),
+    (1e-80, 184.20680743952366),
+    (1e-70, 161.1809565095832),
+    (1e-60, 138.15510557964274),
+    (1e-50, 115.12925464970229),
+    (1e-40, 92.10340371976183),
+    (1e-30, 69.07755278982137),
+    (1e-20, 46.051701859880914),
+    (1e-10, 23.025850929882736),
+    (1.0, 0.0),
+    (10000000000.0, 220258509288.81058),
+    (1e+20, 4.505170185988091e+21),
+    (1e+30, 6.807755278982137e+31),
+    (1e+40, 9.110340371976183e+41),
+    (1e+50, 1.1412925464970229e+52),
+    (1e+60, 1.3715510557964273e+62),
+    (1e+70, 1.601809565095832e+72),
+    (1e+80, 1.8320680743952364e+82),
+    (1e+90, 2.062326583694641e+92),
+    (1e+100, 2.2925850929940456e+102),
+    (1e+110, 2.52284360229345e+112),
+    (1e+120, 2.753102111592855e+122),
+    (1e+130, 2.98336062089226e+132),
+    (1e+140, 3.213619130191664e+142),
+    (1e+150, 3.4438776394910686e+152),
+    (1e+160, 3.674136148790473e+162),
+    (1e+170, 3.9043946580898775e+172),
+    (1e+180, 4.1346531673892825e+182),
+    (1e+190, 4.364911676688687e+192),
+    (1e+200, 4.595170185988091e+202),
+    (1.0000000000000001e+210, 4.825428695287496e+212),
+    (1e+220, 5.0556872045869005e+222),
+    (1e+230, 5.285945713886306e+232),
+    (1e+240, 5.51620422318571e+242),
+    (1e+250, 5.746462732485114e+252),
+    (1e+260, 5.976721241784519e+262),
+    (1e+270, 6.206979751083923e+272),
+    (1e+280, 6.437238260383328e+282),
+    (1e+290, 6.667496769682733e+292),
+    (1e+300, 6.897755278982137e+302),
+    (15.049468233606945, 25.32360064204024),
+    (15.954748873078014, 27.775303536947597),
+    (15.70519756761499, 27.09403444150208),
+    (0.5611460371626009, 0.46093982566147795),
+    (13.2037473715483, 20.503530041115987),
+    (5.493236618165962, 3.9469220908210696),
+    (0.1179744547692596, 2.08002771131086),
+    (23.97511351476665, 51.52812022638862),
+    (5.170401366403467, 3.437872076960345),
+    (14.20478797385869, 23.086754945112176),
+    (21.75579811342134, 44.632284458161465),
+    (16.6942687470664, 29.81758771243265),
+    (9.779464531465923, 12.30781358233071),
+    (15.550461381091106, 26.67366053384004),
+    (16.663256246407407, 29.73125330353219),
+    (23.528174260964263, 50.121853802249085),
+    (3.183282513147848, 0.8687580435345525),
+    (16.808884007518568, 30.13717465784835),
+    (7.4548296312927, 7.44657429036175),
+    (8.307512113943446, 9.151206424109489),
+    (23.16783296266465, 48.994369959727116),
+    (15.231419753769618, 25.811954090145328),
+    (16.851881599694288, 30.25727480990671),
+    (22.7997942777005, 47.848714522652614),
+    (27.37464108989436, 62.492112582072025),
+    (13.297451807323165, 20.742085798139183),
+    (18.37583653033381, 34.58485034713444),
+    (15.16659392553665, 25.63770409121602),
+    (15.364844173059582, 26.171491230492947),
+    (20.781930076446876, 41.678094792776825),
+    (0.5, 0.5723649429247001),
+    (0.6, 0.39823385806923495),
+    (0.7, 0.26086724653166654),
+    (0.8, 0.15205967839983756),
+    (0.9, 0.06637623973474295),
+    (1.1, -0.049872441259839764),
+    (1.2, -0.08537409000331583),
+    (1.3, -0.10817480950786047),
+    (1.4, -0.1196129141723713),
+    (1.5, -0.12078223763524522),
+    (1.6, -0.11259176569675577),
+    (1.7, -0.09580769740706588),
+    (1.8, -0.07108387291437215),
+    (1.9, -0.03898427592308336),
+    (2.0, 0.0),
+    (2.1, 0.04543773854448518),
+    (2.2, 0.09694746679063887),
+    (2.3, 0.15418945495963046),
+    (2.4, 0.21685932244884157),
+    (2.5, 0.2846828704729192),
+    (2.6, 0.35741186354897986),
+    (2.7, 0.4348205536551047),
+    (2.8, 0.5167027919877467),
+    (2.9, 0.6028696102493114),
+    (3.0, core::f64::consts::LN_2),
+    (0.999999999999, 5.772028958989666e-13),
+    (1.000000000001, -5.772669797094564e-13),
+    (0.999999, 5.772164873855652e-07),
+    (1.000001, -5.772148423874147e-07),
+    (0.999, 0.0005780385328913802),
+    (1.001, -0.0005763935982833062),
+    (1.999999999998, -8.455499647621789e-13),
+    (2.000000000002, 8.456438417454142e-13),
+    (1.999998, -8.455673803525766e-07),
+    (2.000002, 8.455699599949668e-07),
+    (1.998, -0.0008442782629153007),
+    (2.002, 0.0008468579998411857),
+    (-0.5, 1.2655121234846454),
+    (-1.5, 0.860047015376481),
+    (-2.5, -0.056243716497674054),
+    (-3.5, -1.309006684993042),
+    (-10.5, -15.147270590717842),
+    (-100.5, -364.90096830942736),
+    (-170.5, -707.9984331450788),
+    (-10000000000.5, -220258509322.20462),
+    (-0.1, 2.3689613327287886),
+    (-0.9, 2.3580731673920323),
+    (-1.1, 2.273651152924463),
+    (-1.9, 1.7162192812196366),
+    (-3.1, 0.4003116967039849),
+    (-5.7, -4.667768588522342),
+    (-20.3, -41.887292480135244),
+    (-150.25, -604.7825011297857),
+    (-1.000000001, 20.72326575378326),
+    (-0.999999999, 20.72326586565113),
+    (-1.001, 6.907333817182055),
+    (-0.999, 6.908179385717436),
+    (-2.000000002, 19.336971391240585),
+    (-1.999999998, 19.336971505954022),
+    (-2.002, 5.519621139274358),
+    (-1.998, 5.52331227620367),
+    (-7.000000007, 10.252194356858455),
+    (-6.999999993, 10.252194385077434),
+    (-7.007, -3.5773483792610925),
+    (-6.993, -3.5491294005933667),
+    (-100.0000001, -347.62128030625314),
+    (-99.9999999, -347.6212793842208),
+    (-100.1, -361.881352583709),
+    (-99.9, -360.9593202461631),
+    (2.55e+305, 1.7906725941420033e+308),
+    (2.5563e+305, 1.7951029165623062e+308),
+    (2.5e+305, 1.7555118602376452e+308),
+    (1e+305, 7.012884533631839e+307),
+    (1e+306, f64::INFINITY),
+];
+
+pub(super) const TGAMMA_F64: [(f64, f64); 104] = [
+    (0.01, 99.4325851191506),
+    (2.9183050847457626, 1.8572418835378068),
+    (5.826610169491525, 89.51649238614614),
+    (8.734915254237288, 22955.751209550355),
+    (11.64322033898305, 16791860.14646539),
+    (14.551525423728814, 26458676968.200134),
+    (17.459830508474578, 76426289077102.95),
+    (20.36813559322034, 3.643503714543648e+17),
+    (23.276440677966104, 2.662619593833554e+21),
+    (26.184745762711866, 2.8235342874719e+25),
+    (29.09305084745763, 4.164696272087322e+29),
+    (32.00135593220339, 8.261395498687806e+33),
+    (34.90966101694915, 2.144320147118387e+38),
+    (37.817966101694914, 7.118683135914753e+42),
+    (40.72627118644068, 2.9650902414085157e+47),
+    (43.63457627118644, 1.5242940598832776e+52),
+    (46.5428813559322, 9.535006786111947e+56),
+    (49.451186440677965, 7.168110000601786e+61),
+    (52.35949152542373, 6.405769719008059e+66),
+    (55.26779661016949, 6.739163816375843e+71),
+    (58.17610169491525, 8.274505338149446e+76),
+    (61.084406779661016, 1.176503053984774e+82),
+    (63.99271186440678, 1.9235266730622305e+87),
+    (66.90101694915255, 3.5931665260314653e+92),
+    (69.80932203389831, 7.624196224609477e+97),
+    (72.71762711864407, 1.827784658486e+103),
+    (75.62593220338984, 4.926450971661005e+108),
+    (78.5342372881356, 1.4861098598856215e+114),
+    (81.44254237288136, 4.996336751925989e+119),
+    (84.35084745762711, 1.864850724610666e+125),
+    (87.25915254237289, 7.699332078413589e+130),
+    (90.16745762711865, 3.504364774328404e+136),
+    (93.07576271186441, 1.7528262580847442e+142),
+    (95.98406779661018, 9.606255782673988e+147),
+    (98.89237288135594, 5.752359332592141e+153),
+    (101.80067796610172, 3.7538493286134175e+159),
+    (104.70898305084746, 2.6630196192251057e+165),
+    (107.61728813559323, 2.0489256448964267e+171),
+    (110.52559322033899, 1.705983912858786e+177),
+    (113.43389830508475, 1.5339591522036493e+183),
+    (116.34220338983052, 1.4865610014024906e+189),
+    (119.25050847457628, 1.5497616048620933e+195),
+    (122.15881355932204, 1.7349359551343467e+201),
+    (125.0671186440678, 2.0820861964532743e+207),
+    (127.97542372881357, 2.674271745997545e+213),
+    (130.88372881355932, 3.6705486955912976e+219),
+    (133.79203389830508, 5.375664361960769e+225),
+    (136.70033898305084, 8.388689915714257e+231),
+    (139.6086440677966, 1.3929270839887354e+238),
+    (142.51694915254237, 2.4579378128718912e+244),
+    (145.42525423728813, 4.603422005378192e+250),
+    (148.3335593220339, 9.139846782420455e+256),
+    (151.24186440677965, 1.9215271989031528e+263),
+    (154.15016949152542, 4.272914147884769e+269),
+    (157.05847457627118, 1.0039462862082559e+276),
+    (159.96677966101694, 2.4897817555304387e+282),
+    (162.8750847457627, 6.511013442251482e+288),
+    (165.78338983050847, 1.7937411165370508e+295),
+    (168.6916949152542, 5.201117540722036e+301),
+    (171.6, 1.5858969096672565e+308),
+    (4.523457922649097, 12.017752108897273),
+    (5.332854375791709, 40.09865228284478),
+    (4.7803631803208475, 17.334202607603643),
+    (9.415011275385007, 99007.67171588037),
+    (6.992178821802858, 709.5340806013955),
+    (8.765354817805934, 24479.145200974817),
+    (9.421805883035756, 100491.01200613745),
+    (2.595922941176907, 1.4252591963814494),
+    (5.5951380649771485, 61.06792502113646),
+    (9.432670340134838, 102910.2673395818),
+    (8.399997833932058, 11405.836739922835),
+    (1.3713443589685148, 0.8892036553710638),
+    (1.2162195438418066, 0.9140257634114649),
+    (4.421180882750436, 10.433738687677472),
+    (0.7254609965648828, 1.2594883578440605),
+    (2.4063875845326987, 1.2473736762636631),
+    (0.7312076697267433, 1.2512481988776962),
+    (6.694721453098957, 409.4436994489655),
+    (7.839360171731552, 3652.2519587494053),
+    (8.970264328787668, 37835.43985908255),
+    (1.5444662376869212, 0.8884761997045926),
+    (7.161198827881962, 975.6662437058108),
+    (6.6025651519137085, 346.3057266026849),
+    (1.4297899792423718, 0.8860420356200117),
+    (8.828328336570754, 27969.18861051687),
+    (9.675447826663838, 175715.08706074406),
+    (2.195878308019197, 1.0993388209591148),
+    (9.525041289189863, 126046.02281510511),
+    (3.982568747172719, 5.87030713322511),
+    (4.872607749908802, 19.84593228933308),
+    (1e-300, 9.999999999999999e+299),
+    (7.943282347242399e-271, 1.258925411794233e+270),
+    (6.309573444802097e-241, 1.5848931924610721e+240),
+    (5.0118723362725915e-211, 1.995262314968932e+210),
+    (3.9810717055349206e-181, 2.5118864315096126e+180),
+    (3.162277660168379e-151, 3.1622776601683797e+150),
+    (2.511886431509613e-121, 3.98107170553492e+120),
+    (1.9952623149689318e-91, 5.011872336272592e+90),
+    (1.584893192461072e-61, 6.3095734448020974e+60),
+    (1.2589254117942332e-31, 7.943282347242399e+30),
+    (0.1, 9.51350769866873),
+    (171.6243769563027, 1.7976931348622299e+308),
+    (171.625, f64::INFINITY),
+    (172.0, f64::INFINITY),
+];
+
+pub(super) const TGAMMA_NEGATIVE_F64: [(f64, f64); 34] = [
+    (-0.5, -3.544907701811032),
+    (-1.5, 2.363271801207355),
+    (-2.5, -0.9453087204829419),
+    (-3.5, 0.2700882058522691),
+    (-10.5, -2.640121820547716e-07),
+    (-100.5, -3.3536908198076787e-159),
+    (-170.5, -3.3127395215386074e-308),
+    (-171.5, 1.9316265431712e-310),
+    (-177.5, 5e-324),
+    (-183.5, 0.0),
+    (-184.5, -0.0),
+    (-185.5, 0.0),
+    (-0.1, -10.686287021193193),
+    (-0.9, -10.570564109631926),
+    (-1.1, 9.714806382902895),
+    (-5.7, 0.009393206267354336),
+    (-20.3, -6.435466204989327e-19),
+    (-150.25, -2.2197139309494306e-263),
+    (-1.000000001, 999999916.8368515),
+    (-0.999999999, -1000000028.7047166),
+    (-1.001, 999.5786270024664),
+    (-0.999, -1000.4241966812758),
+    (-2.000000002, -249999978.8535168),
+    (-1.999999998, 250000007.53187522),
+    (-2.002, -249.54047819183475),
+    (-1.998, 250.4632682908412),
+    (-7.000000007, 28344.67205308311),
+    (-6.999999993, -28344.67285294084),
+    (-7.007, 0.02794971211983479),
+    (-6.993, -0.028749658197462395),
+    (-100.0000001, -1.0715098577556282e-151),
+    (-99.9999999, 1.0715108457227992e-151),
+    (-100.1, -6.869505399212017e-158),
+    (-99.9, 1.72726520939333e-157),
+];
@@ -0,0 +1,522 @@
+use crate::*;
+use core::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI};
+
+/// `sqrt(2 / pi)`, the scale inside the tanh approximation of GELU.
+const SQRT_2_OVER_PI: f64 = FRAC_2_SQRT_PI * FRAC_1_SQRT_2;
+
+/// The cubic coefficient of the tanh approximation of GELU.
+const GELU_TANH_CUBIC: f64 = 0.044_715;
+
+/// Creates a raw SIMD vector filled with `value` rounded to `T`.
+/// # Safety
+/// Make sure `T::is_available()` returns true.
+#[inline(always)]
+unsafe fn splat<T: SimdFloatingElement>(value: f64) -> T::Vector {
+    unsafe { T::set(T::from(value).unwrap()) }
+}
+
+/// Returns `(sigmoid(x), sigmoid(-x))`, both computed from `e^-|x|` so neither overflows or
+/// cancels.
+/// # Safety
+/// Make sure `T::is_available()` returns true.
+#[inline(always)]
+unsafe fn sigmoid_pair<T: SimdFloatingElement + SimdOrdElement + SimdSignedElement>(
+    x: T::Vector,
+) -> (T::Vector, T::Vector) {
+    unsafe {
+        let one = T::set(T::one());
+        let e = <T as SimdFloatingElement>::exp(<T as SimdSignedElement>::neg(
+            <T as SimdSignedElement>::abs(x),
+        ));
+        let d = <T as SimdNumElement>::add(one, e);
+        let big = <T as SimdNumElement>::div(one, d);
+        let small = <T as SimdNumElement>::div(e, d);
+        let negative = <T as SimdOrdElement>::lt(x, T::set(T::zero()));
+        (
+            T::blend(negative, big, small),
+            T::blend(negative, small, big),
+        )
+    }
+}
+
+/// Returns `x` where it is NaN and `value` elsewhere, so step functions still propagate NaN.
+/// # Safety
+/// Make sure `T::is_available()` returns true.
+#[inline(always)]
+unsafe fn keep_nan<T: SimdOrdElement>(x: T::Vector, value: T::Vector) -> T::Vector {
+    unsafe { T::blend(<T as SimdOrdElement>::eq(x, x), x, value) }
+}
+
+/// Activation functions and their derivatives.
+///
+/// Each `*_derivative` method replaces the elements, which are the inputs of the activation, with
+/// the derivative of the activation at that input, as needed for backpropagation.
+impl<'a, T: SimdFloatingElement + SimdOrdElement + SimdSignedElement> SimdMut<'a, T> {
+    /// Calculates the logistic function `1 / (1 + e^-x)` of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.0, f64::INFINITY, f64::NEG_INFINITY, -1000.0];
+    /// SimdMut::new(&mut values).sigmoid();
+    ///
+    /// assert_eq!(values, [0.5, 1.0, 0.0, 0.0]);
+    /// ```
+    pub fn sigmoid(&mut self) {
+        self.map_vectors(|x| unsafe { sigmoid_pair::<T>(x).0 });
+    }
+
+    /// Calculates the derivative of [`sigmoid`](Self::sigmoid), `sigmoid(x) * sigmoid(-x)`.
+    pub fn sigmoid_derivative(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let (s, c) = sigmoid_pair::<T>(x);
+            <T as SimdNumElement>::mul(s, c)
+        });
+    }
+
+    /// Replaces negative elements with zero.
+    pub fn relu(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let zero = T::set(T::zero());
+            T::blend(<T as SimdOrdElement>::lt(x, zero), x, zero)
+        });
+    }
+
+    /// Calculates the derivative of [`relu`](Self::relu): one for positive elements and zero
+    /// otherwise.
+    pub fn relu_derivative(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let zero = T::set(T::zero());
+            keep_nan::<T>(
+                x,
+                T::blend(<T as SimdOrdElement>::lt(zero, x), zero, T::set(T::one())),
+            )
+        });
+    }
+
+    /// Multiplies negative elements by `alpha`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [-2.0f32, -0.5, 0.0, 3.0];
+    /// SimdMut::new(&mut values).leaky_relu(0.1);
+    ///
+    /// assert_eq!(values, [-0.2, -0.05, 0.0, 3.0]);
+    /// ```
+    pub fn leaky_relu(&mut self, alpha: T) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let alpha = unsafe { T::set(alpha) };
+        self.map_vectors(|x| unsafe {
+            T::blend(
+                <T as SimdOrdElement>::lt(x, T::set(T::zero())),
+                x,
+                <T as SimdNumElement>::mul(x, alpha),
+            )
+        });
+    }
+
+    /// Calculates the derivative of [`leaky_relu`](Self::leaky_relu): one for positive elements
+    /// and `alpha` otherwise.
+    pub fn leaky_relu_derivative(&mut self, alpha: T) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let alpha = unsafe { T::set(alpha) };
+        self.map_vectors(|x| unsafe {
+            let positive = <T as SimdOrdElement>::lt(T::set(T::zero()), x);
+            keep_nan::<T>(x, T::blend(positive, alpha, T::set(T::one())))
+        });
+    }
+
+    /// Calculates the Gaussian error linear unit `x * (1 + erf(x / sqrt(2))) / 2` of each element.
+    pub fn gelu(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let half = splat::<T>(0.5);
+            let erf = <T as SimdFloatingElement>::erf(<T as SimdNumElement>::mul(
+                x,
+                splat::<T>(FRAC_1_SQRT_2),
+            ));
+            <T as SimdNumElement>::mul(
+                <T as SimdNumElement>::mul(half, x),
+                <T as SimdNumElement>::add(T::set(T::one()), erf),
+            )
+        });
+    }
+
+    /// Calculates the derivative of [`gelu`](Self::gelu),
+    /// `(1 + erf(x / sqrt(2))) / 2 + x * e^(-x^2 / 2) / sqrt(2 * pi)`.
+    pub fn gelu_derivative(&mut self) {
+        self.map_vectors(|x| unsafe {
+            // The derivative is exactly 0 or 1 well before `x * x` can overflow.
+            let x = <T as SimdOrdElement>::clamp(x, splat::<T>(-64.0), splat::<T>(64.0));
+            let half = splat::<T>(0.5);
+            let erf = <T as SimdFloatingElement>::erf(<T as SimdNumElement>::mul(
+                x,
+                splat::<T>(FRAC_1_SQRT_2),
+            ));
+            let cdf =
+                <T as SimdNumElement>::mul(half, <T as SimdNumElement>::add(T::set(T::one()), erf));
+            let pdf = <T as SimdNumElement>::mul(
+                <T as SimdFloatingElement>::exp(<T as SimdNumElement>::mul(
+                    <T as SimdSignedElement>::neg(half),
+                    <T as SimdNumElement>::mul(x, x),
+                )),
+                splat::<T>(SQRT_2_OVER_PI * 0.5),
+            );
+            <T as SimdNumElement>::fma(x, pdf, cdf)
+        });
+    }
+
+    /// Calculates the tanh approximation of [`gelu`](Self::gelu),
+    /// `x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3))) / 2`.
+    pub fn gelu_tanh(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let inner = <T as SimdNumElement>::mul(
+                splat::<T>(SQRT_2_OVER_PI),
+                <T as SimdNumElement>::fma(
+                    <T as SimdNumElement>::mul(splat::<T>(GELU_TANH_CUBIC), x),
+                    <T as SimdNumElement>::mul(x, x),
+                    x,
+                ),
+            );
+            let tanh = <T as SimdFloatingElement>::tanh(inner);
+            <T as SimdNumElement>::mul(
+                <T as SimdNumElement>::mul(splat::<T>(0.5), x),
+                <T as SimdNumElement>::add(T::set(T::one()), tanh),
+            )
+        });
+    }
+
+    /// Calculates the derivative of [`gelu_tanh`](Self::gelu_tanh).
+    pub fn gelu_tanh_derivative(&mut self) {
+        self.map_vectors(|x| unsafe {
+            // The derivative is exactly 0 or 1 well before `x * x` can overflow.
+            let x = <T as SimdOrdElement>::clamp(x, splat::<T>(-64.0), splat::<T>(64.0));
+            let one = T::set(T::one());
+            let half = splat::<T>(0.5);
+            let x2 = <T as SimdNumElement>::mul(x, x);
+            let inner = <T as SimdNumElement>::mul(
+                splat::<T>(SQRT_2_OVER_PI),
+                <T as SimdNumElement>::fma(
+                    <T as SimdNumElement>::mul(splat::<T>(GELU_TANH_CUBIC), x),
+                    x2,
+                    x,
+                ),
+            );
+            let tanh = <T as SimdFloatingElement>::tanh(inner);
+            let inner_derivative = <T as SimdNumElement>::mul(
+                splat::<T>(SQRT_2_OVER_PI),
+                <T as SimdNumElement>::fma(splat::<T>(3.0 * GELU_TANH_CUBIC), x2, one),
+            );
+            // 1 - tanh^2 as a product, which keeps its accuracy where tanh is close to 1.
+            let sech2 = <T as SimdNumElement>::mul(
+                <T as SimdNumElement>::sub(one, tanh),
+                <T as SimdNumElement>::add(one, tanh),
+            );
+            <T as SimdNumElement>::fma(
+                <T as SimdNumElement>::mul(<T as SimdNumElement>::mul(half, x), sech2),
+                inner_derivative,
+                <T as SimdNumElement>::mul(half, <T as SimdNumElement>::add(one, tanh)),
+            )
+        });
+    }
+
+    /// Calculates the sigmoid linear unit `x * sigmoid(x)` of each element, also known as swish.
+    pub fn silu(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdNumElement>::mul(x, sigmoid_pair::<T>(x).0) });
+    }
+
+    /// Calculates the derivative of [`silu`](Self::silu), `sigmoid(x) * (1 + x * sigmoid(-x))`.
+    pub fn silu_derivative(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let (s, c) = sigmoid_pair::<T>(x);
+            <T as SimdNumElement>::mul(s, <T as SimdNumElement>::fma(x, c, T::set(T::one())))
+        });
+    }
+
+    /// Calculates `ln(1 + e^x)` of each element without overflowing for large `x`.
+    pub fn softplus(&mut self) {
+        self.map_vectors(|x| unsafe {
+            let e = <T as SimdFloatingElement>::exp(<T as SimdSignedElement>::neg(
+                <T as SimdSignedElement>::abs(x),
+            ));
+            <T as SimdNumElement>::add(
+                <T as SimdOrdElement>::max(x, T::set(T::zero())),
+                <T as SimdFloatingElement>::ln_1p(e),
+            )
+        });
+    }
+
+    /// Calculates the derivative of [`softplus`](Self::softplus), which is
+    /// [`sigmoid`](Self::sigmoid).
+    pub fn softplus_derivative(&mut self) {
+        self.sigmoid();
+    }
+
+    /// Calculates the exponential linear unit of each element: `x` for positive elements and
+    /// `alpha * (e^x - 1)` otherwise.
+    pub fn elu(&mut self, alpha: T) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let alpha = unsafe { T::set(alpha) };
+        self.map_vectors(|x| unsafe {
+            let negative = <T as SimdNumElement>::mul(alpha, <T as SimdFloatingElement>::expm1(x));
+            T::blend(<T as SimdOrdElement>::lt(T::set(T::zero()), x), negative, x)
+        });
+    }
+
+    /// Calculates the derivative of [`elu`](Self::elu): one for positive elements and
+    /// `alpha * e^x` otherwise.
+    pub fn elu_derivative(&mut self, alpha: T) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let alpha = unsafe { T::set(alpha) };
+        self.map_vectors(|x| unsafe {
+            let negative = <T as SimdNumElement>::mul(alpha, <T as SimdFloatingElement>::exp(x));
+            T::blend(
+                <T as SimdOrdElement>::lt(T::set(T::zero()), x),
+                negative,
+                T::set(T::one()),
+            )
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    struct Activation<T: SimdElement> {
+        name: &'static str,
+        forward: fn(&mut SimdMut<T>),
+        derivative: fn(&mut SimdMut<T>),
+        reference: fn(f64) -> f64,
+        derivative_reference: fn(f64) -> f64,
+    }
+
+    const ALPHA: f64 = 0.25;
+
+    fn activations<T: SimdFloatingElement + SimdOrdElement + SimdSignedElement>()
+    -> [Activation<T>; 8] {
+        [
+            Activation {
+                name: "sigmoid",
+                forward: |x| x.sigmoid(),
+                derivative: |x| x.sigmoid_derivative(),
+                reference: sigmoid,
+                derivative_reference: |x| sigmoid(x) * sigmoid(-x),
+            },
+            Activation {
+                name: "relu",
+                forward: |x| x.relu(),
+                derivative: |x| x.relu_derivative(),
+                reference: |x| x.max(0.0),
+                derivative_reference: |x| if x > 0.0 { 1.0 } else { 0.0 },
+            },
+            Activation {
+                name: "leaky_relu",
+                forward: |x| x.leaky_relu(T::from(ALPHA).unwrap()),
+                derivative: |x| x.leaky_relu_derivative(T::from(ALPHA).unwrap()),
+                reference: |x| if x > 0.0 { x } else { ALPHA * x },
+                derivative_reference: |x| if x > 0.0 { 1.0 } else { ALPHA },
+            },
+            Activation {
+                name: "gelu",
+                forward: |x| x.gelu(),
+                derivative: |x| x.gelu_derivative(),
+                reference: |x| 0.5 * x * erfc(-x * FRAC_1_SQRT_2),
+                derivative_reference: |x| {
+                    0.5 * erfc(-x * FRAC_1_SQRT_2) + x * (-0.5 * x * x).exp() / (2.0 * PI).sqrt()
+                },
+            },
+            Activation {
+                name: "gelu_tanh",
+                forward: |x| x.gelu_tanh(),
+                derivative: |x| x.gelu_tanh_derivative(),
+                reference: |x| {
+                    0.5 * x * (1.0 + (SQRT_2_OVER_PI * (x + GELU_TANH_CUBIC * x * x * x)).tanh())
+                },
+                derivative_reference: |x| {
+                    let t = (SQRT_2_OVER_PI * (x + GELU_TANH_CUBIC * x * x * x)).tanh();
+                    let inner = SQRT_2_OVER_PI * (1.0 + 3.0 * GELU_TANH_CUBIC * x * x);
+                    0.5 * (1.0 + t) + 0.5 * x * (1.0 - t) * (1.0 + t) * inner
+                },
+            },
+            Activation {
+                name: "silu",
+                forward: |x| x.silu(),
+                derivative: |x| x.silu_derivative(),
+                reference: |x| x * sigmoid(x),
+                derivative_reference: |x| sigmoid(x) * (1.0 + x * sigmoid(-x)),
+            },
+            Activation {
+                name: "softplus",
+                forward: |x| x.softplus(),
+                derivative: |x| x.softplus_derivative(),
+                reference: |x| x.max(0.0) + (-x.abs()).exp().ln_1p(),
+                derivative_reference: sigmoid,
+            },
+            Activation {
+                name: "elu",
+                forward: |x| x.elu(T::from(ALPHA).unwrap()),
+                derivative: |x| x.elu_derivative(T::from(ALPHA).unwrap()),
+                reference: |x| if x > 0.0 { x } else { ALPHA * x.exp_m1() },
+                derivative_reference: |x| if x > 0.0 { 1.0 } else { ALPHA * x.exp() },
+            },
+        ]
+    }
+
+    fn sigmoid(x: f64) -> f64 {
+        if x < 0.0 {
+            x.exp() / (1.0 + x.exp())
+        } else {
+            1.0 / (1.0 + (-x).exp())
+        }
+    }
+
+    /// The complementary error function, by a series of erf below 3 and a continued fraction
+    /// above, accurate to about `1e-15`.
+    fn erfc(x: f64) -> f64 {
+        if x < 0.0 {
+            return 2.0 - erfc(-x);
+        }
+        if x > 3.0 {
+            let mut fraction = x;
+            for n in (1..=60).rev() {
+                fraction = x + n as f64 * 0.5 / fraction;
+            }
+            return (-x * x).exp() / (PI.sqrt() * fraction);
+        }
+
+        // A series of positive terms, which does not cancel like the Taylor series.
+        let mut term = x;
+        let mut sum = x;
+        for n in 1..100 {
+            term *= 2.0 * x * x / (2 * n + 1) as f64;
+            sum += term;
+        }
+        1.0 - FRAC_2_SQRT_PI * (-x * x).exp() * sum
+    }
+
+    fn apply<T: SimdElement + Copy>(f: fn(&mut SimdMut<T>), inputs: &[T]) -> Vec<T> {
+        let mut values = inputs.to_vec();
+        f(&mut SimdMut::new(&mut values));
+        values
+    }
+
+    /// Returns the inputs in `[-20, 20]` with steps of `1 / 64`.
+    fn inputs() -> Vec<f64> {
+        (-1280..=1280).map(|i| i as f64 / 64.0).collect()
+    }
+
+    #[test]
+    fn scalar_reference() {
+        let inputs = inputs();
+        for activation in activations::<f64>() {
+            let name = activation.name;
+            let values = apply(activation.forward, &inputs);
+            let derivatives = apply(activation.derivative, &inputs);
+            for (i, &x) in inputs.iter().enumerate() {
+                let expected = (activation.reference)(x);
+                assert!(
+                    (values[i] - expected).abs() <= 1e-13 * (1.0 + expected.abs()),
+                    "{name}({x}) = {}, expected {expected}",
+                    values[i]
+                );
+                let expected = (activation.derivative_reference)(x);
+                assert!(
+                    (derivatives[i] - expected).abs() <= 1e-13 * (1.0 + expected.abs()),
+                    "{name}'({x}) = {}, expected {expected}",
+                    derivatives[i]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finite_difference() {
+        const H: f64 = 1e-5;
+
+        // The kinks at zero have no derivative to compare with.
+        let inputs: Vec<f64> = inputs().into_iter().filter(|x| *x != 0.0).collect();
+        let above: Vec<f64> = inputs.iter().map(|x| x + H).collect();
+        let below: Vec<f64> = inputs.iter().map(|x| x - H).collect();
+        for activation in activations::<f64>() {
+            let name = activation.name;
+            let derivatives = apply(activation.derivative, &inputs);
+            let above = apply(activation.forward, &above);
+            let below = apply(activation.forward, &below);
+            for (i, &x) in inputs.iter().enumerate() {
+                let expected = (above[i] - below[i]) / (2.0 * H);
+                assert!(
+                    (derivatives[i] - expected).abs() <= 1e-7 * (1.0 + expected.abs()),
+                    "{name}'({x}) = {}, finite difference {expected}",
+                    derivatives[i]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn f32_matches_f64() {
+        let inputs = inputs();
+        let inputs_f32: Vec<f32> = inputs.iter().map(|&x| x as f32).collect();
+        for (wide, narrow) in activations::<f64>().into_iter().zip(activations::<f32>()) {
+            let name = wide.name;
+            let pairs = [
+                (wide.forward, narrow.forward),
+                (wide.derivative, narrow.derivative),
+            ];
+            for (wide, narrow) in pairs {
+                let expected = apply(wide, &inputs);
+                let values = apply(narrow, &inputs_f32);
+                for (i, &x) in inputs.iter().enumerate() {
+                    assert!(
+                        (values[i] as f64 - expected[i]).abs() <= 1e-6 * (1.0 + expected[i].abs()),
+                        "{name} at {x}: {} in f32, {} in f64",
+                        values[i],
+                        expected[i]
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        let inputs = [f64::NAN, -1000.0, 1000.0];
+        let expected = [
+            ("sigmoid", [0.0, 1.0], [0.0, 0.0]),
+            ("relu", [0.0, 1000.0], [0.0, 1.0]),
+            ("leaky_relu", [-1000.0 * ALPHA, 1000.0], [ALPHA, 1.0]),
+            ("gelu", [0.0, 1000.0], [0.0, 1.0]),
+            ("gelu_tanh", [0.0, 1000.0], [0.0, 1.0]),
+            ("silu", [0.0, 1000.0], [0.0, 1.0]),
+            ("softplus", [0.0, 1000.0], [0.0, 1.0]),
+            ("elu", [-ALPHA, 1000.0], [0.0, 1.0]),
+        ];
+        for (activation, (name, values, derivatives)) in
+            activations::<f64>().into_iter().zip(expected)
+        {
+            assert_eq!(activation.name, name);
+            let result = apply(activation.forward, &inputs);
+            assert!(result[0].is_nan(), "{name}(NaN) = {}", result[0]);
+            assert_eq!(result[1..], values, "{name}");
+            let result = apply(activation.derivative, &inputs);
+            assert!(result[0].is_nan(), "{name}'(NaN) = {}", result[0]);
+            assert_eq!(result[1..], derivatives, "{name}'");
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::*;
+
+/// Largest `SimdConvert::BLOCK_LEN`, the size of the buffers for the remainder.
+const MAX_BLOCK_LEN: usize = 32;
+
+/// Converts the first `min(src.len(), dst.len())` elements a block at a time. The remainder is
+/// copied to a buffer and converted as a whole block too.
+#[inline(always)]
+fn convert_elements<const ROUND: bool, T: SimdConvert<U>, U: SimdElement + Copy + Default>(
+    src: &[T],
+    dst: &mut [U],
+) {
+    if !T::is_available() || !U::is_available() {
+        panic!("simd is not available");
+    }
+
+    #[inline(always)]
+    unsafe fn block<const ROUND: bool, T: SimdConvert<U>, U: SimdElement + Copy + Default>(
+        src: *const T,
+        dst: *mut U,
+    ) {
+        unsafe {
+            if ROUND {
+                T::convert_round_block(src, dst);
+            } else {
+                T::convert_block(src, dst);
+            }
+        }
+    }
+
+    let len = src.len().min(dst.len());
+    let src = src.as_ptr();
+    let dst = dst.as_mut_ptr();
+
+    unsafe {
+        for i in 0..len / T::BLOCK_LEN {
+            let offset = i * T::BLOCK_LEN;
+            block::<ROUND, T, U>(src.add(offset), dst.add(offset));
+        }
+
+        let remaining = len % T::BLOCK_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            let mut src_buff = [T::default(); MAX_BLOCK_LEN];
+            let mut dst_buff = [U::default(); MAX_BLOCK_LEN];
+            src.add(offset)
+                .copy_to_nonoverlapping(src_buff.as_mut_ptr(), remaining);
+            block::<ROUND, T, U>(src_buff.as_ptr(), dst_buff.as_mut_ptr());
+            dst_buff
+                .as_ptr()
+                .copy_to_nonoverlapping(dst.add(offset), remaining);
+        }
+    }
+}
+
+/// Stores the elements of `src` converted to `U` to `dst`, truncating floating point towards zero
+/// when converting to integers. See [`SimdConvert`] for how out-of-range values are handled.
+///
+/// Converts `min(src.len(), dst.len())` elements.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let pixels = [0u8, 128, 255];
+/// let mut values = [0.0f32; 3];
+/// convert_into(&pixels, &mut values);
+///
+/// assert_eq!(values, [0.0, 128.0, 255.0]);
+///
+/// let values = [-3.7f32, 99.9, 300.0, f32::NAN];
+/// let mut pixels = [0u8; 4];
+/// convert_into(&values, &mut pixels);
+///
+/// assert_eq!(pixels, [0, 99, 255, 0]);
+/// ```
+pub fn convert_into<T: SimdConvert<U>, U: SimdElement + Copy + Default>(src: &[T], dst: &mut [U]) {
+    convert_elements::<false, T, U>(src, dst);
+}
+
+/// Stores the elements of `src` converted to `U` to `dst` like [`convert_into`], except that
+/// floating point is rounded to the nearest integer, ties to even, when converting to integers.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let values = [0.5f32, 1.5, 2.6, -7.5, 3e9];
+/// let mut integers = [0i32; 5];
+/// convert_round_into(&values, &mut integers);
+///
+/// assert_eq!(integers, [0, 2, 3, -8, i32::MAX]);
+/// ```
+pub fn convert_round_into<T: SimdConvert<U>, U: SimdElement + Copy + Default>(
+    src: &[T],
+    dst: &mut [U],
+) {
+    convert_elements::<true, T, U>(src, dst);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::fmt::Debug;
+
+    /// Element types with values worth converting: the bounds of every other type, ties and
+    /// special floating point values.
+    trait Samples: Copy {
+        fn samples() -> Vec<Self>;
+    }
+
+    const INTEGERS: [i64; 20] = [
+        0,
+        1,
+        -1,
+        127,
+        128,
+        -128,
+        -129,
+        255,
+        256,
+        32_767,
+        32_768,
+        -32_768,
+        -32_769,
+        65_535,
+        65_536,
+        16_777_217,
+        2_147_483_647,
+        -2_147_483_648,
+        4_294_967_295,
+        -4_294_967_296,
+    ];
+
+    impl Samples for i8 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as i8).collect()
+        }
+    }
+
+    impl Samples for u8 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as u8).collect()
+        }
+    }
+
+    impl Samples for i16 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as i16).collect()
+        }
+    }
+
+    impl Samples for u16 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as u16).collect()
+        }
+    }
+
+    impl Samples for i32 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as i32).collect()
+        }
+    }
+
+    impl Samples for u32 {
+        fn samples() -> Vec<Self> {
+            INTEGERS.iter().map(|&x| x as u32).collect()
+        }
+    }
+
+    impl Samples for f64 {
+        fn samples() -> Vec<Self> {
+            let mut samples: Vec<f64> = INTEGERS.iter().map(|&x| x as f64).collect();
+            samples.extend([
+                -0.0,
+                0.5,
+                1.5,
+                -2.5,
+                254.5,
+                255.5,
+                -0.7,
+                65_535.5,
+                2_147_483_647.5,
+                -2_147_483_648.5,
+                2_147_483_648.0,
+                1e300,
+                -1e300,
+                3.4e38,
+                1e-300,
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                f64::NAN,
+            ]);
+            samples
+        }
+    }
+
+    impl Samples for f32 {
+        fn samples() -> Vec<Self> {
+            let mut samples: Vec<f32> = f64::samples().iter().map(|&x| x as f32).collect();
+            samples.extend([2_147_483_520.0, -2_147_483_904.0, 16_777_215.0]);
+            samples
+        }
+    }
+
+    /// Checks `convert_into` and `convert_round_into` against `convert` and `convert_round` on
+    /// several blocks and a remainder.
+    fn check<T: SimdConvert<U> + Samples, U: SimdElement + Copy + Default + Debug>() {
+        let samples = T::samples();
+        let len = 3 * T::BLOCK_LEN.max(samples.len()) + 5;
+        let src: Vec<T> = (0..len).map(|i| samples[i * 7 % samples.len()]).collect();
+
+        let mut dst = vec![U::default(); len];
+        convert_into(&src, &mut dst);
+        for (&x, y) in src.iter().zip(&dst) {
+            assert_eq!(format!("{:?}", T::convert(x)), format!("{y:?}"));
+        }
+
+        let mut dst = vec![U::default(); len];
+        convert_round_into(&src, &mut dst);
+        for (&x, y) in src.iter().zip(&dst) {
+            assert_eq!(format!("{:?}", T::convert_round(x)), format!("{y:?}"));
+        }
+    }
+
+    #[test]
+    fn int_to_float() {
+        check::<i32, f32>();
+        check::<i32, f64>();
+        check::<u8, f32>();
+        check::<i16, f32>();
+        check::<u16, f32>();
+        check::<u32, f32>();
+    }
+
+    #[test]
+    fn float_to_float() {
+        check::<f32, f64>();
+        check::<f64, f32>();
+    }
+
+    #[test]
+    fn float_to_int() {
+        check::<f32, i32>();
+        check::<f64, i32>();
+        check::<f32, u8>();
+        check::<f32, i16>();
+        check::<f32, u16>();
+    }
+
+    #[test]
+    fn widening() {
+        check::<u8, u16>();
+        check::<u8, u32>();
+        check::<u16, u32>();
+        check::<u32, u64>();
+        check::<i8, i16>();
+        check::<i16, i32>();
+        check::<i32, i64>();
+    }
+
+    #[test]
+    fn narrowing() {
+        check::<i16, i8>();
+        check::<i16, u8>();
+        check::<u16, u8>();
+        check::<i32, i16>();
+        check::<i32, u16>();
+        check::<i32, u8>();
+        check::<u32, u16>();
+    }
+
+    #[test]
+    fn scalar_reference() {
+        assert_eq!(<f32 as SimdConvert<i32>>::convert(f32::NAN), 0);
+        assert_eq!(<f32 as SimdConvert<i32>>::convert(3e9), i32::MAX);
+        assert_eq!(<f32 as SimdConvert<u8>>::convert_round(254.5), 254);
+        assert_eq!(<i16 as SimdConvert<i8>>::convert(-300), i8::MIN);
+        assert_eq!(<u32 as SimdConvert<u16>>::convert(70_000), u16::MAX);
+    }
+}
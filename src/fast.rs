@@ -0,0 +1,45 @@
+//! Transcendental functions that trade accuracy for speed, each within 3 ULP.
+//!
+//! The `f32` versions are computed in `f32` instead of going through `f64` like the methods of
+//! [`SimdMut`]. All versions use polynomials of lower degree and skip the double-double steps
+//! that keep the methods of [`SimdMut`] within 1 ULP.
+//!
+//! # Example
+//! ```
+//! use carbon_simd::*;
+//!
+//! let mut values = [0.0f32, 1.0, -1.0, 0.5];
+//! fast::exp(&mut SimdMut::new(&mut values));
+//!
+//! let expected = [1.0f32, 1.0f32.exp(), (-1.0f32).exp(), 0.5f32.exp()];
+//! for (value, expected) in values.iter().zip(expected) {
+//!     assert!((value - expected).abs() <= 3.0 * expected * f32::EPSILON);
+//! }
+//! ```
+
+use crate::*;
+
+/// Calculates `e^x` for each element.
+pub fn exp<T: SimdFastElement>(x: &mut SimdMut<T>) {
+    x.map_vectors(|x| unsafe { T::exp_fast(x) });
+}
+
+/// Calculates tanh of each element.
+pub fn tanh<T: SimdFastElement>(x: &mut SimdMut<T>) {
+    x.map_vectors(|x| unsafe { T::tanh_fast(x) });
+}
+
+/// Calculates the natural logarithm of each element.
+pub fn ln<T: SimdFastElement>(x: &mut SimdMut<T>) {
+    x.map_vectors(|x| unsafe { T::ln_fast(x) });
+}
+
+/// Calculates the sine of each element in radians.
+pub fn sin<T: SimdFastElement>(x: &mut SimdMut<T>) {
+    x.map_vectors(|x| unsafe { T::sin_fast(x) });
+}
+
+/// Calculates the cosine of each element in radians.
+pub fn cos<T: SimdFastElement>(x: &mut SimdMut<T>) {
+    x.map_vectors(|x| unsafe { T::cos_fast(x) });
+}
@@ -0,0 +1,161 @@
+use crate::*;
+
+/// Stores `f` of the vectors of `inputs` to `out`, for the first `len` elements.
+/// # Safety
+/// Make sure `T::is_available()` returns true and lengths of `out` and `inputs` are not less than
+/// `len`.
+#[inline(always)]
+unsafe fn map_inputs<T: SimdElement, const N: usize>(
+    out: *mut T,
+    inputs: [*const T; N],
+    len: usize,
+    f: impl Fn([T::Vector; N]) -> T::Vector,
+) {
+    unsafe {
+        for i in 0..len / T::VECTOR_LEN {
+            let offset = i * T::VECTOR_LEN;
+            T::store(out.add(offset), f(inputs.map(|x| T::load(x.add(offset)))));
+        }
+
+        let remaining = len % T::VECTOR_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            let vectors = inputs.map(|x| T::load_partial(x.add(offset), remaining));
+            T::store_partial(out.add(offset), f(vectors), remaining);
+        }
+    }
+}
+
+/// Stores the lengths `sqrt(x^2 + y^2)` of the two-component vectors given as separate
+/// component slices to `out`.
+///
+/// The squares are not scaled, so components above about `1e19` for `f32` overflow. Use
+/// [`SimdMut::hypot`] where that matters.
+pub fn length2<T: SimdFloatingElement, X: Simd<T>, Y: Simd<T>>(x: &X, y: &Y, out: &mut SimdMut<T>) {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = out.len().min(x.len()).min(y.len());
+    unsafe {
+        map_inputs(out.as_mut_ptr(), [x.as_ptr(), y.as_ptr()], len, |[x, y]| {
+            <T as SimdFloatingElement>::sqrt(T::fma(x, x, <T as SimdNumElement>::mul(y, y)))
+        });
+    }
+}
+
+/// Stores the lengths `sqrt(x^2 + y^2 + z^2)` of the three-component vectors given as separate
+/// component slices to `out`.
+///
+/// The squares are not scaled, so components above about `1e19` for `f32` overflow.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let x = [3.0f32, 0.0, 2.0];
+/// let y = [4.0f32, 0.0, 3.0];
+/// let z = [12.0f32, -1.0, 6.0];
+/// let mut lengths = [0.0; 3];
+///
+/// length3(
+///     &SimdRef::new(&x),
+///     &SimdRef::new(&y),
+///     &SimdRef::new(&z),
+///     &mut SimdMut::new(&mut lengths),
+/// );
+///
+/// assert_eq!(lengths, [13.0, 1.0, 7.0]);
+/// ```
+pub fn length3<T: SimdFloatingElement, X: Simd<T>, Y: Simd<T>, Z: Simd<T>>(
+    x: &X,
+    y: &Y,
+    z: &Z,
+    out: &mut SimdMut<T>,
+) {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = out.len().min(x.len()).min(y.len()).min(z.len());
+    unsafe {
+        map_inputs(
+            out.as_mut_ptr(),
+            [x.as_ptr(), y.as_ptr(), z.as_ptr()],
+            len,
+            |[x, y, z]| {
+                <T as SimdFloatingElement>::sqrt(T::fma(
+                    x,
+                    x,
+                    T::fma(y, y, <T as SimdNumElement>::mul(z, z)),
+                ))
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Checks `length2` and `length3` on every length up to three vectors of `T`, with inputs of
+    /// different lengths and an output longer than all of them.
+    fn check_lengths<T: SimdFloatingElement + num_traits::Float + core::fmt::Debug>(
+        from: fn(i32) -> T,
+    ) {
+        for len in 0..3 * T::VECTOR_LEN + 1 {
+            let component = |k: i32| -> Vec<T> {
+                (0..len as i32 + k)
+                    .map(|i| from((i * 7 + k) % 11 - 5))
+                    .collect()
+            };
+            let (x, y, z) = (component(2), component(0), component(1));
+            let sentinel = from(-1);
+
+            let mut out = vec![sentinel; len + 3];
+            length2(
+                &SimdRef::new(&x),
+                &SimdRef::new(&y),
+                &mut SimdMut::new(&mut out),
+            );
+            for i in 0..len {
+                let expected = x[i].mul_add(x[i], y[i] * y[i]).sqrt();
+                assert_eq!(out[i], expected, "length2 of {len} at {i}");
+            }
+            assert_eq!(out[len..], [sentinel; 3], "length2 of {len}");
+
+            let mut out = vec![sentinel; len + 3];
+            length3(
+                &SimdRef::new(&x),
+                &SimdRef::new(&y),
+                &SimdRef::new(&z),
+                &mut SimdMut::new(&mut out),
+            );
+            for i in 0..len {
+                let expected = x[i].mul_add(x[i], y[i].mul_add(y[i], z[i] * z[i])).sqrt();
+                assert_eq!(out[i], expected, "length3 of {len} at {i}");
+            }
+            assert_eq!(out[len..], [sentinel; 3], "length3 of {len}");
+
+            // A shorter output leaves the rest of the inputs unread.
+            let mut out = vec![sentinel; len];
+            length3(
+                &SimdRef::new(&x),
+                &SimdRef::new(&y),
+                &SimdRef::new(&z),
+                &mut SimdMut::new(&mut out[..len / 2]),
+            );
+            assert_eq!(
+                out[len / 2..],
+                vec![sentinel; len - len / 2],
+                "length3 of {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn lengths() {
+        check_lengths::<f32>(|x| x as f32);
+        check_lengths::<f64>(|x| x as f64);
+    }
+}
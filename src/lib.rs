@@ -17,9 +17,32 @@
 //! ```
 //!
 
+#[cfg(test)]
+mod accuracy;
+mod activation;
+mod convert;
+pub mod fast;
+mod length;
+mod mask;
+pub mod precise;
+mod reduce;
+mod softmax;
+mod widening;
 #[cfg(target_arch = "x86_64")]
 mod x86_64;
 
+pub use convert::convert_into;
+pub use convert::convert_round_into;
+pub use length::length2;
+pub use length::length3;
+pub use mask::SimdMask;
+pub use mask::select;
+pub use reduce::dot;
+pub use reduce::dot_compensated;
+pub use reduce::dot_deterministic;
+pub use reduce::sum_of_squares;
+pub use reduce::weighted_sum;
+
 use core::ops::*;
 use num_traits::Float;
 use num_traits::Num;
@@ -28,6 +51,8 @@ use num_traits::Num;
 pub trait Simd<T: SimdElement>: Deref<Target = [T]> {}
 
 /// A trait for types that may be used as SIMD vector elements.
+/// # Safety
+/// `Vector` must hold exactly `VECTOR_LEN` elements of `Self`, and every function must be sound when `is_available()` returns true.
 pub unsafe trait SimdElement: Sized {
     /// raw SIMD vector type like `__m256i`
     type Vector: Sized + Copy;
@@ -57,247 +82,1670 @@ pub unsafe trait SimdElement: Sized {
     /// Make sure `Self::is_available()` returns true and capacity of `dst` is not less than `len`.
     unsafe fn store_partial(dst: *mut Self, src: Self::Vector, len: usize);
 
-    /// Creates a raw SIMD vector filled with `value`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn set(value: Self) -> Self::Vector;
-}
+    /// Creates a raw SIMD vector filled with `value`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn set(value: Self) -> Self::Vector;
+
+    /// Returns a bit mask whose bit `i` is the most significant bit of lane `i` of `x`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn to_bitmask(x: Self::Vector) -> u32;
+
+    /// Returns a mask whose lane `i` is all set if bit `i` of `bits` is set.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector;
+
+    /// Selects lanes of `right` where `mask` is set, otherwise lanes of `left`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true and each lane of `mask` is all set or all clear.
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector;
+}
+
+/// A trait for type that may be used as numeric SIMD vector elements.
+///
+/// Integer `add`, `sub` and `mul` wrap around on overflow, like `wrapping_add` and friends.
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdNumElement: SimdElement + Num {
+    /// Adds `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn add(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Substructs `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn sub(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Multiples `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Divides `left` by `right`, then return the result.
+    /// Integer lanes divided by zero are left unchanged.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn div(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Calculates `a * b + c`. You should overload this when the architecture has dedicated operator.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    #[inline(always)]
+    unsafe fn fma(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self::Vector {
+        unsafe { <Self as SimdNumElement>::add(<Self as SimdNumElement>::mul(a, b), c) }
+    }
+
+    /// Returns the sum of all lanes of `x`. Integers wrap around on overflow.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn reduce_add(x: Self::Vector) -> Self;
+
+    /// Returns the product of all lanes of `x`. Integers wrap around on overflow.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn reduce_mul(x: Self::Vector) -> Self;
+
+    /// Returns the inclusive prefix sum of the lanes of `x`. Integers wrap around on overflow.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector;
+
+    /// Raises a number to an integer power by squaring, so it takes `O(log |n|)` multiplications.
+    /// Negative `n` takes the reciprocal of the positive power.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    #[inline(always)]
+    unsafe fn powi(x: Self::Vector, n: i32) -> Self::Vector {
+        let one = unsafe { Self::set(Self::one()) };
+        let mut result = one;
+        let mut base = x;
+        let mut e = n.unsigned_abs();
+        while e != 0 {
+            if e & 1 == 1 {
+                result = unsafe { <Self as SimdNumElement>::mul(result, base) };
+            }
+            e >>= 1;
+            if e != 0 {
+                base = unsafe { <Self as SimdNumElement>::mul(base, base) };
+            }
+        }
+
+        if n < 0 {
+            result = unsafe { <Self as SimdNumElement>::div(one, result) };
+        }
+        result
+    }
+}
+
+/// A trait for types that may be used as floating point SIMD vector elements.
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdFloatingElement: SimdNumElement + Float {
+    /// Returns sqrt of `x`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn sqrt(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `1 / x`, correctly rounded.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn recip(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `1 / sqrt(x)`, within 1 ULP. Zeros give infinity with their sign and negative
+    /// numbers give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn rsqrt(x: Self::Vector) -> Self::Vector;
+
+    /// Returns an estimate of `1 / x`, refined by `steps` Newton-Raphson iterations.
+    ///
+    /// For `f32` the estimate comes from `_mm256_rcp_ps`, with a relative error below `2^-11`.
+    /// One step brings it within 2 ULP and two steps within 1 ULP. Subnormal inputs give infinity
+    /// and subnormal results give zero. `f64` has no such instruction, so this is
+    /// [`recip`](Self::recip) and `steps` is ignored.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn recip_approx(x: Self::Vector, steps: u32) -> Self::Vector;
+
+    /// Returns an estimate of `1 / sqrt(x)`, refined by `steps` Newton-Raphson iterations.
+    ///
+    /// For `f32` the estimate comes from `_mm256_rsqrt_ps`, with a relative error below `2^-11`.
+    /// One step brings it within 4 ULP and two steps within 2 ULP. Subnormal inputs give
+    /// infinity. `f64` has no such instruction, so this is [`rsqrt`](Self::rsqrt) and `steps` is
+    /// ignored.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn rsqrt_approx(x: Self::Vector, steps: u32) -> Self::Vector;
+
+    /// Returns the cube root of `x`, within 1 ULP. Negative numbers give negative roots.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn cbrt(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `sqrt(x^2 + y^2)` without overflowing or underflowing in the squares, within 1 ULP.
+    /// An infinite argument gives infinity even if the other is NaN, like `f64::hypot`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn hypot(x: Self::Vector, y: Self::Vector) -> Self::Vector;
+
+    /// Returns `e^x`, within 1 ULP. Large inputs overflow to infinity and small ones underflow
+    /// through the subnormals to zero.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn exp(x: Self::Vector) -> Self::Vector;
+
+    /// Returns tanh of `x`, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn tanh(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the natural logarithm of `x`, within 1 ULP.
+    /// Zero gives negative infinity and negative numbers give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn ln(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the base 2 logarithm of `x`, within 1 ULP.
+    /// Zero gives negative infinity and negative numbers give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn log2(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the base 10 logarithm of `x`, within 1 ULP.
+    /// Zero gives negative infinity and negative numbers give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn log10(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `ln(1 + x)`, accurate even when `x` is close to zero, within 1 ULP.
+    /// `-1` gives negative infinity and numbers below `-1` give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn ln_1p(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the sine of `x` in radians, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn sin(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the cosine of `x` in radians, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn cos(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the tangent of `x` in radians, within 2 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn tan(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `(sin(x), cos(x))`, cheaper than computing them separately.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn sincos(x: Self::Vector) -> (Self::Vector, Self::Vector);
+
+    /// Returns the arcsine of `x` in radians, within 1 ULP. `|x| > 1` gives NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn asin(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the arccosine of `x` in radians, within 1 ULP. `|x| > 1` gives NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn acos(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the arctangent of `x` in radians, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn atan(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the angle of the point `(x, y)` in radians, in `[-pi, pi]`, within 2 ULP.
+    /// Zeros and infinities are handled like `f64::atan2`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn atan2(y: Self::Vector, x: Self::Vector) -> Self::Vector;
+
+    /// Returns `2^x`, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn exp2(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `10^x`, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn exp10(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `e^x - 1`, accurate even when `x` is close to zero, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn expm1(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `x` raised to the power `y`, within 1 ULP.
+    /// Special cases are handled like `f64::powf`: negative `x` with a non-integer `y` gives NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn powf(x: Self::Vector, y: Self::Vector) -> Self::Vector;
+
+    /// Returns the error function of `x`, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn erf(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the complementary error function `1 - erf(x)`, accurate even where `erf(x)` is close
+    /// to one, within 1 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn erfc(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the natural logarithm of the absolute value of the gamma function, within 1 ULP for
+    /// positive `x`. Negative `x` loses relative accuracy near the zeros of the result. Zero and
+    /// negative integers give positive infinity.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn lgamma(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the gamma function of `x`, within 1 ULP for positive `x` and 2 ULP for negative `x`.
+    /// Zeros give infinity with their sign, and negative integers give NaN.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn tgamma(x: Self::Vector) -> Self::Vector;
+
+    /// Returns `magnitude` with the sign of `sign`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn copysign(magnitude: Self::Vector, sign: Self::Vector) -> Self::Vector;
+
+    /// Returns `1.0` for positive `x` including `+0.0`, `-1.0` for negative `x` including `-0.0`, and NaN for NaN, like `f32::signum`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn signum(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the largest integer less than or equal to `x`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn floor(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the smallest integer greater than or equal to `x`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn ceil(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the nearest integer to `x`, rounding half-way cases away from zero like
+    /// `f32::round`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn round(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the nearest integer to `x`, rounding half-way cases to even like
+    /// `f32::round_ties_even`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn round_ties_even(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the integer part of `x`, rounding towards zero.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn trunc(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the fractional part `x - trunc(x)`, with the sign of `x`, like `f32::fract`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn fract(x: Self::Vector) -> Self::Vector;
+}
+
+/// A trait for floating point SIMD vector elements with faster, less accurate versions of some
+/// functions of [`SimdFloatingElement`], used by [`fast`].
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdFastElement: SimdFloatingElement {
+    /// Returns `e^x` like [`exp`](SimdFloatingElement::exp), trading accuracy for speed, within 3
+    /// ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn exp_fast(x: Self::Vector) -> Self::Vector;
+
+    /// Returns tanh of `x` like [`tanh`](SimdFloatingElement::tanh), trading accuracy for speed,
+    /// within 3 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn tanh_fast(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the natural logarithm of `x` like [`ln`](SimdFloatingElement::ln), trading
+    /// accuracy for speed, within 3 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn ln_fast(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the sine of `x` like [`sin`](SimdFloatingElement::sin), trading accuracy for speed,
+    /// within 3 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn sin_fast(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the cosine of `x` like [`cos`](SimdFloatingElement::cos), trading accuracy for
+    /// speed, within 3 ULP.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn cos_fast(x: Self::Vector) -> Self::Vector;
+}
+
+/// A trait for types that may be used as integer SIMD vector elements.
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdIntegerElement: SimdNumElement + Num {
+    /// Calculate and of `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn and(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Calculates or of `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn or(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Calculates not of `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn not(left: Self::Vector) -> Self::Vector;
+
+    /// Calculates xor of `left` and `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Adds `left` and `right` with wrapping, and returns the result with a mask whose lanes have the most significant bit set where the addition overflowed.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector);
+
+    /// Substructs `right` from `left` with wrapping, and returns the result with a mask whose lanes have the most significant bit set where the substruction overflowed.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector);
+
+    /// Multiples `left` and `right` with wrapping, and returns the result with a mask whose lanes have the most significant bit set where the multiplication overflowed.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector);
+}
+
+/// A trait for types that may be used as ordered SIMD vector elements.
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdOrdElement: SimdElement + PartialOrd {
+    /// Returns the smaller of `left` and `right`.
+    /// Floating point NaN is ignored like `f32::min`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Returns the larger of `left` and `right`.
+    /// Floating point NaN is ignored like `f32::max`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Returns a mask whose lanes are all set where `left == right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Returns a mask whose lanes are all set where `left < right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Returns a mask whose lanes are all set where `left <= right`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+
+    /// Returns the smallest lane of `x`, ignoring floating point NaN like `min`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn reduce_min(x: Self::Vector) -> Self;
+
+    /// Returns the largest lane of `x`, ignoring floating point NaN like `max`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn reduce_max(x: Self::Vector) -> Self;
+
+    /// Restricts `x` to `[min, max]`.
+    /// Floating point NaN in `x` is kept like `f32::clamp`.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    #[inline(always)]
+    unsafe fn clamp(x: Self::Vector, min: Self::Vector, max: Self::Vector) -> Self::Vector {
+        unsafe { <Self as SimdOrdElement>::max(<Self as SimdOrdElement>::min(x, max), min) }
+    }
+}
+
+/// A trait for types that may be used as signed SIMD vector elements.
+/// # Safety
+/// Implementors must compute each lane independently, as described by each function.
+pub unsafe trait SimdSignedElement: SimdNumElement {
+    /// Returns `-x`. Integer `MIN` wraps around to itself.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn neg(x: Self::Vector) -> Self::Vector;
+
+    /// Returns the absolute value of `x`. Integer `MIN` wraps around to itself.
+    /// # Safety
+    /// Make sure `Self::is_available()` returns true.
+    unsafe fn abs(x: Self::Vector) -> Self::Vector;
+}
+
+/// A trait for types whose SIMD vector elements can be converted to `U`.
+///
+/// Integers convert to floating point rounding to nearest, and widening integers is exact.
+/// Floating point converts to integers and narrowing integers converts with saturation at the
+/// bounds of `U`, where floating point NaN gives zero like `as`.
+/// # Safety
+/// `BLOCK_LEN` must be at most 32, and `convert_block` and `convert_round_block` must convert
+/// `BLOCK_LEN` elements exactly like `convert` and `convert_round`.
+pub unsafe trait SimdConvert<U: SimdElement + Copy + Default>:
+    SimdElement + Copy + Default
+{
+    /// Number of elements converted by each call of [`convert_block`](Self::convert_block), at
+    /// most 32.
+    const BLOCK_LEN: usize;
+
+    /// Converts `x`, truncating floating point towards zero when converting to integers.
+    fn convert(x: Self) -> U;
+
+    /// Converts `x` like [`convert`](Self::convert), except that floating point is rounded to the
+    /// nearest integer, ties to even, when converting to integers.
+    #[inline(always)]
+    fn convert_round(x: Self) -> U {
+        Self::convert(x)
+    }
+
+    /// Converts `Self::BLOCK_LEN` elements from `src` to `dst` like [`convert`](Self::convert).
+    /// # Safety
+    /// Make sure `Self::is_available()` and `U::is_available()` return true and lengths of `src`
+    /// and `dst` are not less than `Self::BLOCK_LEN`.
+    unsafe fn convert_block(src: *const Self, dst: *mut U);
+
+    /// Converts `Self::BLOCK_LEN` elements from `src` to `dst` like
+    /// [`convert_round`](Self::convert_round).
+    /// # Safety
+    /// Make sure `Self::is_available()` and `U::is_available()` return true and lengths of `src`
+    /// and `dst` are not less than `Self::BLOCK_LEN`.
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut U) {
+        unsafe { Self::convert_block(src, dst) }
+    }
+}
+
+/// A trait for integer SIMD vector elements whose lanes can be summed into the wider lanes of `U`
+/// without overflowing.
+/// # Safety
+/// `widening_sum` must return lanes whose sum is the exact sum of the lanes of its input.
+pub unsafe trait SimdWideningSum<U: SimdNumElement>: SimdNumElement {
+    /// Returns lanes of `U` whose sum is the sum of all lanes of `x`.
+    /// # Safety
+    /// Make sure `Self::is_available()` and `U::is_available()` return true.
+    unsafe fn widening_sum(x: Self::Vector) -> U::Vector;
+}
+
+/// A trait for integer SIMD vector elements whose products with lanes of `R` can be summed into
+/// the wider lanes of `U` without overflowing.
+/// # Safety
+/// `widening_dot` must return lanes whose sum is the exact sum of the products of its inputs.
+pub unsafe trait SimdWideningDot<R: SimdNumElement, U: SimdNumElement>:
+    SimdNumElement
+{
+    /// Returns lanes of `U` whose sum is the sum of the products of the lanes of `left` and
+    /// `right`.
+    /// # Safety
+    /// Make sure `Self::is_available()`, `R::is_available()` and `U::is_available()` return true.
+    unsafe fn widening_dot(left: Self::Vector, right: R::Vector) -> U::Vector;
+}
+
+/// Mutable SIMD wrapper structure
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SimdRef<'a, T: SimdElement>(&'a [T]);
+
+impl<'a, T: SimdElement> SimdRef<'a, T> {
+    /// Creates new `SimdRef<T>`.
+    pub fn new(slice: &'a [T]) -> Self {
+        Self(slice)
+    }
+}
+
+impl<'a, T: SimdOrdElement> SimdRef<'a, T> {
+    /// Returns a mask of `self == rhs`.
+    pub fn simd_eq<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::eq(left, right))
+        })
+    }
+
+    /// Returns a mask of `self != rhs`. Floating point NaN is unequal to everything.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let left = [1.0f32, f32::NAN, -0.0, 2.0];
+    /// let right = [1.0f32, f32::NAN, 0.0, 3.0];
+    ///
+    /// let mask = SimdRef::new(&left).simd_ne(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [false, true, false, true]);
+    /// ```
+    pub fn simd_ne<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            !T::to_bitmask(<T as SimdOrdElement>::eq(left, right))
+        })
+    }
+
+    /// Returns a mask of `self < rhs`.
+    pub fn simd_lt<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::lt(left, right))
+        })
+    }
+
+    /// Returns a mask of `self <= rhs`.
+    pub fn simd_le<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::le(left, right))
+        })
+    }
+
+    /// Returns a mask of `self > rhs`. Floating point NaN compares false.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// // Unsigned elements with the top bit set are larger, not negative.
+    /// let left = [200u8, 127, 128, 0];
+    /// let right = [100u8, 128, 127, 255];
+    /// let mask = SimdRef::new(&left).simd_gt(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [true, false, true, false]);
+    ///
+    /// let left = [u64::MAX, 1 << 63, (1 << 63) - 1, 5];
+    /// let right = [0u64, (1 << 63) - 1, 1 << 63, 5];
+    /// let mask = SimdRef::new(&left).simd_gt(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [true, true, false, false]);
+    ///
+    /// let left = [f64::NAN, 1.0, f64::INFINITY];
+    /// let right = [0.0f64, f64::NAN, 1.0];
+    /// let mask = SimdRef::new(&left).simd_gt(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [false, false, true]);
+    /// ```
+    pub fn simd_gt<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::lt(right, left))
+        })
+    }
+
+    /// Returns a mask of `self >= rhs`. Floating point NaN compares false.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let left = [u32::MAX, 1 << 31, 7, 0];
+    /// let right = [1u32 << 31, u32::MAX, 7, 1];
+    /// let mask = SimdRef::new(&left).simd_ge(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [true, false, true, false]);
+    ///
+    /// let left = [i64::MIN, i64::MAX, -1];
+    /// let right = [i64::MAX, i64::MIN, -1];
+    /// let mask = SimdRef::new(&left).simd_ge(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [false, true, true]);
+    ///
+    /// let left = [f32::NAN, -0.0];
+    /// let right = [f32::NAN, 0.0];
+    /// let mask = SimdRef::new(&left).simd_ge(&SimdRef::new(&right));
+    /// assert_eq!(mask.to_vec(), [false, true]);
+    /// ```
+    pub fn simd_ge<R: Simd<T>>(&self, rhs: &R) -> SimdMask {
+        self.compare(rhs, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::le(right, left))
+        })
+    }
+
+    /// Returns a mask of `self == value`.
+    pub fn simd_eq_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::eq(left, right))
+        })
+    }
+
+    /// Returns a mask of `self != value`.
+    pub fn simd_ne_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            !T::to_bitmask(<T as SimdOrdElement>::eq(left, right))
+        })
+    }
+
+    /// Returns a mask of `self < value`.
+    pub fn simd_lt_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::lt(left, right))
+        })
+    }
+
+    /// Returns a mask of `self <= value`.
+    pub fn simd_le_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::le(left, right))
+        })
+    }
+
+    /// Returns a mask of `self > value`.
+    pub fn simd_gt_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::lt(right, left))
+        })
+    }
+
+    /// Returns a mask of `self >= value`.
+    pub fn simd_ge_scalar(&self, value: T) -> SimdMask {
+        self.compare_scalar(value, |left, right| unsafe {
+            T::to_bitmask(<T as SimdOrdElement>::le(right, left))
+        })
+    }
+
+    #[inline(always)]
+    fn compare<R: Simd<T>>(&self, rhs: &R, f: impl Fn(T::Vector, T::Vector) -> u32) -> SimdMask {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len().min(rhs.len());
+        let left = self.as_ptr();
+        let right = rhs.as_ptr();
+        let mut mask = SimdMask::with_capacity(len);
+
+        unsafe {
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let left_vector = T::load(left.add(offset));
+                let right_vector = T::load(right.add(offset));
+                mask.push_bits(f(left_vector, right_vector), T::VECTOR_LEN);
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                let right_vector = T::load_partial(right.add(offset), remaining);
+                mask.push_bits(f(left_vector, right_vector), remaining);
+            }
+        }
+
+        mask
+    }
+
+    #[inline(always)]
+    fn compare_scalar(&self, value: T, f: impl Fn(T::Vector, T::Vector) -> u32) -> SimdMask {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let left = self.as_ptr();
+        let mut mask = SimdMask::with_capacity(len);
+
+        unsafe {
+            let right_vector = T::set(value);
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let left_vector = T::load(left.add(offset));
+                mask.push_bits(f(left_vector, right_vector), T::VECTOR_LEN);
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                mask.push_bits(f(left_vector, right_vector), remaining);
+            }
+        }
+
+        mask
+    }
+}
+
+impl<'a, T: SimdFloatingElement> SimdRef<'a, T> {
+    /// Writes the sine and cosine of each element to `sin_out` and `cos_out`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let angles = [0.0f32, std::f32::consts::FRAC_PI_2];
+    /// let mut sin = [0.0; 2];
+    /// let mut cos = [0.0; 2];
+    /// SimdRef::new(&angles).sincos_into(&mut SimdMut::new(&mut sin), &mut SimdMut::new(&mut cos));
+    ///
+    /// assert_eq!(sin, [0.0, 1.0]);
+    /// assert!(cos[0] == 1.0 && cos[1].abs() < 1e-7);
+    /// ```
+    pub fn sincos_into(&self, sin_out: &mut SimdMut<T>, cos_out: &mut SimdMut<T>) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len().min(sin_out.len()).min(cos_out.len());
+        let x = self.as_ptr();
+        let sin = sin_out.as_mut_ptr();
+        let cos = cos_out.as_mut_ptr();
+
+        unsafe {
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let x_vector = T::load(x.add(offset));
+                let (sin_vector, cos_vector) = T::sincos(x_vector);
+                T::store(sin.add(offset), sin_vector);
+                T::store(cos.add(offset), cos_vector);
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let x_vector = T::load_partial(x.add(offset), remaining);
+                let (sin_vector, cos_vector) = T::sincos(x_vector);
+                T::store_partial(sin.add(offset), sin_vector, remaining);
+                T::store_partial(cos.add(offset), cos_vector, remaining);
+            }
+        }
+    }
+}
+
+impl<'a, T: SimdElement> Deref for SimdRef<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+impl<'a, T: SimdElement> Simd<T> for SimdRef<'a, T> {}
+
+/// Mutable SIMD wrapper structure
+#[derive(Debug, PartialEq)]
+#[repr(transparent)]
+pub struct SimdMut<'a, T: SimdElement>(&'a mut [T]);
+
+impl<'a, T: SimdElement> SimdMut<'a, T> {
+    /// Creates new `SimdMut<T>`.
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self(slice)
+    }
+
+    #[inline(always)]
+    fn map_vectors(&mut self, f: impl Fn(T::Vector) -> T::Vector) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let x = self.as_mut_ptr();
+
+        unsafe {
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let x_vector = T::load(x.add(offset));
+                T::store(x.add(offset), f(x_vector));
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let x_vector = T::load_partial(x.add(offset), remaining);
+                T::store_partial(x.add(offset), f(x_vector), remaining);
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn zip_vectors<R: Simd<T>>(&mut self, rhs: &R, f: impl Fn(T::Vector, T::Vector) -> T::Vector) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len().min(rhs.len());
+        let left = self.as_mut_ptr();
+        let right = rhs.as_ptr();
+
+        unsafe {
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let left_vector = T::load(left.add(offset));
+                let right_vector = T::load(right.add(offset));
+                T::store(left.add(offset), f(left_vector, right_vector));
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                let right_vector = T::load_partial(right.add(offset), remaining);
+                T::store_partial(left.add(offset), f(left_vector, right_vector), remaining);
+            }
+        }
+    }
+}
+
+impl<'a, T: SimdNumElement> SimdMut<'a, T> {
+    /// Raises a number to an integer power.
+    pub fn powi(&mut self, n: i32) {
+        self.map_vectors(|x| unsafe { T::powi(x, n) });
+    }
+
+    /// Replaces each element with the sum of it and all elements before it.
+    /// Integers wrap around on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// SimdMut::new(&mut values).prefix_sum();
+    ///
+    /// assert_eq!(values, [1, 3, 6, 10, 15]);
+    /// ```
+    pub fn prefix_sum(&mut self) {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let x = self.as_mut_ptr();
+
+        unsafe {
+            let mut carry = T::set(T::zero());
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let x_vector = T::load(x.add(offset));
+                let result_vector = <T as SimdNumElement>::add(T::prefix_sum(x_vector), carry);
+                T::store(x.add(offset), result_vector);
+                carry = T::set(x.add(offset + T::VECTOR_LEN - 1).read());
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let x_vector = T::load_partial(x.add(offset), remaining);
+                let result_vector = <T as SimdNumElement>::add(T::prefix_sum(x_vector), carry);
+                T::store_partial(x.add(offset), result_vector, remaining);
+            }
+        }
+    }
+
+    /// Replaces each element with the sum of all elements before it.
+    /// Integers wrap around on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// SimdMut::new(&mut values).exclusive_prefix_sum();
+    ///
+    /// assert_eq!(values, [0, 1, 3, 6, 10]);
+    /// ```
+    pub fn exclusive_prefix_sum(&mut self) {
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+
+        self.prefix_sum();
+
+        let x = self.as_mut_ptr();
+        unsafe {
+            x.copy_to(x.add(1), len - 1);
+            x.write(T::zero());
+        }
+    }
+}
+
+impl<'a, T: SimdFloatingElement> SimdMut<'a, T> {
+    /// Calculates square root.
+    pub fn sqrt(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::sqrt(x) });
+    }
+
+    /// Calculates `1 / x` of each element.
+    pub fn recip(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::recip(x) });
+    }
+
+    /// Calculates `1 / sqrt(x)` of each element.
+    pub fn rsqrt(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::rsqrt(x) });
+    }
+
+    /// Calculates an estimate of `1 / x` of each element, refined by `steps` Newton-Raphson
+    /// iterations. See [`SimdFloatingElement::recip_approx`] for the accuracy.
+    pub fn recip_approx(&mut self, steps: u32) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::recip_approx(x, steps) });
+    }
+
+    /// Calculates an estimate of `1 / sqrt(x)` of each element, refined by `steps`
+    /// Newton-Raphson iterations. See [`SimdFloatingElement::rsqrt_approx`] for the accuracy.
+    ///
+    /// With one step this is much cheaper than [`sqrt`](Self::sqrt) followed by a division,
+    /// for example to normalize vectors.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [4.0f32, 0.25, 100.0, 2.0];
+    /// SimdMut::new(&mut values).rsqrt_approx(1);
+    ///
+    /// let expected = [0.5f32, 2.0, 0.1, std::f32::consts::FRAC_1_SQRT_2];
+    /// for (value, expected) in values.iter().zip(expected) {
+    ///     assert!((value - expected).abs() <= 4.0 * f32::EPSILON * expected);
+    /// }
+    /// ```
+    pub fn rsqrt_approx(&mut self, steps: u32) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::rsqrt_approx(x, steps) });
+    }
+
+    /// Calculates the cube root of each element.
+    pub fn cbrt(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::cbrt(x) });
+    }
+
+    /// Replaces each element `x` with `sqrt(x^2 + y^2)`, which does not overflow even where the
+    /// squares would.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut x = [3.0, 3e300, 5e-320, f64::INFINITY];
+    /// let y = [4.0, 4e300, 0.0, f64::NAN];
+    /// SimdMut::new(&mut x).hypot(&SimdRef::new(&y));
+    ///
+    /// assert_eq!(x, [5.0, 5e300, 5e-320, f64::INFINITY]);
+    /// ```
+    pub fn hypot<R: Simd<T>>(&mut self, y: &R) {
+        self.zip_vectors(y, |x, y| unsafe { <T as SimdFloatingElement>::hypot(x, y) });
+    }
+
+    /// Calculates `e^self`
+    pub fn exp(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::exp(x) });
+    }
+
+    /// Calculates `tanh`
+    pub fn tanh(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::tanh(x) });
+    }
+
+    /// Calculates the natural logarithm of each element.
+    pub fn ln(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::ln(x) });
+    }
+
+    /// Calculates the base 2 logarithm of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1.0f32, 8.0, 0.5, f32::MIN_POSITIVE / 8.0, 0.0, f32::INFINITY, -2.0];
+    /// SimdMut::new(&mut values).log2();
+    ///
+    /// assert_eq!(values[..6], [0.0, 3.0, -1.0, -129.0, f32::NEG_INFINITY, f32::INFINITY]);
+    /// assert!(values[6].is_nan());
+    /// ```
+    pub fn log2(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::log2(x) });
+    }
+
+    /// Calculates the base 10 logarithm of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1.0, 10.0, 1000.0, 0.0, -1.0];
+    /// SimdMut::new(&mut values).log10();
+    ///
+    /// assert_eq!(values[..4], [0.0, 1.0, 3.0, f64::NEG_INFINITY]);
+    /// assert!(values[4].is_nan());
+    /// ```
+    pub fn log10(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::log10(x) });
+    }
+
+    /// Calculates `ln(1 + x)` of each element, more accurately than `ln` when `x` is close to zero.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1e-20, -0.0, std::f64::consts::E - 1.0, -1.0, -2.0];
+    /// SimdMut::new(&mut values).ln_1p();
+    ///
+    /// // `(1.0 + 1e-20).ln()` would round to zero.
+    /// assert_eq!(values[..4], [1e-20, -0.0, 1.0, f64::NEG_INFINITY]);
+    /// assert!(values[1].is_sign_negative());
+    /// assert!(values[4].is_nan());
+    /// ```
+    pub fn ln_1p(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::ln_1p(x) });
+    }
+
+    /// Calculates the sine of each element in radians.
+    pub fn sin(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::sin(x) });
+    }
+
+    /// Calculates the cosine of each element in radians.
+    pub fn cos(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::cos(x) });
+    }
+
+    /// Calculates the tangent of each element in radians.
+    pub fn tan(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::tan(x) });
+    }
+
+    /// Calculates the arcsine of each element in radians.
+    pub fn asin(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::asin(x) });
+    }
+
+    /// Calculates the arccosine of each element in radians.
+    pub fn acos(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::acos(x) });
+    }
 
-/// A trait for type that may be used as numeric SIMD vector elements.
-pub unsafe trait SimdNumElement: SimdElement + Num {
-    /// Adds `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn add(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Calculates the arctangent of each element in radians.
+    pub fn atan(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::atan(x) });
+    }
 
-    /// Substructs `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn sub(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Replaces each element `y` with `atan2(y, x)`, where `x` is the matching element of `x`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    /// use std::f64::consts::{FRAC_PI_2, PI};
+    ///
+    /// let mut y = [0.0, 1.0, 0.0, -1.0];
+    /// let x = [1.0, 0.0, -1.0, 0.0];
+    /// SimdMut::new(&mut y).atan2(&SimdRef::new(&x));
+    ///
+    /// assert_eq!(y, [0.0, FRAC_PI_2, PI, -FRAC_PI_2]);
+    /// ```
+    pub fn atan2<R: Simd<T>>(&mut self, x: &R) {
+        self.zip_vectors(x, |y, x| unsafe { <T as SimdFloatingElement>::atan2(y, x) });
+    }
 
-    /// Multiples `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Calculates 2 raised to the power of each element.
+    pub fn exp2(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::exp2(x) });
+    }
 
-    /// Divides `left` by `right`, then return the result.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn div(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Calculates 10 raised to the power of each element.
+    pub fn exp10(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::exp10(x) });
+    }
 
-    /// Calculates `a * b + c`. You should overload this when the architecture has dedicated operator.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    #[inline(always)]
-    unsafe fn fma(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self::Vector {
-        unsafe { <Self as SimdNumElement>::add(<Self as SimdNumElement>::mul(a, b), c) }
+    /// Calculates `e^x - 1` for each element, accurate even when `x` is close to zero.
+    pub fn expm1(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::expm1(x) });
     }
 
-    /// Raises a number to an integer power.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    #[inline(always)]
-    unsafe fn powi(x: Self::Vector, n: i32) -> Self::Vector {
-        let mut result = unsafe { Self::set(Self::one()) };
-        if n < 0 {
-            for _ in 0..-n {
-                result = unsafe { <Self as SimdNumElement>::div(result, x) };
-            }
-        }
-        if 0 < n {
-            for _ in 0..n {
-                result = unsafe { <Self as SimdNumElement>::mul(result, x) };
-            }
+    /// Raises each element to the power of the matching element of `y`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut x = [2.0, 9.0, -2.0, 0.0];
+    /// let y = [10.0, 0.5, 3.0, 0.0];
+    /// SimdMut::new(&mut x).powf(&SimdRef::new(&y));
+    ///
+    /// assert_eq!(x, [1024.0, 3.0, -8.0, 1.0]);
+    /// ```
+    pub fn powf<R: Simd<T>>(&mut self, y: &R) {
+        self.zip_vectors(y, |x, y| unsafe { <T as SimdFloatingElement>::powf(x, y) });
+    }
+
+    /// Raises each element to the power `y`.
+    pub fn powf_scalar(&mut self, y: T) {
+        if !T::is_available() {
+            panic!("simd is not available");
         }
 
-        result
+        let y = unsafe { T::set(y) };
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::powf(x, y) });
     }
-}
 
-/// A trait for types that may be used as floating point SIMD vector elements.
-pub unsafe trait SimdFloatingElement: SimdNumElement + Float {
-    /// Returns sqrt of `x`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn sqrt(x: Self::Vector) -> Self::Vector;
+    /// Calculates the error function of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, 0.5, -1.5, 3.0, f64::NAN];
+    /// SimdMut::new(&mut values).erf();
+    ///
+    /// assert_eq!(values[..7], [0.0, -0.0, 1.0, -1.0, 0.520_499_877_813_046_5, -0.966_105_146_475_310_8, 0.999_977_909_503_001_4]);
+    /// assert!(values[1].is_sign_negative());
+    /// assert!(values[7].is_nan());
+    /// ```
+    pub fn erf(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::erf(x) });
+    }
 
-    /// Returns exp(x).
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn exp(x: Self::Vector) -> Self::Vector;
+    /// Calculates the complementary error function `1 - erf(x)` of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.0, f64::INFINITY, f64::NEG_INFINITY, 0.5, -1.5, 10.0, 27.0, f64::NAN];
+    /// SimdMut::new(&mut values).erfc();
+    ///
+    /// assert_eq!(values[..7], [1.0, 0.0, 2.0, 0.479_500_122_186_953_5, 1.966_105_146_475_310_8, 2.088_487_583_762_545e-45, 5.237_05e-319]);
+    /// assert!(values[7].is_nan());
+    /// ```
+    pub fn erfc(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::erfc(x) });
+    }
 
-    /// Returns tanh of `x`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn tanh(x: Self::Vector) -> Self::Vector;
-}
+    /// Calculates the natural logarithm of the absolute value of the gamma function of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.0, -0.0, -2.0, f64::INFINITY, f64::NEG_INFINITY, 0.5, 3.0, -1.5, f64::NAN];
+    /// SimdMut::new(&mut values).lgamma();
+    ///
+    /// let ln_2 = std::f64::consts::LN_2;
+    /// assert_eq!(values[..5], [f64::INFINITY; 5]);
+    /// assert_eq!(values[5..8], [0.572_364_942_924_700_1, ln_2, 0.860_047_015_376_481]);
+    /// assert!(values[8].is_nan());
+    /// ```
+    pub fn lgamma(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::lgamma(x) });
+    }
 
-/// A trait for types that may be used as integer SIMD vector elements.
-pub unsafe trait SimdIntegerElement: SimdNumElement + Num {
-    /// Calculate and of `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn and(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Calculates the gamma function of each element.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1.0f64, 5.0, 11.0, -0.5, 0.0, -0.0, -1.0, f64::NEG_INFINITY];
+    /// SimdMut::new(&mut values).tgamma();
+    ///
+    /// assert_eq!(values[..6], [1.0, 24.0, 3_628_800.0, -3.544_907_701_811_032, f64::INFINITY, f64::NEG_INFINITY]);
+    /// assert!(values[6].is_nan() && values[7].is_nan());
+    ///
+    /// // Past 171.6 the result overflows.
+    /// let mut values = [171.0f64, 200.0, 1e300, f64::INFINITY];
+    /// SimdMut::new(&mut values).tgamma();
+    ///
+    /// assert_eq!(values, [7.257_415_615_307_999e306, f64::INFINITY, f64::INFINITY, f64::INFINITY]);
+    ///
+    /// let mut values = [36.0f32, 1e20];
+    /// SimdMut::new(&mut values).tgamma();
+    ///
+    /// assert_eq!(values, [f32::INFINITY; 2]);
+    /// ```
+    pub fn tgamma(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::tgamma(x) });
+    }
 
-    /// Calculates or of `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn or(left: Self::Vector, right: Self::Vector) -> Self::Vector;
+    /// Copies the sign of `sign` to each element.
+    pub fn copysign<R: Simd<T>>(&mut self, sign: &R) {
+        self.zip_vectors(sign, |magnitude, sign| unsafe {
+            <T as SimdFloatingElement>::copysign(magnitude, sign)
+        });
+    }
 
-    /// Calculates not of `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn not(left: Self::Vector) -> Self::Vector;
+    /// Calculates the sign of each element like `f32::signum`.
+    pub fn signum(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::signum(x) });
+    }
 
-    /// Calculates xor of `left` and `right`.
-    /// # Safety
-    /// Make sure `Self::is_available()` returns true.
-    unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector;
-}
+    /// Rounds each element down to an integer.
+    pub fn floor(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::floor(x) });
+    }
 
-/// Mutable SIMD wrapper structure
-#[repr(transparent)]
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub struct SimdRef<'a, T: SimdElement>(&'a [T]);
+    /// Rounds each element up to an integer.
+    pub fn ceil(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::ceil(x) });
+    }
 
-impl<'a, T: SimdElement> SimdRef<'a, T> {
-    /// Creates new `SimdRef<T>`.
-    pub fn new(slice: &'a [T]) -> Self {
-        Self(slice)
+    /// Rounds each element to the nearest integer, with half-way cases away from zero like
+    /// `f32::round`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.5f32, 1.5, 2.5, -2.5, -0.4];
+    /// SimdMut::new(&mut values).round();
+    ///
+    /// assert_eq!(values, [1.0, 2.0, 3.0, -3.0, -0.0]);
+    /// ```
+    pub fn round(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::round(x) });
     }
-}
 
-impl<'a, T: SimdElement> Deref for SimdRef<'a, T> {
-    type Target = [T];
+    /// Rounds each element to the nearest integer, with half-way cases to even like
+    /// `f32::round_ties_even`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.5f32, 1.5, 2.5, -2.5, -0.4];
+    /// SimdMut::new(&mut values).round_ties_even();
+    ///
+    /// assert_eq!(values, [0.0, 2.0, 2.0, -2.0, -0.0]);
+    /// ```
+    pub fn round_ties_even(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::round_ties_even(x) });
+    }
 
-    fn deref(&self) -> &[T] {
-        &self.0
+    /// Rounds each element towards zero.
+    pub fn trunc(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::trunc(x) });
     }
-}
 
-impl<'a, T: SimdElement> Simd<T> for SimdRef<'a, T> {}
+    /// Replaces each element with its fractional part `x - trunc(x)`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [3.75f64, -3.75, 2.0];
+    /// SimdMut::new(&mut values).fract();
+    ///
+    /// assert_eq!(values, [0.75, -0.75, 0.0]);
+    /// ```
+    pub fn fract(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdFloatingElement>::fract(x) });
+    }
+}
 
-/// Mutable SIMD wrapper structure
-#[derive(Debug, PartialEq)]
-#[repr(transparent)]
-pub struct SimdMut<'a, T: SimdElement>(&'a mut [T]);
+impl<'a, T: SimdSignedElement> SimdMut<'a, T> {
+    /// Negates each element. Integer `MIN` wraps around to itself.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [i8::MIN, i8::MAX, 0, -5];
+    /// SimdMut::new(&mut values).neg();
+    /// assert_eq!(values, [i8::MIN, -i8::MAX, 0, 5]);
+    ///
+    /// let mut values = [i64::MIN, i64::MAX, 0, -5];
+    /// SimdMut::new(&mut values).neg();
+    /// assert_eq!(values, [i64::MIN, -i64::MAX, 0, 5]);
+    ///
+    /// let mut values = [0.0f32, -0.0, f32::INFINITY];
+    /// SimdMut::new(&mut values).neg();
+    /// assert_eq!(values.map(f32::to_bits), [-0.0f32, 0.0, f32::NEG_INFINITY].map(f32::to_bits));
+    /// ```
+    pub fn neg(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdSignedElement>::neg(x) });
+    }
 
-impl<'a, T: SimdElement> SimdMut<'a, T> {
-    /// Creates new `SimdMut<T>`.
-    pub fn new(slice: &'a mut [T]) -> Self {
-        Self(slice)
+    /// Calculates absolute value. Integer `MIN` wraps around to itself.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [i16::MIN, -i16::MAX, 3];
+    /// SimdMut::new(&mut values).abs();
+    /// assert_eq!(values, [i16::MIN, i16::MAX, 3]);
+    ///
+    /// let mut values = [i32::MIN, -i32::MAX, 3];
+    /// SimdMut::new(&mut values).abs();
+    /// assert_eq!(values, [i32::MIN, i32::MAX, 3]);
+    ///
+    /// let mut values = [i64::MIN, -i64::MAX, 3];
+    /// SimdMut::new(&mut values).abs();
+    /// assert_eq!(values, [i64::MIN, i64::MAX, 3]);
+    ///
+    /// let mut values = [-0.0f64, f64::NEG_INFINITY, -f64::NAN];
+    /// SimdMut::new(&mut values).abs();
+    /// assert_eq!(values[0].to_bits(), 0.0f64.to_bits());
+    /// assert_eq!(values[1], f64::INFINITY);
+    /// assert!(values[2].is_nan() && values[2].is_sign_positive());
+    /// ```
+    pub fn abs(&mut self) {
+        self.map_vectors(|x| unsafe { <T as SimdSignedElement>::abs(x) });
     }
 }
 
-impl<'a, T: SimdNumElement> SimdMut<'a, T> {
-    /// Raises a number to an integer power.
-    pub fn powi(&mut self, n: i32) {
+impl<'a, T: SimdElement> SimdMut<'a, T> {
+    /// Replaces elements where `mask` is set with elements of `rhs`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [-1.0, 2.0, -3.0, 4.0];
+    /// let zeros = [0.0; 4];
+    ///
+    /// let negative = SimdRef::new(&values).simd_lt_scalar(0.0);
+    /// SimdMut::new(&mut values).blend_assign(&negative, &SimdRef::new(&zeros));
+    ///
+    /// assert_eq!(values, [0.0, 2.0, 0.0, 4.0]);
+    /// ```
+    pub fn blend_assign<R: Simd<T>>(&mut self, mask: &SimdMask, rhs: &R) {
         if !T::is_available() {
             panic!("simd is not available");
         }
 
-        let len = self.len();
-        let x = self.as_mut_ptr();
+        let len = self.len().min(mask.len()).min(rhs.len());
+        let left = self.as_mut_ptr();
+        let right = rhs.as_ptr();
 
         unsafe {
             for i in 0..len / T::VECTOR_LEN {
                 let offset = i * T::VECTOR_LEN;
-                let x_vector = T::load(x.add(offset));
-                let result_vector = T::powi(x_vector, n);
-                T::store(x.add(offset), result_vector);
+                let mask_vector = T::from_bitmask(mask.bits(offset, T::VECTOR_LEN));
+                let left_vector = T::load(left.add(offset));
+                let right_vector = T::load(right.add(offset));
+                let result_vector = T::blend(mask_vector, left_vector, right_vector);
+                T::store(left.add(offset), result_vector);
             }
 
             let remaining = len % T::VECTOR_LEN;
             if remaining != 0 {
                 let offset = len - remaining;
-                let x_vector = T::load_partial(x.add(offset), remaining);
-                let result_vector = T::powi(x_vector, n);
-                T::store(x.add(offset), result_vector);
+                let mask_vector = T::from_bitmask(mask.bits(offset, remaining));
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                let right_vector = T::load_partial(right.add(offset), remaining);
+                let result_vector = T::blend(mask_vector, left_vector, right_vector);
+                T::store_partial(left.add(offset), result_vector, remaining);
             }
         }
     }
 }
 
-impl<'a, T: SimdFloatingElement> SimdMut<'a, T> {
-    /// Calculates square root.
-    pub fn sqrt(&mut self) {
+impl<'a, T: SimdIntegerElement> SimdMut<'a, T> {
+    /// Adds `rhs`, checking overflow.
+    ///
+    /// Returns `Err(index)` of the first overflowed element. Elements before `index` are updated and the others are left unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1u8, 2, 250, 4, 255];
+    /// let rhs = [10u8, 10, 10, 10, 10];
+    ///
+    /// let result = SimdMut::new(&mut values).checked_add_assign(&SimdRef::new(&rhs));
+    ///
+    /// assert_eq!(result, Err(2));
+    /// assert_eq!(values, [11, 12, 250, 4, 255]);
+    /// ```
+    pub fn checked_add_assign<R: Simd<T>>(&mut self, rhs: &R) -> Result<(), usize> {
+        self.checked_assign(rhs, |left, right| unsafe {
+            T::overflowing_add(left, right)
+        })
+    }
+
+    /// Substructs `rhs`, checking overflow.
+    ///
+    /// Returns `Err(index)` of the first overflowed element. Elements before `index` are updated and the others are left unchanged.
+    pub fn checked_sub_assign<R: Simd<T>>(&mut self, rhs: &R) -> Result<(), usize> {
+        self.checked_assign(rhs, |left, right| unsafe {
+            T::overflowing_sub(left, right)
+        })
+    }
+
+    /// Multiples `rhs`, checking overflow.
+    ///
+    /// Returns `Err(index)` of the first overflowed element. Elements before `index` are updated and the others are left unchanged.
+    ///
+    /// For `i64` and `u64` the elements are multiplied one by one, since AVX2 has no 64-bit
+    /// multiplication that detects overflow, so this is much slower than for the other widths.
+    pub fn checked_mul_assign<R: Simd<T>>(&mut self, rhs: &R) -> Result<(), usize> {
+        self.checked_assign(rhs, |left, right| unsafe {
+            T::overflowing_mul(left, right)
+        })
+    }
+
+    /// Adds `rhs` with wrapping, then returns whether any element overflowed.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [i16::MAX, 1, i16::MIN];
+    /// let rhs = [1i16, 1, -1];
+    ///
+    /// assert!(SimdMut::new(&mut values).overflowing_add_assign(&SimdRef::new(&rhs)));
+    /// assert_eq!(values, [i16::MIN, 2, i16::MAX]);
+    /// ```
+    pub fn overflowing_add_assign<R: Simd<T>>(&mut self, rhs: &R) -> bool {
+        self.overflowing_assign(rhs, |left, right| unsafe {
+            T::overflowing_add(left, right)
+        })
+    }
+
+    /// Substructs `rhs` with wrapping, then returns whether any element overflowed.
+    pub fn overflowing_sub_assign<R: Simd<T>>(&mut self, rhs: &R) -> bool {
+        self.overflowing_assign(rhs, |left, right| unsafe {
+            T::overflowing_sub(left, right)
+        })
+    }
+
+    /// Multiples `rhs` with wrapping, then returns whether any element overflowed.
+    ///
+    /// For `i64` and `u64` the elements are multiplied one by one, since AVX2 has no 64-bit
+    /// multiplication that detects overflow, so this is much slower than for the other widths.
+    pub fn overflowing_mul_assign<R: Simd<T>>(&mut self, rhs: &R) -> bool {
+        self.overflowing_assign(rhs, |left, right| unsafe {
+            T::overflowing_mul(left, right)
+        })
+    }
+
+    #[inline(always)]
+    fn checked_assign<R: Simd<T>>(
+        &mut self,
+        rhs: &R,
+        op: impl Fn(T::Vector, T::Vector) -> (T::Vector, T::Vector),
+    ) -> Result<(), usize> {
         if !T::is_available() {
             panic!("simd is not available");
         }
 
-        let len = self.len();
-        let x = self.as_mut_ptr();
+        let len = self.len().min(rhs.len());
+        let left = self.as_mut_ptr();
+        let right = rhs.as_ptr();
 
         unsafe {
             for i in 0..len / T::VECTOR_LEN {
                 let offset = i * T::VECTOR_LEN;
-                let x_vector = T::load(x.add(offset));
-                let result_vector = <T as SimdFloatingElement>::sqrt(x_vector);
-                T::store(x.add(offset), result_vector);
+                let left_vector = T::load(left.add(offset));
+                let right_vector = T::load(right.add(offset));
+                let (result_vector, overflow) = op(left_vector, right_vector);
+                let overflow = T::to_bitmask(overflow);
+                if overflow != 0 {
+                    let index = overflow.trailing_zeros() as usize;
+                    T::store_partial(left.add(offset), result_vector, index);
+                    return Err(offset + index);
+                }
+                T::store(left.add(offset), result_vector);
             }
 
             let remaining = len % T::VECTOR_LEN;
             if remaining != 0 {
                 let offset = len - remaining;
-                let x_vector = T::load_partial(x.add(offset), remaining);
-                let result_vector = <T as SimdFloatingElement>::sqrt(x_vector);
-                T::store(x.add(offset), result_vector);
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                let right_vector = T::load_partial(right.add(offset), remaining);
+                let (result_vector, overflow) = op(left_vector, right_vector);
+                let overflow = T::to_bitmask(overflow) & ((1u64 << remaining) - 1) as u32;
+                if overflow != 0 {
+                    let index = overflow.trailing_zeros() as usize;
+                    T::store_partial(left.add(offset), result_vector, index);
+                    return Err(offset + index);
+                }
+                T::store_partial(left.add(offset), result_vector, remaining);
             }
         }
+
+        Ok(())
     }
 
-    /// Calculates `e^self`
-    pub fn exp(&mut self) {
+    #[inline(always)]
+    fn overflowing_assign<R: Simd<T>>(
+        &mut self,
+        rhs: &R,
+        op: impl Fn(T::Vector, T::Vector) -> (T::Vector, T::Vector),
+    ) -> bool {
         if !T::is_available() {
             panic!("simd is not available");
         }
 
-        let len = self.len();
-        let x = self.as_mut_ptr();
+        let len = self.len().min(rhs.len());
+        let left = self.as_mut_ptr();
+        let right = rhs.as_ptr();
+        let mut overflowed = 0;
 
         unsafe {
             for i in 0..len / T::VECTOR_LEN {
                 let offset = i * T::VECTOR_LEN;
-                let x_vector = T::load(x.add(offset));
-                let result_vector = <T as SimdFloatingElement>::exp(x_vector);
-                T::store(x.add(offset), result_vector);
+                let left_vector = T::load(left.add(offset));
+                let right_vector = T::load(right.add(offset));
+                let (result_vector, overflow) = op(left_vector, right_vector);
+                overflowed |= T::to_bitmask(overflow);
+                T::store(left.add(offset), result_vector);
             }
 
             let remaining = len % T::VECTOR_LEN;
             if remaining != 0 {
                 let offset = len - remaining;
-                let x_vector = T::load_partial(x.add(offset), remaining);
-                let result_vector = <T as SimdFloatingElement>::exp(x_vector);
-                T::store(x.add(offset), result_vector);
+                let left_vector = T::load_partial(left.add(offset), remaining);
+                let right_vector = T::load_partial(right.add(offset), remaining);
+                let (result_vector, overflow) = op(left_vector, right_vector);
+                overflowed |= T::to_bitmask(overflow) & ((1u64 << remaining) - 1) as u32;
+                T::store_partial(left.add(offset), result_vector, remaining);
             }
         }
+
+        overflowed != 0
     }
+}
 
-    /// Calculates `tanh`
-    pub fn tanh(&mut self) {
+impl<'a, T: SimdOrdElement> SimdMut<'a, T> {
+    /// Replaces each element with the smaller of it and `rhs`.
+    /// Floating point NaN is ignored like `f32::min`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// // Unsigned elements above the signed maximum compare as larger.
+    /// let mut values = [200u8, 1, 128, 255];
+    /// SimdMut::new(&mut values).min_assign(&SimdRef::new(&[100, 127, 127, 0]));
+    /// assert_eq!(values, [100, 1, 127, 0]);
+    ///
+    /// let mut values = [u64::MAX, 1, 1 << 63];
+    /// SimdMut::new(&mut values).min_assign(&SimdRef::new(&[0, u64::MAX, (1 << 63) - 1]));
+    /// assert_eq!(values, [0, 1, (1 << 63) - 1]);
+    ///
+    /// let mut values = [i64::MIN, i64::MAX, -1];
+    /// SimdMut::new(&mut values).min_assign(&SimdRef::new(&[i64::MAX, i64::MIN, 0]));
+    /// assert_eq!(values, [i64::MIN, i64::MIN, -1]);
+    ///
+    /// let mut values = [f32::NAN, 1.0, f32::NAN];
+    /// SimdMut::new(&mut values).min_assign(&SimdRef::new(&[2.0, f32::NAN, f32::NAN]));
+    /// assert_eq!(values[..2], [2.0, 1.0]);
+    /// assert!(values[2].is_nan());
+    /// ```
+    pub fn min_assign<R: Simd<T>>(&mut self, rhs: &R) {
+        self.zip_vectors(rhs, |left, right| unsafe {
+            <T as SimdOrdElement>::min(left, right)
+        });
+    }
+
+    /// Replaces each element with the larger of it and `rhs`.
+    /// Floating point NaN is ignored like `f32::max`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [40_000u16, 1, 32_768];
+    /// SimdMut::new(&mut values).max_assign(&SimdRef::new(&[100, 65_535, 32_767]));
+    /// assert_eq!(values, [40_000, 65_535, 32_768]);
+    ///
+    /// let mut values = [u32::MAX, 0, 1 << 31];
+    /// SimdMut::new(&mut values).max_assign(&SimdRef::new(&[1, 1 << 31, (1 << 31) - 1]));
+    /// assert_eq!(values, [u32::MAX, 1 << 31, 1 << 31]);
+    ///
+    /// let mut values = [u64::MAX, 1, 1 << 63];
+    /// SimdMut::new(&mut values).max_assign(&SimdRef::new(&[0, u64::MAX, (1 << 63) - 1]));
+    /// assert_eq!(values, [u64::MAX, u64::MAX, 1 << 63]);
+    ///
+    /// let mut values = [f64::NAN, -1.0];
+    /// SimdMut::new(&mut values).max_assign(&SimdRef::new(&[-2.0, f64::NAN]));
+    /// assert_eq!(values, [-2.0, -1.0]);
+    /// ```
+    pub fn max_assign<R: Simd<T>>(&mut self, rhs: &R) {
+        self.zip_vectors(rhs, |left, right| unsafe {
+            <T as SimdOrdElement>::max(left, right)
+        });
+    }
+
+    /// Restricts each element to `[min, max]`.
+    /// Floating point NaN is kept like `f32::clamp`.
+    /// # Panics
+    /// Panics if `min > max`, or either is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [-5.0f32, 0.5, 7.0, f32::NAN, f32::INFINITY];
+    /// SimdMut::new(&mut values).clamp(0.0, 1.0);
+    /// assert_eq!(values[..3], [0.0, 0.5, 1.0]);
+    /// assert!(values[3].is_nan());
+    /// assert_eq!(values[4], 1.0);
+    ///
+    /// let mut values = [0u64, 5, u64::MAX];
+    /// SimdMut::new(&mut values).clamp(1, 1 << 63);
+    /// assert_eq!(values, [1, 5, 1 << 63]);
+    /// ```
+    pub fn clamp(&mut self, min: T, max: T) {
+        assert!(min <= max, "min must not be greater than max");
         if !T::is_available() {
             panic!("simd is not available");
         }
 
-        let len = self.len();
-        let x = self.as_mut_ptr();
-
-        unsafe {
-            for i in 0..len / T::VECTOR_LEN {
-                let offset = i * T::VECTOR_LEN;
-                let x_vector = T::load(x.add(offset));
-                let result_vector = <T as SimdFloatingElement>::tanh(x_vector);
-                T::store(x.add(offset), result_vector);
-            }
-
-            let remaining = len % T::VECTOR_LEN;
-            if remaining != 0 {
-                let offset = len - remaining;
-                let x_vector = T::load_partial(x.add(offset), remaining);
-                let result_vector = <T as SimdFloatingElement>::tanh(x_vector);
-                T::store(x.add(offset), result_vector);
-            }
-        }
+        let (min, max) = unsafe { (T::set(min), T::set(max)) };
+        self.map_vectors(|x| unsafe { <T as SimdOrdElement>::clamp(x, min, max) });
     }
 }
 
@@ -429,14 +1877,376 @@ impl<'a, T: SimdElement> Deref for SimdMut<'a, T> {
     type Target = [T];
 
     fn deref(&self) -> &[T] {
-        &self.0
+        self.0
     }
 }
 
 impl<'a, T: SimdElement> DerefMut for SimdMut<'a, T> {
     fn deref_mut(&mut self) -> &mut [T] {
-        &mut self.0
+        self.0
     }
 }
 
 impl<'a, T: SimdElement> Simd<T> for SimdMut<'a, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
+    use num_traits::{Bounded, FromPrimitive};
+    use std::fmt::Debug;
+
+    trait Element:
+        SimdIntegerElement
+        + Bounded
+        + FromPrimitive
+        + OverflowingAdd
+        + OverflowingSub
+        + OverflowingMul
+        + Copy
+        + Debug
+    {
+    }
+
+    impl<
+        T: SimdIntegerElement
+            + Bounded
+            + FromPrimitive
+            + OverflowingAdd
+            + OverflowingSub
+            + OverflowingMul
+            + Copy
+            + Debug,
+    > Element for T
+    {
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    enum Op {
+        Add,
+        Sub,
+        Mul,
+    }
+
+    impl Op {
+        /// Returns `len` pairs of operands that do not overflow.
+        fn operands<T: Element>(self, len: usize) -> (Vec<T>, Vec<T>) {
+            let left = (0..len).map(|i| match self {
+                Op::Add => i % 50,
+                Op::Sub => i % 50 + 3,
+                Op::Mul => i % 10,
+            });
+            let left = left.map(|x| T::from_usize(x).unwrap()).collect();
+            let right = (0..len).map(|i| T::from_usize(i % 3).unwrap()).collect();
+            (left, right)
+        }
+
+        /// Returns a pair of operands that overflows.
+        fn overflowing_operands<T: Element>(self) -> (T, T) {
+            match self {
+                Op::Add => (T::max_value(), T::one()),
+                Op::Sub => (T::min_value(), T::one()),
+                Op::Mul => (T::max_value(), T::from_u8(2).unwrap()),
+            }
+        }
+
+        fn scalar<T: Element>(self, left: T, right: T) -> T {
+            match self {
+                Op::Add => left.overflowing_add(&right).0,
+                Op::Sub => left.overflowing_sub(&right).0,
+                Op::Mul => left.overflowing_mul(&right).0,
+            }
+        }
+
+        fn checked_assign<T: Element>(self, left: &mut [T], right: &[T]) -> Result<(), usize> {
+            let mut left = SimdMut::new(left);
+            let right = SimdRef::new(right);
+            match self {
+                Op::Add => left.checked_add_assign(&right),
+                Op::Sub => left.checked_sub_assign(&right),
+                Op::Mul => left.checked_mul_assign(&right),
+            }
+        }
+
+        fn overflowing_assign<T: Element>(self, left: &mut [T], right: &[T]) -> bool {
+            let mut left = SimdMut::new(left);
+            let right = SimdRef::new(right);
+            match self {
+                Op::Add => left.overflowing_add_assign(&right),
+                Op::Sub => left.overflowing_sub_assign(&right),
+                Op::Mul => left.overflowing_mul_assign(&right),
+            }
+        }
+    }
+
+    /// Checks `checked_*_assign` and `overflowing_*_assign` with an overflow at every index of two
+    /// vectors and a partial tail.
+    fn check_overflow<T: Element>() {
+        let len = 2 * T::VECTOR_LEN + T::VECTOR_LEN / 2 + 1;
+
+        for op in [Op::Add, Op::Sub, Op::Mul] {
+            let (left, right) = op.operands::<T>(len);
+            let wrapped = |left: &[T], right: &[T]| -> Vec<T> {
+                left.iter()
+                    .zip(right)
+                    .map(|(&l, &r)| op.scalar(l, r))
+                    .collect()
+            };
+
+            let mut values = left.clone();
+            assert_eq!(op.checked_assign(&mut values, &right), Ok(()), "{op:?}");
+            assert_eq!(values, wrapped(&left, &right), "{op:?}");
+            let mut values = left.clone();
+            assert!(!op.overflowing_assign(&mut values, &right), "{op:?}");
+            assert_eq!(values, wrapped(&left, &right), "{op:?}");
+
+            let (l, r) = op.overflowing_operands::<T>();
+            for index in 0..len {
+                // A second overflow later on must not be the one reported.
+                let (mut left, mut right) = (left.clone(), right.clone());
+                for i in [index, index + 2] {
+                    if i < len {
+                        left[i] = l;
+                        right[i] = r;
+                    }
+                }
+                let expected = wrapped(&left, &right);
+
+                let mut values = left.clone();
+                assert_eq!(op.checked_assign(&mut values, &right), Err(index), "{op:?}");
+                assert_eq!(values[..index], expected[..index], "{op:?} at {index}");
+                assert_eq!(values[index..], left[index..], "{op:?} at {index}");
+
+                let mut values = left.clone();
+                assert!(op.overflowing_assign(&mut values, &right), "{op:?}");
+                assert_eq!(values, expected, "{op:?} at {index}");
+            }
+
+            // Elements past the end of the left side are ignored, even if they would overflow.
+            let (mut left, mut right) = op.operands::<T>(len + 1);
+            left[len] = l;
+            right[len] = r;
+            let mut values = left.clone();
+            assert_eq!(
+                op.checked_assign(&mut values[..len], &right),
+                Ok(()),
+                "{op:?}"
+            );
+            assert_eq!(
+                values[..len],
+                wrapped(&left[..len], &right[..len]),
+                "{op:?}"
+            );
+            let mut values = left.clone();
+            assert!(!op.overflowing_assign(&mut values[..len], &right), "{op:?}");
+        }
+    }
+
+    #[test]
+    fn overflow_signed() {
+        check_overflow::<i8>();
+        check_overflow::<i16>();
+        check_overflow::<i32>();
+        check_overflow::<i64>();
+    }
+
+    #[test]
+    fn overflow_unsigned() {
+        check_overflow::<u8>();
+        check_overflow::<u16>();
+        check_overflow::<u32>();
+        check_overflow::<u64>();
+    }
+
+    /// Checks each rounding function on `values` against `scalar` bit for bit, so the sign of
+    /// zero results counts. Any NaN matches any NaN.
+    fn check_rounding<T: SimdFloatingElement + Copy + Debug>(
+        values: &[T],
+        to_bits: fn(T) -> u64,
+        is_nan: fn(T) -> bool,
+        scalar: [fn(T) -> T; 6],
+    ) {
+        let names = [
+            "floor",
+            "ceil",
+            "round",
+            "round_ties_even",
+            "trunc",
+            "fract",
+        ];
+        let simd: [fn(&mut SimdMut<T>); 6] = [
+            |x| x.floor(),
+            |x| x.ceil(),
+            |x| x.round(),
+            |x| x.round_ties_even(),
+            |x| x.trunc(),
+            |x| x.fract(),
+        ];
+        for ((name, f), scalar) in names.into_iter().zip(simd).zip(scalar) {
+            let mut output = values.to_vec();
+            f(&mut SimdMut::new(&mut output));
+            for (&x, &y) in values.iter().zip(&output) {
+                let expected = scalar(x);
+                assert!(
+                    to_bits(y) == to_bits(expected) || is_nan(y) && is_nan(expected),
+                    "{name}({x:?}) = {y:?}, expected {expected:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rounding() {
+        // Ties, signed zeros, the largest value below one half, values on either side of 2^23 and
+        // 2^52 where every value is an integer, subnormals, the extremes, infinities and NaN.
+        let values = [
+            0.5f32,
+            1.5,
+            2.5,
+            -0.5,
+            -1.5,
+            -2.5,
+            0.0,
+            -0.0,
+            0.25,
+            -0.25,
+            0.499_999_97,
+            -0.499_999_97,
+            1.75,
+            -1.75,
+            8_388_607.5,
+            -8_388_607.5,
+            8_388_608.0,
+            -8_388_609.0,
+            16_777_216.0,
+            1e-40,
+            -1e-40,
+            f32::MIN_POSITIVE,
+            f32::MAX,
+            f32::MIN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        check_rounding(
+            &values,
+            |x| x.to_bits() as u64,
+            f32::is_nan,
+            [
+                f32::floor,
+                f32::ceil,
+                f32::round,
+                f32::round_ties_even,
+                f32::trunc,
+                f32::fract,
+            ],
+        );
+
+        let values = [
+            0.5f64,
+            1.5,
+            2.5,
+            -0.5,
+            -1.5,
+            -2.5,
+            0.0,
+            -0.0,
+            0.25,
+            -0.25,
+            0.499_999_999_999_999_94,
+            -0.499_999_999_999_999_94,
+            1.75,
+            -1.75,
+            8_388_607.5,
+            -8_388_609.0,
+            4_503_599_627_370_495.5,
+            -4_503_599_627_370_495.5,
+            4_503_599_627_370_496.0,
+            -4_503_599_627_370_497.0,
+            9_007_199_254_740_992.0,
+            1e-310,
+            -1e-310,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::MIN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        check_rounding(
+            &values,
+            f64::to_bits,
+            f64::is_nan,
+            [
+                f64::floor,
+                f64::ceil,
+                f64::round,
+                f64::round_ties_even,
+                f64::trunc,
+                f64::fract,
+            ],
+        );
+    }
+
+    const EXPONENTS: [i32; 12] = [0, 1, 2, 3, 31, 1000, i32::MAX, -1, -2, -3, -1000, i32::MIN];
+
+    #[test]
+    fn powi_float() {
+        let values = [
+            0.0f64,
+            -0.0,
+            1.0,
+            -1.0,
+            2.0,
+            -0.5,
+            1.000_001,
+            -0.999_999,
+            1e300,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        for n in EXPONENTS {
+            let mut output = values;
+            SimdMut::new(&mut output).powi(n);
+            for (&x, &y) in values.iter().zip(&output) {
+                let expected = x.powi(n);
+                assert!(
+                    y.to_bits() == expected.to_bits() || y.is_nan() && expected.is_nan(),
+                    "{x:e}^{n} = {y:e}, expected {expected:e}"
+                );
+            }
+
+            let values = values.map(|x| x as f32);
+            let mut output = values;
+            SimdMut::new(&mut output).powi(n);
+            for (&x, &y) in values.iter().zip(&output) {
+                let expected = x.powi(n);
+                assert!(
+                    y.to_bits() == expected.to_bits() || y.is_nan() && expected.is_nan(),
+                    "{x:e}^{n} = {y:e}, expected {expected:e}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn powi_integer() {
+        let values = [0i32, 1, -1, 2, -2, 3, 7, i32::MAX, i32::MIN];
+        for n in EXPONENTS {
+            let mut output = values;
+            SimdMut::new(&mut output).powi(n);
+            for (&x, &y) in values.iter().zip(&output) {
+                let power = x.wrapping_pow(n.unsigned_abs());
+                // Negative powers divide one by the wrapped positive power, leaving one where
+                // that is zero.
+                let expected = match (n < 0, power) {
+                    (true, 0) => 1,
+                    (true, power) => 1 / power,
+                    (false, power) => power,
+                };
+                assert_eq!(y, expected, "{x}^{n}");
+            }
+        }
+    }
+}
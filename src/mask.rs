@@ -0,0 +1,296 @@
+use crate::*;
+
+/// Packed lane mask produced by SIMD comparisons.
+///
+/// Bit `i` tells whether the comparison held for element `i`.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash)]
+pub struct SimdMask {
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl SimdMask {
+    /// Creates new `SimdMask` with `len` unset bits.
+    pub fn new(len: usize) -> Self {
+        Self {
+            bits: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    /// Creates an empty `SimdMask` which can hold `capacity` bits without reallocating.
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            bits: Vec::with_capacity(capacity.div_ceil(64)),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the mask has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns bit `index`, or `None` if out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.bits[index / 64] >> (index % 64) & 1 != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets bit `index` to `value`.
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: bool) {
+        assert!(index < self.len, "index out of bounds");
+
+        let bit = 1 << (index % 64);
+        if value {
+            self.bits[index / 64] |= bit;
+        } else {
+            self.bits[index / 64] &= !bit;
+        }
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns true if any bit is set.
+    pub fn any(&self) -> bool {
+        self.bits.iter().any(|&word| word != 0)
+    }
+
+    /// Returns true if every bit is set.
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.len
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.bits[i / 64] >> (i % 64) & 1 != 0)
+    }
+
+    /// Returns the bits as `Vec<bool>`.
+    pub fn to_vec(&self) -> Vec<bool> {
+        self.iter().collect()
+    }
+
+    /// Returns `count` bits from `offset`.
+    #[inline(always)]
+    pub(crate) fn bits(&self, offset: usize, count: usize) -> u32 {
+        debug_assert!(count <= 32 && offset + count <= self.len);
+
+        let shift = offset % 64;
+        let mut bits = self.bits[offset / 64] >> shift;
+        if 64 < shift + count {
+            bits |= self.bits[offset / 64 + 1] << (64 - shift);
+        }
+        (bits & ((1u64 << count) - 1)) as u32
+    }
+
+    /// Appends the lowest `count` bits of `bits`.
+    #[inline(always)]
+    pub(crate) fn push_bits(&mut self, bits: u32, count: usize) {
+        debug_assert!(count <= 32);
+
+        let bits = bits as u64 & ((1u64 << count) - 1);
+        let shift = self.len % 64;
+        if shift == 0 {
+            self.bits.push(bits);
+        } else {
+            *self.bits.last_mut().unwrap() |= bits << shift;
+            if 64 < shift + count {
+                self.bits.push(bits >> (64 - shift));
+            }
+        }
+        self.len += count;
+    }
+}
+
+impl From<&[bool]> for SimdMask {
+    fn from(values: &[bool]) -> Self {
+        let mut mask = Self::new(values.len());
+        for (i, &value) in values.iter().enumerate() {
+            if value {
+                mask.bits[i / 64] |= 1 << (i % 64);
+            }
+        }
+        mask
+    }
+}
+
+/// Stores `b` where `mask` is set, otherwise `a`, to `dst`.
+pub fn select<T: SimdElement, A: Simd<T>, B: Simd<T>>(
+    dst: &mut SimdMut<T>,
+    mask: &SimdMask,
+    a: &A,
+    b: &B,
+) {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = dst.len().min(mask.len()).min(a.len()).min(b.len());
+    let dst = dst.as_mut_ptr();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    unsafe {
+        for i in 0..len / T::VECTOR_LEN {
+            let offset = i * T::VECTOR_LEN;
+            let mask_vector = T::from_bitmask(mask.bits(offset, T::VECTOR_LEN));
+            let a_vector = T::load(a.add(offset));
+            let b_vector = T::load(b.add(offset));
+            T::store(dst.add(offset), T::blend(mask_vector, a_vector, b_vector));
+        }
+
+        let remaining = len % T::VECTOR_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            let mask_vector = T::from_bitmask(mask.bits(offset, remaining));
+            let a_vector = T::load_partial(a.add(offset), remaining);
+            let b_vector = T::load_partial(b.add(offset), remaining);
+            T::store_partial(
+                dst.add(offset),
+                T::blend(mask_vector, a_vector, b_vector),
+                remaining,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a mask of `len` bits with a pattern that differs between neighbouring vectors.
+    fn pattern(len: usize) -> SimdMask {
+        let bits: Vec<bool> = (0..len).map(|i| i % 3 == 0 || i % 7 == 5).collect();
+        SimdMask::from(&bits[..])
+    }
+
+    /// Checks `select` and `blend_assign` on every length up to three vectors of `T` and past two
+    /// mask words, with masks shorter and longer than the slices.
+    fn check_select<T: SimdElement + Copy + PartialEq + core::fmt::Debug>(from: fn(usize) -> T) {
+        let lengths = (0..3 * T::VECTOR_LEN + 1).chain([130]);
+        for len in lengths {
+            let a: Vec<T> = (0..len).map(|i| from(2 * i % 200)).collect();
+            let b: Vec<T> = (0..len).map(|i| from((2 * i + 1) % 200)).collect();
+            let untouched = from(255);
+
+            for mask_len in [len.saturating_sub(5), len, len + 70] {
+                let mask = pattern(mask_len);
+                let used = len.min(mask_len);
+                let expected = |i: usize| match mask.get(i) {
+                    Some(true) => b[i],
+                    Some(false) => a[i],
+                    None => unreachable!(),
+                };
+
+                let mut dst = vec![untouched; len];
+                select(
+                    &mut SimdMut::new(&mut dst),
+                    &mask,
+                    &SimdRef::new(&a),
+                    &SimdRef::new(&b),
+                );
+                for (i, &value) in dst.iter().enumerate() {
+                    let expected = if i < used { expected(i) } else { untouched };
+                    assert_eq!(
+                        value, expected,
+                        "select of {len} with {mask_len} bits at {i}"
+                    );
+                }
+
+                let mut values = a.clone();
+                SimdMut::new(&mut values).blend_assign(&mask, &SimdRef::new(&b));
+                for (i, &value) in values.iter().enumerate() {
+                    let expected = if i < used { expected(i) } else { a[i] };
+                    assert_eq!(
+                        value, expected,
+                        "blend_assign of {len} with {mask_len} bits at {i}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn select_and_blend() {
+        check_select::<u8>(|x| x as u8);
+        check_select::<i16>(|x| x as i16);
+        check_select::<f32>(|x| x as f32);
+        check_select::<f64>(|x| x as f64);
+    }
+
+    #[test]
+    fn bits_across_words() {
+        let mut mask = SimdMask::new(130);
+        assert_eq!(mask.count_ones(), 0);
+        assert!(!mask.any());
+
+        for index in [0, 63, 64, 65, 127, 128, 129] {
+            mask.set(index, true);
+            assert_eq!(mask.get(index), Some(true), "bit {index}");
+        }
+        assert_eq!(mask.count_ones(), 7);
+        assert_eq!(mask.get(62), Some(false));
+        assert_eq!(mask.get(130), None);
+        assert!(mask.any() && !mask.all());
+
+        mask.set(64, false);
+        assert_eq!(mask.get(63), Some(true));
+        assert_eq!(mask.get(64), Some(false));
+        assert_eq!(mask.count_ones(), 6);
+
+        let expected: Vec<bool> = (0..130)
+            .map(|i| [0, 63, 65, 127, 128, 129].contains(&i))
+            .collect();
+        assert_eq!(mask.to_vec(), expected);
+        assert_eq!(SimdMask::from(&expected[..]), mask);
+        assert!(SimdMask::from(&[true; 130][..]).all());
+    }
+
+    #[test]
+    fn push_and_read_bits_across_words() {
+        let mut mask = SimdMask::with_capacity(0);
+        let mut expected = Vec::new();
+        for (i, count) in [32, 16, 32, 7, 32, 1, 32].into_iter().enumerate() {
+            let bits = 0x9e37_79b9u32.rotate_left(i as u32 * 5);
+            mask.push_bits(bits, count);
+            expected.extend((0..count).map(|j| bits >> j & 1 != 0));
+        }
+        assert_eq!(mask.len(), expected.len());
+        assert_eq!(mask.to_vec(), expected);
+
+        for offset in 0..expected.len() {
+            let count = 32.min(expected.len() - offset);
+            let bits = mask.bits(offset, count);
+            for j in 0..count {
+                assert_eq!(
+                    bits >> j & 1 != 0,
+                    expected[offset + j],
+                    "bit {j} from {offset}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn set_out_of_bounds() {
+        SimdMask::new(64).set(64, true);
+    }
+}
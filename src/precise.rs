@@ -0,0 +1,43 @@
+//! Transcendental functions within 1 ULP, the same as the methods of [`SimdMut`].
+//!
+//! These exist to be swapped with [`fast`](crate::fast) where the accuracy matters.
+//!
+//! # Example
+//! ```
+//! use carbon_simd::*;
+//!
+//! let mut values = [1.0f64, 2.0, 0.5, 10.0];
+//! precise::ln(&mut SimdMut::new(&mut values));
+//!
+//! let expected = [0.0f64, 2.0f64.ln(), 0.5f64.ln(), 10.0f64.ln()];
+//! for (value, expected) in values.iter().zip(expected) {
+//!     assert!((value - expected).abs() <= expected.abs() * f64::EPSILON);
+//! }
+//! ```
+
+use crate::*;
+
+/// Calculates `e^x` for each element.
+pub fn exp<T: SimdFloatingElement>(x: &mut SimdMut<T>) {
+    x.exp();
+}
+
+/// Calculates tanh of each element.
+pub fn tanh<T: SimdFloatingElement>(x: &mut SimdMut<T>) {
+    x.tanh();
+}
+
+/// Calculates the natural logarithm of each element.
+pub fn ln<T: SimdFloatingElement>(x: &mut SimdMut<T>) {
+    x.ln();
+}
+
+/// Calculates the sine of each element in radians.
+pub fn sin<T: SimdFloatingElement>(x: &mut SimdMut<T>) {
+    x.sin();
+}
+
+/// Calculates the cosine of each element in radians.
+pub fn cos<T: SimdFloatingElement>(x: &mut SimdMut<T>) {
+    x.cos();
+}
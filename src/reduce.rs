@@ -0,0 +1,775 @@
+use crate::*;
+
+impl<'a, T: SimdElement> SimdRef<'a, T> {
+    /// Folds the vectors of `self` with `f`, padding the remainder with `identity`.
+    #[inline(always)]
+    fn fold_vectors(
+        &self,
+        identity: T,
+        f: impl Fn(T::Vector, T::Vector) -> T::Vector,
+    ) -> T::Vector {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let x = self.as_ptr();
+
+        unsafe {
+            let identity = T::set(identity);
+            let mut acc = identity;
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                acc = f(acc, T::load(x.add(offset)));
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let mask = T::from_bitmask((1 << remaining) - 1);
+                let x_vector = T::blend(mask, identity, T::load_partial(x.add(offset), remaining));
+                acc = f(acc, x_vector);
+            }
+
+            acc
+        }
+    }
+}
+
+impl<'a, T: SimdNumElement> SimdRef<'a, T> {
+    /// Returns the sum of all elements. Integers wrap around on overflow.
+    pub fn sum(&self) -> T {
+        let acc = self.fold_vectors(T::zero(), |acc, x| unsafe {
+            <T as SimdNumElement>::add(acc, x)
+        });
+        unsafe { T::reduce_add(acc) }
+    }
+
+    /// Returns the product of all elements. Integers wrap around on overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+    ///
+    /// assert_eq!(SimdRef::new(&values).product(), 3628800.0);
+    /// ```
+    pub fn product(&self) -> T {
+        let acc = self.fold_vectors(T::one(), |acc, x| unsafe {
+            <T as SimdNumElement>::mul(acc, x)
+        });
+        unsafe { T::reduce_mul(acc) }
+    }
+}
+
+impl<'a, T: SimdFloatingElement> SimdRef<'a, T> {
+    /// Returns the sum of all elements using compensated (Kahan-Babuska) summation.
+    ///
+    /// The rounding error of each addition is carried in a separate accumulator,
+    /// so the result stays accurate for long slices where `sum` loses precision.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let values: Vec<f32> = (0..100000).map(|i| 1.0 + i as f32 * 1e-3).collect();
+    /// let reference: f64 = values.iter().map(|&x| x as f64).sum();
+    ///
+    /// let sum = SimdRef::new(&values).sum_compensated();
+    ///
+    /// assert!((sum as f64 - reference).abs() <= reference * f32::EPSILON as f64);
+    /// ```
+    pub fn sum_compensated(&self) -> T {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let x = self.as_ptr();
+
+        unsafe {
+            let mut sum = T::set(T::zero());
+            let mut compensation = sum;
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let x_vector = T::load(x.add(offset));
+                let (new_sum, error) = two_sum::<T>(sum, x_vector);
+                sum = new_sum;
+                compensation = <T as SimdNumElement>::add(compensation, error);
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let x_vector = T::load_partial(x.add(offset), remaining);
+                let (new_sum, error) = two_sum::<T>(sum, x_vector);
+                sum = new_sum;
+                compensation = <T as SimdNumElement>::add(compensation, error);
+            }
+
+            reduce_compensated::<T>(sum, compensation)
+        }
+    }
+
+    /// Returns the sum of all elements in a fixed association order.
+    ///
+    /// Element `i` is added to virtual lane `i % 8` in index order, then the 8 lanes are added
+    /// as `((l0 + l1) + (l2 + l3)) + ((l4 + l5) + (l6 + l7))`. The order does not depend on
+    /// `T::VECTOR_LEN`, so the result is bit-identical on every backend and CPU.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let values: Vec<f32> = (0..1000).map(|i| (i as f32).sin() * 1e4).collect();
+    ///
+    /// let mut lanes = [-0.0f32; 8];
+    /// for (i, &x) in values.iter().enumerate() {
+    ///     lanes[i % 8] += x;
+    /// }
+    /// let expected = ((lanes[0] + lanes[1]) + (lanes[2] + lanes[3]))
+    ///     + ((lanes[4] + lanes[5]) + (lanes[6] + lanes[7]));
+    ///
+    /// let sum = SimdRef::new(&values).sum_deterministic();
+    ///
+    /// assert_eq!(sum.to_bits(), expected.to_bits());
+    /// ```
+    pub fn sum_deterministic(&self) -> T {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let x = self.as_ptr();
+        unsafe {
+            deterministic_reduce::<T>(
+                self.len(),
+                |i| *x.add(i),
+                |offset, len| load::<T>(x.add(offset), len),
+            )
+        }
+    }
+}
+
+impl<'a, T: SimdOrdElement + Copy> SimdRef<'a, T> {
+    /// Returns the smallest element, or `None` if `self` is empty.
+    /// Floating point NaN is ignored like `f32::min`.
+    pub fn min(&self) -> Option<T> {
+        let first = *self.first()?;
+        let acc = self.fold_vectors(first, |acc, x| unsafe {
+            <T as SimdOrdElement>::min(acc, x)
+        });
+        Some(unsafe { T::reduce_min(acc) })
+    }
+
+    /// Returns the largest element, or `None` if `self` is empty.
+    /// Floating point NaN is ignored like `f32::max`.
+    pub fn max(&self) -> Option<T> {
+        let first = *self.first()?;
+        let acc = self.fold_vectors(first, |acc, x| unsafe {
+            <T as SimdOrdElement>::max(acc, x)
+        });
+        Some(unsafe { T::reduce_max(acc) })
+    }
+
+    /// Returns the index of the first smallest element, or `None` if `self` is empty.
+    /// Floating point NaN is ignored, and `Some(0)` is returned if every element is NaN.
+    pub fn argmin(&self) -> Option<usize> {
+        let min = self.min()?;
+        Some(self.position(min).unwrap_or(0))
+    }
+
+    /// Returns the index of the first largest element, or `None` if `self` is empty.
+    /// Floating point NaN is ignored, and `Some(0)` is returned if every element is NaN.
+    pub fn argmax(&self) -> Option<usize> {
+        let max = self.max()?;
+        Some(self.position(max).unwrap_or(0))
+    }
+
+    /// Returns the index of the first element equal to `value`.
+    #[inline(always)]
+    fn position(&self, value: T) -> Option<usize> {
+        let len = self.len();
+        let x = self.as_ptr();
+
+        unsafe {
+            let value = T::set(value);
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let found = T::to_bitmask(<T as SimdOrdElement>::eq(T::load(x.add(offset)), value));
+                if found != 0 {
+                    return Some(offset + found.trailing_zeros() as usize);
+                }
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let x_vector = T::load_partial(x.add(offset), remaining);
+                let found = T::to_bitmask(<T as SimdOrdElement>::eq(x_vector, value))
+                    & ((1 << remaining) - 1);
+                if found != 0 {
+                    return Some(offset + found.trailing_zeros() as usize);
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns the dot product of `a` and `b`. Integers wrap around on overflow.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let b = [5.0, 4.0, 3.0, 2.0, 1.0];
+///
+/// assert_eq!(dot(&SimdRef::new(&a), &SimdRef::new(&b)), 35.0);
+/// ```
+pub fn dot<T: SimdNumElement, A: Simd<T>, B: Simd<T>>(a: &A, b: &B) -> T {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = a.len().min(b.len());
+    unsafe { fma_reduce(a.as_ptr(), b.as_ptr(), len) }
+}
+
+/// Returns the sum of squares of `a`. Integers wrap around on overflow.
+pub fn sum_of_squares<T: SimdNumElement, A: Simd<T>>(a: &A) -> T {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    unsafe { fma_reduce(a.as_ptr(), a.as_ptr(), a.len()) }
+}
+
+/// Returns the sum of `values` weighted by `weights`. Integers wrap around on overflow.
+///
+/// This is [`dot`] with the arguments named for weighting, and the weights are not normalized.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let values = [1.0, 2.0, 3.0];
+/// let weights = [0.5, 0.25, 0.25];
+///
+/// assert_eq!(weighted_sum(&SimdRef::new(&values), &SimdRef::new(&weights)), 1.75);
+/// ```
+pub fn weighted_sum<T: SimdNumElement, V: Simd<T>, W: Simd<T>>(values: &V, weights: &W) -> T {
+    dot(values, weights)
+}
+
+/// Returns the sum of `a[i] * b[i]` for `i < len`.
+///
+/// Several independent accumulators are used so that each `fma` does not wait for the previous one.
+/// # Safety
+/// Make sure `T::is_available()` returns true and lengths of `a` and `b` are not less than `len`.
+#[inline(always)]
+unsafe fn fma_reduce<T: SimdNumElement>(a: *const T, b: *const T, len: usize) -> T {
+    const ACCUMULATORS: usize = 4;
+
+    unsafe {
+        let mut acc = [T::set(T::zero()); ACCUMULATORS];
+        let block_len = ACCUMULATORS * T::VECTOR_LEN;
+
+        for i in 0..len / block_len {
+            for (j, acc) in acc.iter_mut().enumerate() {
+                let offset = i * block_len + j * T::VECTOR_LEN;
+                *acc = T::fma(T::load(a.add(offset)), T::load(b.add(offset)), *acc);
+            }
+        }
+
+        let mut offset = len - len % block_len;
+        while offset + T::VECTOR_LEN <= len {
+            acc[0] = T::fma(T::load(a.add(offset)), T::load(b.add(offset)), acc[0]);
+            offset += T::VECTOR_LEN;
+        }
+
+        let remaining = len - offset;
+        if remaining != 0 {
+            let a_vector = T::load_partial(a.add(offset), remaining);
+            let b_vector = T::load_partial(b.add(offset), remaining);
+            acc[1] = T::fma(a_vector, b_vector, acc[1]);
+        }
+
+        let acc = <T as SimdNumElement>::add(
+            <T as SimdNumElement>::add(acc[0], acc[1]),
+            <T as SimdNumElement>::add(acc[2], acc[3]),
+        );
+        T::reduce_add(acc)
+    }
+}
+
+/// Returns the dot product of `a` and `b` using compensated summation.
+///
+/// Both the rounding error of each product and of each addition are carried, so the result is
+/// about as accurate as if it were computed with twice the precision.
+///
+/// # Example
+/// ```
+/// use carbon_simd::*;
+///
+/// let a: Vec<f32> = (0..10000).map(|i| (i % 7) as f32 - 3.1).collect();
+/// let b: Vec<f32> = (0..10000).map(|i| (i % 5) as f32 + 0.3).collect();
+/// let reference: f64 = a.iter().zip(&b).map(|(&x, &y)| x as f64 * y as f64).sum();
+///
+/// let dot = dot_compensated(&SimdRef::new(&a), &SimdRef::new(&b));
+///
+/// assert!((dot as f64 - reference).abs() <= reference.abs() * f32::EPSILON as f64);
+/// ```
+pub fn dot_compensated<T: SimdFloatingElement, A: Simd<T>, B: Simd<T>>(a: &A, b: &B) -> T {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = a.len().min(b.len());
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    unsafe {
+        let zero = T::set(T::zero());
+        let mut sum = zero;
+        let mut compensation = zero;
+
+        let mut step = |a_vector: T::Vector, b_vector: T::Vector| {
+            let product = <T as SimdNumElement>::mul(a_vector, b_vector);
+            let product_error = T::fma(
+                a_vector,
+                b_vector,
+                <T as SimdNumElement>::sub(zero, product),
+            );
+            let (new_sum, sum_error) = two_sum::<T>(sum, product);
+            sum = new_sum;
+            compensation = <T as SimdNumElement>::add(
+                compensation,
+                <T as SimdNumElement>::add(sum_error, product_error),
+            );
+        };
+
+        for i in 0..len / T::VECTOR_LEN {
+            let offset = i * T::VECTOR_LEN;
+            step(T::load(a.add(offset)), T::load(b.add(offset)));
+        }
+
+        let remaining = len % T::VECTOR_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            step(
+                T::load_partial(a.add(offset), remaining),
+                T::load_partial(b.add(offset), remaining),
+            );
+        }
+
+        reduce_compensated::<T>(sum, compensation)
+    }
+}
+
+/// Returns `left + right` and its rounding error (Knuth's TwoSum).
+/// # Safety
+/// Make sure `T::is_available()` returns true.
+#[inline(always)]
+unsafe fn two_sum<T: SimdNumElement>(left: T::Vector, right: T::Vector) -> (T::Vector, T::Vector) {
+    unsafe {
+        let sum = <T as SimdNumElement>::add(left, right);
+        let right_part = <T as SimdNumElement>::sub(sum, left);
+        let left_part = <T as SimdNumElement>::sub(sum, right_part);
+        let error = <T as SimdNumElement>::add(
+            <T as SimdNumElement>::sub(left, left_part),
+            <T as SimdNumElement>::sub(right, right_part),
+        );
+        (sum, error)
+    }
+}
+
+/// Largest `VECTOR_LEN` of floating point elements, which bounds the stack buffers of the lanes.
+const MAX_FLOAT_VECTOR_LEN: usize = 16;
+
+/// Adds all lanes of `sum` and `compensation` with scalar second-order (Kahan-Babuska-Klein) summation.
+/// # Safety
+/// Make sure `T::is_available()` returns true.
+#[inline(always)]
+unsafe fn reduce_compensated<T: SimdFloatingElement>(sum: T::Vector, compensation: T::Vector) -> T {
+    fn scalar_two_sum<T: Float>(left: T, right: T) -> (T, T) {
+        let sum = left + right;
+        let error = if right.abs() <= left.abs() {
+            (left - sum) + right
+        } else {
+            (right - sum) + left
+        };
+        (sum, error)
+    }
+
+    assert!(T::VECTOR_LEN <= MAX_FLOAT_VECTOR_LEN);
+    let mut lanes = [T::zero(); 2 * MAX_FLOAT_VECTOR_LEN];
+    unsafe {
+        T::store(lanes.as_mut_ptr(), sum);
+        T::store(lanes.as_mut_ptr().add(T::VECTOR_LEN), compensation);
+    }
+
+    let mut total = T::zero();
+    let mut compensation1 = T::zero();
+    let mut compensation2 = T::zero();
+    for &lane in &lanes[..2 * T::VECTOR_LEN] {
+        let (new_total, error) = scalar_two_sum(total, lane);
+        let (new_compensation1, error) = scalar_two_sum(compensation1, error);
+        total = new_total;
+        compensation1 = new_compensation1;
+        compensation2 = compensation2 + error;
+    }
+
+    (total + compensation1) + compensation2
+}
+
+/// Returns the dot product of `a` and `b` in a fixed association order.
+///
+/// Products are accumulated like `SimdRef::sum_deterministic`. Each product is rounded before
+/// it is added instead of using `fma`, so the result is bit-identical on every backend and CPU.
+pub fn dot_deterministic<T: SimdFloatingElement, A: Simd<T>, B: Simd<T>>(a: &A, b: &B) -> T {
+    if !T::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = a.len().min(b.len());
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    unsafe {
+        deterministic_reduce::<T>(
+            len,
+            |i| *a.add(i) * *b.add(i),
+            |offset, len| {
+                <T as SimdNumElement>::mul(
+                    load::<T>(a.add(offset), len),
+                    load::<T>(b.add(offset), len),
+                )
+            },
+        )
+    }
+}
+
+/// Loads `len` values, using `load` for a full vector and `load_partial` otherwise.
+/// # Safety
+/// Make sure `T::is_available()` returns true and length of `src` is not less than `len`.
+#[inline(always)]
+unsafe fn load<T: SimdElement>(src: *const T, len: usize) -> T::Vector {
+    unsafe {
+        if len == T::VECTOR_LEN {
+            T::load(src)
+        } else {
+            T::load_partial(src, len)
+        }
+    }
+}
+
+/// Number of virtual lanes of deterministic reductions.
+const DETERMINISTIC_LANES: usize = 8;
+
+/// Adds `len` values into `DETERMINISTIC_LANES` virtual lanes, then adds the lanes in a fixed tree.
+///
+/// `scalar(i)` returns value `i`, and `vector(offset, len)` returns values from `offset`,
+/// of which only the first `len` lanes are used.
+/// # Safety
+/// Make sure `T::is_available()` returns true and both closures are valid for indices less than `len`.
+#[inline(always)]
+unsafe fn deterministic_reduce<T: SimdFloatingElement>(
+    len: usize,
+    scalar: impl Fn(usize) -> T,
+    vector: impl Fn(usize, usize) -> T::Vector,
+) -> T {
+    // `-0.0 + x` is exactly `x` for every `x`, unlike `0.0 + -0.0`.
+    let identity = -T::zero();
+    let mut lanes = [identity; DETERMINISTIC_LANES];
+
+    if T::VECTOR_LEN <= DETERMINISTIC_LANES && DETERMINISTIC_LANES.is_multiple_of(T::VECTOR_LEN) {
+        let vectors = DETERMINISTIC_LANES / T::VECTOR_LEN;
+
+        unsafe {
+            let identity_vector = T::set(identity);
+            let mut acc = [identity_vector; DETERMINISTIC_LANES];
+
+            for offset in (0..len).step_by(DETERMINISTIC_LANES) {
+                for (j, acc) in acc.iter_mut().take(vectors).enumerate() {
+                    let offset = offset + j * T::VECTOR_LEN;
+                    if len <= offset {
+                        break;
+                    }
+
+                    let count = (len - offset).min(T::VECTOR_LEN);
+                    let mask = T::from_bitmask((1 << count) - 1);
+                    let x_vector = T::blend(mask, identity_vector, vector(offset, count));
+                    *acc = <T as SimdNumElement>::add(*acc, x_vector);
+                }
+            }
+
+            for (i, lane) in lanes.iter_mut().enumerate() {
+                let vector = acc[i / T::VECTOR_LEN];
+                let lane_mask = T::from_bitmask(1 << (i % T::VECTOR_LEN));
+                *lane = T::reduce_add(T::blend(lane_mask, identity_vector, vector));
+            }
+        }
+    } else {
+        for i in 0..len {
+            lanes[i % DETERMINISTIC_LANES] = lanes[i % DETERMINISTIC_LANES] + scalar(i);
+        }
+    }
+
+    ((lanes[0] + lanes[1]) + (lanes[2] + lanes[3]))
+        + ((lanes[4] + lanes[5]) + (lanes[6] + lanes[7]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the sum of `values` accumulated in double-double, rounded to `f64`.
+    fn sum_reference(values: impl Iterator<Item = f64>) -> f64 {
+        let mut sum = 0.0f64;
+        let mut error = 0.0f64;
+        for x in values {
+            let new_sum = sum + x;
+            let x_part = new_sum - sum;
+            error += (sum - (new_sum - x_part)) + (x - x_part);
+            sum = new_sum;
+        }
+        sum + error
+    }
+
+    /// Asserts that `got` is within 1 ULP of `T` of `expected`.
+    fn assert_within_ulp<T: Float + Into<f64>>(name: &str, len: usize, got: T, expected: f64) {
+        let ulp = 2f64.powi(expected.abs().log2().floor() as i32) * T::epsilon().into();
+        let error = (got.into() - expected).abs();
+        assert!(
+            error <= ulp,
+            "{name} of {len} elements: {} instead of {expected}",
+            got.into()
+        );
+    }
+
+    /// Returns `len` values of `big, 0.1, -big` repeated and ending with `big`, whose plain sum
+    /// loses every `0.1`.
+    fn cancelling<T: Float>(len: usize, big: T) -> Vec<T> {
+        let pattern = [big, T::from(0.1).unwrap(), -big];
+        (0..len)
+            .map(|i| if i == len - 1 { big } else { pattern[i % 3] })
+            .collect()
+    }
+
+    fn check_compensated<T: SimdFloatingElement + Copy + Into<f64>>(big: T) {
+        let lengths = (0..3 * T::VECTOR_LEN + 1).chain([1000, 3001, 3000 + T::VECTOR_LEN - 1]);
+        for len in lengths {
+            let values = cancelling(len, big);
+            let expected = sum_reference(values.iter().map(|&x| x.into()));
+            let sum = SimdRef::new(&values).sum_compensated();
+            assert_within_ulp("sum_compensated", len, sum, expected);
+
+            // Every product is one of the values of `cancelling` times 3, or 3 times 0.1 rounded,
+            // and the rounding error of each product is carried as well.
+            let weights: Vec<T> = (0..len).map(|i| T::from(1 + i % 5).unwrap()).collect();
+            let expected = sum_reference(values.iter().zip(&weights).flat_map(|(&x, &w)| {
+                let (x, w): (f64, f64) = (x.into(), w.into());
+                let product = x * w;
+                [product, x.mul_add(w, -product)]
+            }));
+            let dot = dot_compensated(&SimdRef::new(&values), &SimdRef::new(&weights));
+            assert_within_ulp("dot_compensated", len, dot, expected);
+        }
+    }
+
+    #[test]
+    fn compensated() {
+        check_compensated(1e7f32);
+        check_compensated(1e15f64);
+    }
+
+    /// Returns the first smallest and largest of `values` with their indices, ignoring NaN.
+    fn min_max_reference<T: PartialOrd + Copy>(values: &[T]) -> ((T, usize), (T, usize)) {
+        let is_nan = |x: T| x.partial_cmp(&x).is_none();
+        let mut min = (values[0], 0);
+        let mut max = (values[0], 0);
+        for (i, &x) in values.iter().enumerate() {
+            // NaN is not comparable and a NaN first element is replaced by anything else.
+            if x < min.0 || (is_nan(min.0) && !is_nan(x)) {
+                min = (x, i);
+            }
+            if x > max.0 || (is_nan(max.0) && !is_nan(x)) {
+                max = (x, i);
+            }
+        }
+        (min, max)
+    }
+
+    fn check_reductions<T>(from: fn(i32) -> T)
+    where
+        T: SimdNumElement + SimdOrdElement + Copy + PartialOrd + core::fmt::Debug,
+    {
+        let empty = SimdRef::<T>::new(&[]);
+        assert_eq!(empty.sum(), T::zero());
+        assert_eq!(empty.product(), T::one());
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+        assert_eq!(empty.argmin(), None);
+        assert_eq!(empty.argmax(), None);
+
+        for len in 1..3 * T::VECTOR_LEN + 1 {
+            // All negative or all positive, so padding the tail with zero would change `max` or
+            // `min`, and ties with the extreme values, where the first index must be returned.
+            let negative: Vec<T> = (0..len).map(|i| from(-1 - (i * 7 % 13) as i32)).collect();
+            let positive: Vec<T> = (0..len).map(|i| from(1 + (i * 5 % 11) as i32)).collect();
+            let mut ties = negative.clone();
+            ties[len / 2] = from(-20);
+            ties[len / 3] = from(0);
+            ties[len - 1] = from(-20);
+            if len / 3 != len / 2 {
+                ties[len - 1 - len / 3] = from(0);
+            }
+
+            for values in [&negative, &positive, &ties] {
+                let x = SimdRef::new(values);
+                let sum = values.iter().fold(T::zero(), |acc, &x| acc + x);
+                let ((min, argmin), (max, argmax)) = min_max_reference(values);
+                assert_eq!(x.sum(), sum, "sum of {values:?}");
+                assert_eq!(x.min(), Some(min), "min of {values:?}");
+                assert_eq!(x.max(), Some(max), "max of {values:?}");
+                assert_eq!(x.argmin(), Some(argmin), "argmin of {values:?}");
+                assert_eq!(x.argmax(), Some(argmax), "argmax of {values:?}");
+            }
+
+            // Padding the tail with zero would zero the product, and the factors are exact.
+            let factors: Vec<T> = (0..len).map(|i| from([2, -1, -2, 3][i % 4])).collect();
+            let product = factors.iter().fold(T::one(), |acc, &x| acc * x);
+            assert_eq!(
+                SimdRef::new(&factors).product(),
+                product,
+                "product of {factors:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn reductions() {
+        check_reductions(|x| x);
+        check_reductions(|x| x as f32);
+    }
+
+    #[test]
+    fn reductions_ignore_nan() {
+        for len in 1..3 * f32::VECTOR_LEN + 1 {
+            let values: Vec<f32> = (0..len)
+                .map(|i| match i % 5 {
+                    0 | 3 => f32::NAN,
+                    _ => (i * 7 % 13) as f32 - 6.0,
+                })
+                .collect();
+            let x = SimdRef::new(&values);
+            if len == 1 {
+                assert!(x.min().unwrap().is_nan() && x.max().unwrap().is_nan());
+                assert_eq!((x.argmin(), x.argmax()), (Some(0), Some(0)));
+                continue;
+            }
+
+            let ((min, argmin), (max, argmax)) = min_max_reference(&values);
+            assert_eq!(x.min(), Some(min), "min of {values:?}");
+            assert_eq!(x.max(), Some(max), "max of {values:?}");
+            assert_eq!(x.argmin(), Some(argmin), "argmin of {values:?}");
+            assert_eq!(x.argmax(), Some(argmax), "argmax of {values:?}");
+        }
+
+        let values = [f32::NAN; 11];
+        assert_eq!(SimdRef::new(&values).argmin(), Some(0));
+        assert_eq!(SimdRef::new(&values).argmax(), Some(0));
+    }
+
+    fn check_fma_reduce<T>(from: fn(i32) -> T)
+    where
+        T: SimdNumElement + Copy + core::fmt::Debug,
+    {
+        // Every full block of the 4 accumulators, followed by every number of vectors and
+        // elements left over. The values are small integers, so floating point results are exact.
+        let block_len = 4 * T::VECTOR_LEN;
+        for len in (0..3).flat_map(|k| (0..block_len).map(move |r| k * block_len + r)) {
+            let a: Vec<T> = (0..len).map(|i| from((i * 7 % 19) as i32 - 9)).collect();
+            let b: Vec<T> = (0..len + 3)
+                .map(|i| from((i * 5 % 13) as i32 - 6))
+                .collect();
+            let dot_product = a
+                .iter()
+                .zip(&b)
+                .fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+            let squares = a.iter().fold(T::zero(), |acc, &x| acc + x * x);
+
+            let (a, b) = (SimdRef::new(&a), SimdRef::new(&b));
+            assert_eq!(dot(&a, &b), dot_product, "dot of {len} elements");
+            assert_eq!(dot(&b, &a), dot_product, "dot of {len} elements");
+            assert_eq!(
+                weighted_sum(&a, &b),
+                dot_product,
+                "weighted_sum of {len} elements"
+            );
+            assert_eq!(
+                sum_of_squares(&a),
+                squares,
+                "sum_of_squares of {len} elements"
+            );
+        }
+    }
+
+    #[test]
+    fn fma_reductions() {
+        check_fma_reduce(|x| x as f32);
+        check_fma_reduce(|x| x as f64);
+        check_fma_reduce(|x| x);
+    }
+
+    /// Returns the sum of `values` in the documented order of the deterministic reductions.
+    fn deterministic_reference<T: Float>(values: impl Iterator<Item = T>) -> T {
+        let mut lanes = [-T::zero(); 8];
+        for (i, x) in values.enumerate() {
+            lanes[i % 8] = lanes[i % 8] + x;
+        }
+        ((lanes[0] + lanes[1]) + (lanes[2] + lanes[3]))
+            + ((lanes[4] + lanes[5]) + (lanes[6] + lanes[7]))
+    }
+
+    fn check_deterministic<T>(to_bits: fn(T) -> u64)
+    where
+        T: SimdFloatingElement + Copy + core::fmt::Debug,
+    {
+        // Values of very different magnitudes and signs, so that any other order rounds
+        // differently.
+        let value = |i: usize| {
+            let x = T::from((i * 37 % 101) as f64 - 50.3).unwrap();
+            x * T::from(10f64.powi((i % 7) as i32 * 3 - 9)).unwrap()
+        };
+        for len in (0..70).chain([1000, 1001, 1007]) {
+            let a: Vec<T> = (0..len).map(value).collect();
+            let b: Vec<T> = (0..len + 2).map(|i| value(i + 3)).collect();
+
+            let sum = SimdRef::new(&a).sum_deterministic();
+            let expected = deterministic_reference(a.iter().copied());
+            assert_eq!(to_bits(sum), to_bits(expected), "sum of {a:?}");
+
+            let dot = dot_deterministic(&SimdRef::new(&a), &SimdRef::new(&b));
+            let expected = deterministic_reference(a.iter().zip(&b).map(|(&x, &y)| x * y));
+            assert_eq!(to_bits(dot), to_bits(expected), "dot of {a:?} and {b:?}");
+        }
+
+        // The lanes start at negative zero, so the sign of zero is kept.
+        let zeros = [-T::zero(); 3];
+        let sum = SimdRef::new(&zeros).sum_deterministic();
+        assert_eq!(to_bits(sum), to_bits(-T::zero()));
+    }
+
+    #[test]
+    fn deterministic() {
+        check_deterministic(|x: f32| x.to_bits() as u64);
+        check_deterministic(f64::to_bits);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::*;
+
+impl<'a, T: SimdFloatingElement + SimdOrdElement> SimdMut<'a, T> {
+    /// Returns the sum of `e^(x - max)` over all elements, storing each term in place of its
+    /// element if `STORE` is set.
+    #[inline(always)]
+    fn exp_shifted_sum<const STORE: bool>(&mut self, max: T) -> T {
+        if !T::is_available() {
+            panic!("simd is not available");
+        }
+
+        let len = self.len();
+        let x = self.as_mut_ptr();
+
+        unsafe {
+            let max = T::set(max);
+            let zero = T::set(T::zero());
+            let mut sum = zero;
+
+            for i in 0..len / T::VECTOR_LEN {
+                let offset = i * T::VECTOR_LEN;
+                let e = <T as SimdFloatingElement>::exp(<T as SimdNumElement>::sub(
+                    T::load(x.add(offset)),
+                    max,
+                ));
+                if STORE {
+                    T::store(x.add(offset), e);
+                }
+                sum = <T as SimdNumElement>::add(sum, e);
+            }
+
+            let remaining = len % T::VECTOR_LEN;
+            if remaining != 0 {
+                let offset = len - remaining;
+                let e = <T as SimdFloatingElement>::exp(<T as SimdNumElement>::sub(
+                    T::load_partial(x.add(offset), remaining),
+                    max,
+                ));
+                if STORE {
+                    T::store_partial(x.add(offset), e, remaining);
+                }
+                let mask = T::from_bitmask((1 << remaining) - 1);
+                sum = <T as SimdNumElement>::add(sum, T::blend(mask, zero, e));
+            }
+
+            T::reduce_add(sum)
+        }
+    }
+
+    /// Replaces the elements with their softmax, `e^x / sum(e^x)`, so they add up to one.
+    ///
+    /// The largest element is subtracted before exponentiating, so large inputs do not overflow.
+    /// Takes one pass to find the largest element, one to exponentiate and sum, and one to
+    /// normalize.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [1000.0f32, 1000.0, 1000.0, 1000.0, -1000.0];
+    /// SimdMut::new(&mut values).softmax();
+    ///
+    /// assert_eq!(values, [0.25, 0.25, 0.25, 0.25, 0.0]);
+    /// ```
+    pub fn softmax(&mut self) {
+        let Some(max) = SimdRef::new(self).max() else {
+            return;
+        };
+
+        let sum = self.exp_shifted_sum::<true>(max);
+        let scale = unsafe { T::set(T::one() / sum) };
+        self.map_vectors(|x| unsafe { <T as SimdNumElement>::mul(x, scale) });
+    }
+
+    /// Replaces the elements with the natural logarithm of their softmax,
+    /// `x - ln(sum(e^x))`.
+    ///
+    /// Unlike taking [`ln`](Self::ln) of [`softmax`](Self::softmax), elements far below the
+    /// largest one stay finite instead of underflowing to negative infinity.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut values = [0.0f64, 0.0, -1000.0];
+    /// SimdMut::new(&mut values).log_softmax();
+    ///
+    /// let ln2 = std::f64::consts::LN_2;
+    /// assert_eq!(values, [-ln2, -ln2, -1000.0 - ln2]);
+    /// ```
+    pub fn log_softmax(&mut self) {
+        let Some(max) = SimdRef::new(self).max() else {
+            return;
+        };
+
+        let sum = self.exp_shifted_sum::<false>(max);
+        let shift = unsafe { T::set(max + sum.ln()) };
+        self.map_vectors(|x| unsafe { <T as SimdNumElement>::sub(x, shift) });
+    }
+
+    /// Applies [`softmax`](Self::softmax) to each row of `row_len` consecutive elements, as in a
+    /// row-major matrix.
+    ///
+    /// # Panics
+    /// Panics if `row_len` is zero or does not divide the length.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut scores = [0.0f32, 0.0, 5.0, 5.0, 1.0, 1.0];
+    /// SimdMut::new(&mut scores).softmax_rows(2);
+    ///
+    /// assert_eq!(scores, [0.5; 6]);
+    /// ```
+    pub fn softmax_rows(&mut self, row_len: usize) {
+        for row in self.rows_mut(row_len) {
+            SimdMut::new(row).softmax();
+        }
+    }
+
+    /// Applies [`log_softmax`](Self::log_softmax) to each row of `row_len` consecutive elements,
+    /// as in a row-major matrix.
+    ///
+    /// # Panics
+    /// Panics if `row_len` is zero or does not divide the length.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let mut scores = [0.0f64, 0.0, 3.0, f64::NEG_INFINITY];
+    /// SimdMut::new(&mut scores).log_softmax_rows(2);
+    ///
+    /// let ln2 = std::f64::consts::LN_2;
+    /// assert_eq!(scores, [-ln2, -ln2, 0.0, f64::NEG_INFINITY]);
+    /// ```
+    pub fn log_softmax_rows(&mut self, row_len: usize) {
+        for row in self.rows_mut(row_len) {
+            SimdMut::new(row).log_softmax();
+        }
+    }
+
+    #[inline(always)]
+    fn rows_mut(&mut self, row_len: usize) -> core::slice::ChunksExactMut<'_, T> {
+        if row_len == 0 || !self.len().is_multiple_of(row_len) {
+            panic!("row length must be nonzero and divide the length");
+        }
+
+        self.chunks_exact_mut(row_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Returns `len` scores spread over a wide range, with the largest ones in different lanes
+    /// and some negative infinities.
+    fn scores(len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| match i % 11 {
+                7 => f64::NEG_INFINITY,
+                _ => ((i * 37) % 101) as f64 * 0.9 - 45.0 + i as f64 * 0.25,
+            })
+            .collect()
+    }
+
+    fn softmax_reference(x: &[f64]) -> Vec<f64> {
+        let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let sum: f64 = x.iter().map(|x| (x - max).exp()).sum();
+        x.iter().map(|x| (x - max).exp() / sum).collect()
+    }
+
+    fn log_softmax_reference(x: &[f64]) -> Vec<f64> {
+        let max = x.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let sum: f64 = x.iter().map(|x| (x - max).exp()).sum();
+        x.iter().map(|x| x - max - sum.ln()).collect()
+    }
+
+    /// Asserts that `got` is within `tolerance` of `expected`, relative to `expected` but at
+    /// least to `scale`.
+    fn assert_close(got: &[f64], expected: &[f64], tolerance: f64, scale: f64) {
+        for (i, (got, expected)) in got.iter().zip(expected).enumerate() {
+            assert!(
+                got == expected || (got - expected).abs() <= tolerance * expected.abs().max(scale),
+                "{got} at {i}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn scalar_reference() {
+        for len in 1..100 {
+            let scores = scores(len);
+
+            let mut values = scores.clone();
+            SimdMut::new(&mut values).softmax();
+            assert_close(&values, &softmax_reference(&scores), 1e-13, 0.0);
+
+            let mut values = scores.clone();
+            SimdMut::new(&mut values).log_softmax();
+            assert_close(&values, &log_softmax_reference(&scores), 1e-13, 1.0);
+
+            let narrow: Vec<f32> = scores.iter().map(|&x| x as f32).collect();
+            let mut values = narrow.clone();
+            SimdMut::new(&mut values).softmax();
+            let wide: Vec<f64> = narrow.iter().map(|&x| x as f64).collect();
+            let values: Vec<f64> = values.iter().map(|&x| x as f64).collect();
+            assert_close(
+                &values,
+                &softmax_reference(&wide),
+                1e-5,
+                f32::MIN_POSITIVE as f64,
+            );
+        }
+    }
+
+    #[test]
+    fn rows() {
+        let row_len = 13;
+        let scores = scores(row_len * 5);
+
+        let mut values = scores.clone();
+        SimdMut::new(&mut values).softmax_rows(row_len);
+        for (row, scores) in values.chunks(row_len).zip(scores.chunks(row_len)) {
+            assert_close(row, &softmax_reference(scores), 1e-13, 0.0);
+        }
+
+        let mut values = scores.clone();
+        SimdMut::new(&mut values).log_softmax_rows(row_len);
+        for (row, scores) in values.chunks(row_len).zip(scores.chunks(row_len)) {
+            assert_close(row, &log_softmax_reference(scores), 1e-13, 1.0);
+        }
+    }
+
+    #[test]
+    fn special_values() {
+        let mut values: [f64; 0] = [];
+        SimdMut::new(&mut values).softmax();
+
+        // Lanes holding only negative infinity contribute nothing.
+        let mut values = [
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+            f64::NEG_INFINITY,
+            0.0,
+            0.0,
+        ];
+        SimdMut::new(&mut values).softmax();
+        assert_eq!(values, [0.0, 0.0, 0.0, 0.5, 0.5]);
+
+        let mut values = [1.0f32, f32::NAN, 2.0];
+        SimdMut::new(&mut values).softmax();
+        assert!(values.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    #[should_panic(expected = "row length must be nonzero and divide the length")]
+    fn rows_must_divide_length() {
+        SimdMut::new(&mut [0.0f32; 6]).log_softmax_rows(4);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::*;
+
+/// Returns the sum of all elements of `x`, accumulated in lanes of `U`.
+#[inline(always)]
+fn widening_sum<T: SimdWideningSum<U>, U: SimdNumElement>(x: &[T]) -> U {
+    if !T::is_available() || !U::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = x.len();
+    let x = x.as_ptr();
+
+    unsafe {
+        let mut acc = U::set(U::zero());
+
+        for i in 0..len / T::VECTOR_LEN {
+            let offset = i * T::VECTOR_LEN;
+            let x_vector = T::load(x.add(offset));
+            acc = <U as SimdNumElement>::add(acc, T::widening_sum(x_vector));
+        }
+
+        let remaining = len % T::VECTOR_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            let x_vector = T::load_partial(x.add(offset), remaining);
+            acc = <U as SimdNumElement>::add(acc, T::widening_sum(x_vector));
+        }
+
+        U::reduce_add(acc)
+    }
+}
+
+/// Returns the dot product of `left` and `right`, accumulated in lanes of `U`.
+#[inline(always)]
+fn widening_dot<T: SimdWideningDot<R, U>, R: SimdNumElement, U: SimdNumElement>(
+    left: &[T],
+    right: &[R],
+) -> U {
+    if !T::is_available() || !R::is_available() || !U::is_available() {
+        panic!("simd is not available");
+    }
+
+    let len = left.len().min(right.len());
+    let left = left.as_ptr();
+    let right = right.as_ptr();
+
+    unsafe {
+        let mut acc = U::set(U::zero());
+
+        for i in 0..len / T::VECTOR_LEN {
+            let offset = i * T::VECTOR_LEN;
+            let left_vector = T::load(left.add(offset));
+            let right_vector = R::load(right.add(offset));
+            acc = <U as SimdNumElement>::add(acc, T::widening_dot(left_vector, right_vector));
+        }
+
+        let remaining = len % T::VECTOR_LEN;
+        if remaining != 0 {
+            let offset = len - remaining;
+            let left_vector = T::load_partial(left.add(offset), remaining);
+            let right_vector = R::load_partial(right.add(offset), remaining);
+            acc = <U as SimdNumElement>::add(acc, T::widening_dot(left_vector, right_vector));
+        }
+
+        U::reduce_add(acc)
+    }
+}
+
+impl<'a> SimdRef<'a, u8> {
+    /// Returns the sum of all elements without overflow, widening to `u64`.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// let pixels = [255u8; 100];
+    ///
+    /// assert_eq!(SimdRef::new(&pixels).sum_u64(), 25500);
+    /// ```
+    pub fn sum_u64(&self) -> u64 {
+        widening_sum(self)
+    }
+
+    /// Returns the dot product with `rhs`, widening to `i32`.
+    ///
+    /// Each product is exact and the sum wraps around on `i32` overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use carbon_simd::*;
+    ///
+    /// // The extreme products, which would saturate if pairs of them were added in `i16`.
+    /// let weights = [-128i8; 100];
+    /// let pixels = [255u8; 100];
+    ///
+    /// assert_eq!(SimdRef::new(&pixels).dot_i32(&SimdRef::new(&weights)), 100 * 255 * -128);
+    ///
+    /// let weights = [127i8; 100];
+    ///
+    /// assert_eq!(SimdRef::new(&pixels).dot_i32(&SimdRef::new(&weights)), 100 * 255 * 127);
+    /// ```
+    pub fn dot_i32<R: Simd<i8>>(&self, rhs: &R) -> i32 {
+        widening_dot(self, rhs)
+    }
+}
+
+impl<'a> SimdRef<'a, i16> {
+    /// Returns the sum of all elements, widening to `i32`.
+    ///
+    /// The sum wraps around on `i32` overflow.
+    pub fn sum_i32(&self) -> i32 {
+        widening_sum(self)
+    }
+
+    /// Returns the dot product with `rhs`, widening to `i32`.
+    ///
+    /// Each product is exact and the sum wraps around on `i32` overflow.
+    pub fn dot_i32<R: Simd<i16>>(&self, rhs: &R) -> i32 {
+        widening_dot(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Returns lengths of up to three vectors of `T` and a long one, to cover every tail.
+    fn lengths<T: SimdElement>() -> impl Iterator<Item = usize> {
+        (0..3 * T::VECTOR_LEN + 1).chain([1000 + T::VECTOR_LEN - 1])
+    }
+
+    #[test]
+    fn u8() {
+        for len in lengths::<u8>() {
+            let pixels: Vec<u8> = (0..len).map(|i| (i * 97 % 256) as u8).collect();
+            let sum: u64 = pixels.iter().map(|&x| x as u64).sum();
+            assert_eq!(
+                SimdRef::new(&pixels).sum_u64(),
+                sum,
+                "sum of {len} elements"
+            );
+
+            let weights: Vec<i8> = (0..len + 1).map(|i| (i * 61 % 256) as u8 as i8).collect();
+            let dot = pixels
+                .iter()
+                .zip(&weights)
+                .fold(0i32, |acc, (&x, &y)| acc.wrapping_add(x as i32 * y as i32));
+            let pixels = SimdRef::new(&pixels);
+            assert_eq!(
+                pixels.dot_i32(&SimdRef::new(&weights)),
+                dot,
+                "dot of {len} elements"
+            );
+
+            // The largest products, which would saturate if added in pairs in `i16`.
+            let pixels = vec![255u8; len];
+            let pixels = SimdRef::new(&pixels);
+            let weights = SimdRef::new(&[-128i8; 1100]);
+            assert_eq!(pixels.sum_u64(), 255 * len as u64);
+            assert_eq!(pixels.dot_i32(&weights), -255 * 128 * len as i32);
+        }
+    }
+
+    #[test]
+    fn i16() {
+        for len in lengths::<i16>() {
+            let values: Vec<i16> = (0..len)
+                .map(|i| match i % 9 {
+                    0 => i16::MIN,
+                    4 => i16::MAX,
+                    _ => (i * 7919 % 65536) as u16 as i16,
+                })
+                .collect();
+            let sum = values.iter().map(|&x| x as i32).sum();
+            assert_eq!(
+                SimdRef::new(&values).sum_i32(),
+                sum,
+                "sum of {len} elements"
+            );
+
+            let other: Vec<i16> = (0..len + 1)
+                .map(|i| (i * 104_729 % 65536) as u16 as i16)
+                .collect();
+            let dot = values
+                .iter()
+                .zip(&other)
+                .fold(0i32, |acc, (&x, &y)| acc.wrapping_add(x as i32 * y as i32));
+            let values = SimdRef::new(&values);
+            assert_eq!(
+                values.dot_i32(&SimdRef::new(&other)),
+                dot,
+                "dot of {len} elements"
+            );
+
+            // Each product is 2^30, so the sum wraps around from the second one on.
+            let extremes = vec![i16::MIN; len];
+            let extremes = SimdRef::new(&extremes);
+            let dot = (0..len).fold(0i32, |acc, _| acc.wrapping_add(1 << 30));
+            assert_eq!(extremes.dot_i32(&extremes), dot, "dot of {len} elements");
+            assert_eq!(extremes.sum_i32(), -32768 * len as i32);
+        }
+    }
+}
@@ -1,10 +1,35 @@
+mod convert;
 mod floating;
 mod integer;
 mod num;
+mod ord;
+mod signed;
+mod widening;
 
 use crate::SimdElement;
 use core::arch::x86_64::*;
 
+/// Folds 8 lanes of `x` into one with `f`.
+#[inline(always)]
+unsafe fn reduce_ps(x: __m256, f: impl Fn(__m256, __m256) -> __m256) -> f32 {
+    unsafe {
+        let x = f(x, _mm256_permute2f128_ps(x, x, 1));
+        let x = f(x, _mm256_permute_ps(x, 0b01_00_11_10));
+        let x = f(x, _mm256_permute_ps(x, 0b10_11_00_01));
+        _mm256_cvtss_f32(x)
+    }
+}
+
+/// Folds 4 lanes of `x` into one with `f`.
+#[inline(always)]
+unsafe fn reduce_pd(x: __m256d, f: impl Fn(__m256d, __m256d) -> __m256d) -> f64 {
+    unsafe {
+        let x = f(x, _mm256_permute2f128_pd(x, x, 1));
+        let x = f(x, _mm256_permute_pd(x, 0b0101));
+        _mm256_cvtsd_f64(x)
+    }
+}
+
 unsafe impl SimdElement for i8 {
     type Vector = __m256i;
     const VECTOR_LEN: usize = 32;
@@ -48,6 +73,31 @@ unsafe impl SimdElement for i8 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi8(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_epi8(x) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let bytes = _mm256_shuffle_epi8(
+                _mm256_set1_epi32(bits as i32),
+                _mm256_setr_epi8(
+                    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3,
+                    3, 3, 3, 3, 3, 3,
+                ),
+            );
+            let select = _mm256_set1_epi64x(0x8040201008040201u64 as i64);
+            _mm256_cmpeq_epi8(_mm256_and_si256(bytes, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for u8 {
@@ -93,6 +143,31 @@ unsafe impl SimdElement for u8 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi8(value as i8) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_epi8(x) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let bytes = _mm256_shuffle_epi8(
+                _mm256_set1_epi32(bits as i32),
+                _mm256_setr_epi8(
+                    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 3, 3,
+                    3, 3, 3, 3, 3, 3,
+                ),
+            );
+            let select = _mm256_set1_epi64x(0x8040201008040201u64 as i64);
+            _mm256_cmpeq_epi8(_mm256_and_si256(bytes, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for i16 {
@@ -138,6 +213,46 @@ unsafe impl SimdElement for i16 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi16(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe {
+            let packed = _mm256_packs_epi16(x, _mm256_setzero_si256());
+            let packed = _mm256_permute4x64_epi64(packed, 0b11_01_10_00);
+            _mm256_movemask_epi8(packed) as u32
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi16(
+                1 << 0,
+                1 << 1,
+                1 << 2,
+                1 << 3,
+                1 << 4,
+                1 << 5,
+                1 << 6,
+                1 << 7,
+                1 << 8,
+                1 << 9,
+                1 << 10,
+                1 << 11,
+                1 << 12,
+                1 << 13,
+                1 << 14,
+                1 << 15,
+            );
+            let bits = _mm256_set1_epi16(bits as i16);
+            _mm256_cmpeq_epi16(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for u16 {
@@ -183,6 +298,46 @@ unsafe impl SimdElement for u16 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi16(value as i16) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe {
+            let packed = _mm256_packs_epi16(x, _mm256_setzero_si256());
+            let packed = _mm256_permute4x64_epi64(packed, 0b11_01_10_00);
+            _mm256_movemask_epi8(packed) as u32
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi16(
+                1 << 0,
+                1 << 1,
+                1 << 2,
+                1 << 3,
+                1 << 4,
+                1 << 5,
+                1 << 6,
+                1 << 7,
+                1 << 8,
+                1 << 9,
+                1 << 10,
+                1 << 11,
+                1 << 12,
+                1 << 13,
+                1 << 14,
+                1 << 15,
+            );
+            let bits = _mm256_set1_epi16(bits as i16);
+            _mm256_cmpeq_epi16(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for i32 {
@@ -228,6 +383,25 @@ unsafe impl SimdElement for i32 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi32(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_ps(_mm256_castsi256_ps(x)) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128);
+            let bits = _mm256_set1_epi32(bits as i32);
+            _mm256_cmpeq_epi32(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for u32 {
@@ -273,6 +447,25 @@ unsafe impl SimdElement for u32 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi32(value as i32) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_ps(_mm256_castsi256_ps(x)) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi32(1, 2, 4, 8, 16, 32, 64, 128);
+            let bits = _mm256_set1_epi32(bits as i32);
+            _mm256_cmpeq_epi32(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for i64 {
@@ -318,6 +511,25 @@ unsafe impl SimdElement for i64 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi64x(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_pd(_mm256_castsi256_pd(x)) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi64x(1, 2, 4, 8);
+            let bits = _mm256_set1_epi64x(bits as i64);
+            _mm256_cmpeq_epi64(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for u64 {
@@ -363,6 +575,25 @@ unsafe impl SimdElement for u64 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_epi64x(value as i64) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_pd(_mm256_castsi256_pd(x)) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe {
+            let select = _mm256_setr_epi64x(1, 2, 4, 8);
+            let bits = _mm256_set1_epi64x(bits as i64);
+            _mm256_cmpeq_epi64(_mm256_and_si256(bits, select), select)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_epi8(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for f32 {
@@ -408,6 +639,21 @@ unsafe impl SimdElement for f32 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_ps(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_ps(x) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe { _mm256_castsi256_ps(i32::from_bitmask(bits)) }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_ps(left, right, mask) }
+    }
 }
 
 unsafe impl SimdElement for f64 {
@@ -453,4 +699,19 @@ unsafe impl SimdElement for f64 {
     unsafe fn set(value: Self) -> Self::Vector {
         unsafe { _mm256_set1_pd(value) }
     }
+
+    #[inline(always)]
+    unsafe fn to_bitmask(x: Self::Vector) -> u32 {
+        unsafe { _mm256_movemask_pd(x) as u32 }
+    }
+
+    #[inline(always)]
+    unsafe fn from_bitmask(bits: u32) -> Self::Vector {
+        unsafe { _mm256_castsi256_pd(i64::from_bitmask(bits)) }
+    }
+
+    #[inline(always)]
+    unsafe fn blend(mask: Self::Vector, left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_blendv_pd(left, right, mask) }
+    }
 }
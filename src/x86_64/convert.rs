@@ -0,0 +1,573 @@
+use crate::*;
+use core::arch::x86_64::*;
+
+/// Converts `x` to `i32` towards zero, saturating at the bounds and giving zero for NaN.
+#[inline(always)]
+unsafe fn cvtt_ps_epi32(x: __m256) -> __m256i {
+    unsafe {
+        // Out of range lanes give `i32::MIN`, which flipping all bits turns into `i32::MAX`.
+        let over = _mm256_cmp_ps(x, _mm256_set1_ps(2_147_483_648.0), _CMP_GE_OQ);
+        let v = _mm256_xor_si256(_mm256_cvttps_epi32(x), _mm256_castps_si256(over));
+        _mm256_and_si256(v, _mm256_castps_si256(_mm256_cmp_ps(x, x, _CMP_ORD_Q)))
+    }
+}
+
+/// Clamps `x` to `[min, max]`, giving zero for NaN.
+#[inline(always)]
+unsafe fn clamp_ps(x: __m256, min: f32, max: f32) -> __m256 {
+    unsafe {
+        let x = _mm256_and_ps(x, _mm256_cmp_ps(x, x, _CMP_ORD_Q));
+        _mm256_min_ps(_mm256_max_ps(x, _mm256_set1_ps(min)), _mm256_set1_ps(max))
+    }
+}
+
+/// Clamps `x` to `[min, max]`, giving zero for NaN.
+#[inline(always)]
+unsafe fn clamp_pd(x: __m256d, min: f64, max: f64) -> __m256d {
+    unsafe {
+        let x = _mm256_and_pd(x, _mm256_cmp_pd(x, x, _CMP_ORD_Q));
+        _mm256_min_pd(_mm256_max_pd(x, _mm256_set1_pd(min)), _mm256_set1_pd(max))
+    }
+}
+
+/// Rounds `x` to the nearest integer, ties to even.
+#[inline(always)]
+unsafe fn round_ps(x: __m256) -> __m256 {
+    unsafe { _mm256_round_ps(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC) }
+}
+
+/// Packs the eight `i32` lanes of `x` into the low eight bytes, saturating to `[0, 255]`.
+#[inline(always)]
+unsafe fn pack_epi32_epu8(x: __m256i) -> __m128i {
+    unsafe {
+        let x = _mm_packs_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+        _mm_packus_epi16(x, x)
+    }
+}
+
+unsafe impl SimdConvert<f32> for i32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            _mm256_storeu_ps(dst, _mm256_cvtepi32_ps(Self::load(src)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f64> for i32 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f64 {
+        x as f64
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f64) {
+        unsafe {
+            _mm256_storeu_pd(dst, _mm256_cvtepi32_pd(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f32> for u8 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            let x = _mm256_cvtepu8_epi32(_mm_loadl_epi64(src as _));
+            _mm256_storeu_ps(dst, _mm256_cvtepi32_ps(x));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f32> for i16 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            let x = _mm256_cvtepi16_epi32(_mm_loadu_si128(src as _));
+            _mm256_storeu_ps(dst, _mm256_cvtepi32_ps(x));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f32> for u16 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            let x = _mm256_cvtepu16_epi32(_mm_loadu_si128(src as _));
+            _mm256_storeu_ps(dst, _mm256_cvtepi32_ps(x));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f32> for u32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            // Both 16-bit halves convert exactly, so only the final addition rounds.
+            let x = Self::load(src);
+            let high = _mm256_cvtepi32_ps(_mm256_srli_epi32(x, 16));
+            let low = _mm256_cvtepi32_ps(_mm256_and_si256(x, _mm256_set1_epi32(0xffff)));
+            _mm256_storeu_ps(dst, _mm256_fmadd_ps(high, _mm256_set1_ps(65_536.0), low));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f64> for f32 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f64 {
+        x as f64
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f64) {
+        unsafe {
+            _mm256_storeu_pd(dst, _mm256_cvtps_pd(_mm_loadu_ps(src)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<f32> for f64 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> f32 {
+        x as f32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut f32) {
+        unsafe {
+            _mm_storeu_ps(dst, _mm256_cvtpd_ps(Self::load(src)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i32> for f32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i32 {
+        x as i32
+    }
+
+    #[inline(always)]
+    fn convert_round(x: Self) -> i32 {
+        x.round_ties_even() as i32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i32) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, cvtt_ps_epi32(Self::load(src)));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut i32) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, cvtt_ps_epi32(round_ps(Self::load(src))));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i32> for f64 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i32 {
+        x as i32
+    }
+
+    #[inline(always)]
+    fn convert_round(x: Self) -> i32 {
+        x.round_ties_even() as i32
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i32) {
+        unsafe {
+            let x = clamp_pd(Self::load(src), i32::MIN as f64, i32::MAX as f64);
+            _mm_storeu_si128(dst as _, _mm256_cvttpd_epi32(x));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut i32) {
+        unsafe {
+            let x = clamp_pd(Self::load(src), i32::MIN as f64, i32::MAX as f64);
+            let x = _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC);
+            _mm_storeu_si128(dst as _, _mm256_cvttpd_epi32(x));
+        }
+    }
+}
+
+unsafe impl SimdConvert<u8> for f32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u8 {
+        x as u8
+    }
+
+    #[inline(always)]
+    fn convert_round(x: Self) -> u8 {
+        x.round_ties_even() as u8
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u8) {
+        unsafe {
+            let x = _mm256_cvttps_epi32(clamp_ps(Self::load(src), 0.0, 255.0));
+            _mm_storel_epi64(dst as _, pack_epi32_epu8(x));
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut u8) {
+        unsafe {
+            let x = _mm256_cvttps_epi32(round_ps(clamp_ps(Self::load(src), 0.0, 255.0)));
+            _mm_storel_epi64(dst as _, pack_epi32_epu8(x));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i16> for f32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i16 {
+        x as i16
+    }
+
+    #[inline(always)]
+    fn convert_round(x: Self) -> i16 {
+        x.round_ties_even() as i16
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i16) {
+        unsafe {
+            let x = _mm256_cvttps_epi32(clamp_ps(Self::load(src), -32_768.0, 32_767.0));
+            let x = _mm_packs_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut i16) {
+        unsafe {
+            let x = clamp_ps(round_ps(Self::load(src)), -32_768.0, 32_767.0);
+            let x = _mm256_cvttps_epi32(x);
+            let x = _mm_packs_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u16> for f32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u16 {
+        x as u16
+    }
+
+    #[inline(always)]
+    fn convert_round(x: Self) -> u16 {
+        x.round_ties_even() as u16
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u16) {
+        unsafe {
+            let x = _mm256_cvttps_epi32(clamp_ps(Self::load(src), 0.0, 65_535.0));
+            let x = _mm_packus_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn convert_round_block(src: *const Self, dst: *mut u16) {
+        unsafe {
+            let x = _mm256_cvttps_epi32(clamp_ps(round_ps(Self::load(src)), 0.0, 65_535.0));
+            let x = _mm_packus_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u16> for u8 {
+    const BLOCK_LEN: usize = 16;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u16 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u16) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepu8_epi16(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<u32> for u8 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u32 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u32) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepu8_epi32(_mm_loadl_epi64(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<u32> for u16 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u32 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u32) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepu16_epi32(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<u64> for u32 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u64 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u64) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepu32_epi64(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i16> for i8 {
+    const BLOCK_LEN: usize = 16;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i16 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i16) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepi8_epi16(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i32> for i16 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i32 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i32) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepi16_epi32(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i64> for i32 {
+    const BLOCK_LEN: usize = 4;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i64 {
+        x.into()
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i64) {
+        unsafe {
+            _mm256_storeu_si256(dst as _, _mm256_cvtepi32_epi64(_mm_loadu_si128(src as _)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<i8> for i16 {
+    const BLOCK_LEN: usize = 16;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i8 {
+        x.clamp(i8::MIN.into(), i8::MAX.into()) as i8
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i8) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm_packs_epi16(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u8> for i16 {
+    const BLOCK_LEN: usize = 16;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u8 {
+        x.clamp(0, u8::MAX.into()) as u8
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u8) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm_packus_epi16(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u8> for u16 {
+    const BLOCK_LEN: usize = 16;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u8 {
+        x.min(u8::MAX.into()) as u8
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u8) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm256_min_epu16(x, _mm256_set1_epi16(0xff));
+            let x = _mm_packus_epi16(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<i16> for i32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> i16 {
+        x.clamp(i16::MIN.into(), i16::MAX.into()) as i16
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut i16) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm_packs_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u16> for i32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u16 {
+        x.clamp(0, u16::MAX.into()) as u16
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u16) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm_packus_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
+
+unsafe impl SimdConvert<u8> for i32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u8 {
+        x.clamp(0, u8::MAX.into()) as u8
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u8) {
+        unsafe {
+            _mm_storel_epi64(dst as _, pack_epi32_epu8(Self::load(src)));
+        }
+    }
+}
+
+unsafe impl SimdConvert<u16> for u32 {
+    const BLOCK_LEN: usize = 8;
+
+    #[inline(always)]
+    fn convert(x: Self) -> u16 {
+        x.min(u16::MAX.into()) as u16
+    }
+
+    #[inline(always)]
+    unsafe fn convert_block(src: *const Self, dst: *mut u16) {
+        unsafe {
+            let x = Self::load(src);
+            let x = _mm256_min_epu32(x, _mm256_set1_epi32(0xffff));
+            let x = _mm_packus_epi32(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            _mm_storeu_si128(dst as _, x);
+        }
+    }
+}
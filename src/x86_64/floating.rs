@@ -1,6 +1,88 @@
+mod erf;
+mod exp;
+mod gamma;
+mod inverse_trig;
+mod log;
+mod pow;
+mod root;
+mod trig;
+
 use crate::*;
 use core::arch::x86_64::*;
 
+/// Applies `f` to `x` in `f64`, so the result is only rounded once when narrowed back to `f32`.
+#[inline(always)]
+unsafe fn map_pd(x: __m256, f: impl Fn(__m256d) -> __m256d) -> __m256 {
+    unsafe {
+        let lo = f(_mm256_cvtps_pd(_mm256_castps256_ps128(x)));
+        let hi = f(_mm256_cvtps_pd(_mm256_extractf128_ps(x, 1)));
+        _mm256_set_m128(_mm256_cvtpd_ps(hi), _mm256_cvtpd_ps(lo))
+    }
+}
+
+/// Applies `f` to `x` and `y` in `f64`, so the result is only rounded once when narrowed back to
+/// `f32`.
+#[inline(always)]
+unsafe fn zip_pd(x: __m256, y: __m256, f: impl Fn(__m256d, __m256d) -> __m256d) -> __m256 {
+    unsafe {
+        let lo = f(
+            _mm256_cvtps_pd(_mm256_castps256_ps128(x)),
+            _mm256_cvtps_pd(_mm256_castps256_ps128(y)),
+        );
+        let hi = f(
+            _mm256_cvtps_pd(_mm256_extractf128_ps(x, 1)),
+            _mm256_cvtps_pd(_mm256_extractf128_ps(y, 1)),
+        );
+        _mm256_set_m128(_mm256_cvtpd_ps(hi), _mm256_cvtpd_ps(lo))
+    }
+}
+
+/// Returns `(a + b, err)` where `err` is the rounding error of the sum.
+#[inline(always)]
+unsafe fn two_sum_pd(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let s = _mm256_add_pd(a, b);
+        let bb = _mm256_sub_pd(s, a);
+        let err = _mm256_add_pd(_mm256_sub_pd(a, _mm256_sub_pd(s, bb)), _mm256_sub_pd(b, bb));
+        (s, err)
+    }
+}
+
+/// Returns `(a + a_lo) * (b + b_lo)` as a double-double.
+#[inline(always)]
+unsafe fn mul_dd_pd(a: __m256d, a_lo: __m256d, b: __m256d, b_lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let hi = _mm256_mul_pd(a, b);
+        let lo = _mm256_fmadd_pd(a, b_lo, _mm256_fmsub_pd(a, b, hi));
+        (hi, _mm256_fmadd_pd(a_lo, b, lo))
+    }
+}
+
+/// Returns a mask of the lanes of `x` that are zero or infinite, where Newton-Raphson steps on a
+/// reciprocal estimate would give NaN instead of keeping the estimate.
+#[inline(always)]
+unsafe fn zero_or_infinite_ps(x: __m256) -> __m256 {
+    unsafe {
+        let a = _mm256_andnot_ps(_mm256_set1_ps(-0.0), x);
+        _mm256_or_ps(
+            _mm256_cmp_ps(a, _mm256_setzero_ps(), _CMP_EQ_OQ),
+            _mm256_cmp_ps(a, _mm256_set1_ps(f32::INFINITY), _CMP_EQ_OQ),
+        )
+    }
+}
+
+/// Picks `values[i]` in lanes where exactly the first `i` masks are set.
+#[inline(always)]
+unsafe fn pick_pd(masks: &[__m256d; 4], values: [f64; 5]) -> __m256d {
+    unsafe {
+        let mut result = _mm256_set1_pd(values[0]);
+        for i in 0..4 {
+            result = _mm256_blendv_pd(result, _mm256_set1_pd(values[i + 1]), masks[i]);
+        }
+        result
+    }
+}
+
 unsafe impl SimdFloatingElement for f32 {
     #[inline(always)]
     unsafe fn sqrt(x: Self::Vector) -> Self::Vector {
@@ -8,57 +90,221 @@ unsafe impl SimdFloatingElement for f32 {
     }
 
     #[inline(always)]
-    unsafe fn exp(x: Self::Vector) -> Self::Vector {
+    unsafe fn recip(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_div_ps(Self::set(1.0), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn rsqrt(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| _mm256_div_pd(_mm256_set1_pd(1.0), _mm256_sqrt_pd(x))) }
+    }
+
+    #[inline(always)]
+    unsafe fn recip_approx(x: Self::Vector, steps: u32) -> Self::Vector {
+        unsafe {
+            let estimate = _mm256_rcp_ps(x);
+            let mut r = estimate;
+            for _ in 0..steps {
+                // r + r * (1 - x * r)
+                r = _mm256_fmadd_ps(r, _mm256_fnmadd_ps(x, r, Self::set(1.0)), r);
+            }
+            _mm256_blendv_ps(r, estimate, zero_or_infinite_ps(estimate))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn rsqrt_approx(x: Self::Vector, steps: u32) -> Self::Vector {
         unsafe {
-            const LN_2: f32 = core::f32::consts::LN_2;
-            const C0: f32 = 1.0 / 1.0;
-            const C1: f32 = 1.0 / 1.0;
-            const C2: f32 = 1.0 / 2.0;
-            const C3: f32 = 1.0 / 6.0;
-            const C4: f32 = 1.0 / 24.0;
-            const C5: f32 = 1.0 / 120.0;
-
-            let fx = _mm256_mul_ps(x, Self::set(1.0 / LN_2));
-            let n = _mm256_floor_ps(fx);
-            let f = _mm256_sub_ps(x, _mm256_mul_ps(n, _mm256_set1_ps(LN_2)));
-
-            let poly = _mm256_set1_ps(C5);
-            let poly = _mm256_fmadd_ps(poly, f, _mm256_set1_ps(C4));
-            let poly = _mm256_fmadd_ps(poly, f, _mm256_set1_ps(C3));
-            let poly = _mm256_fmadd_ps(poly, f, _mm256_set1_ps(C2));
-            let poly = _mm256_fmadd_ps(poly, f, _mm256_set1_ps(C1));
-            let poly = _mm256_fmadd_ps(poly, f, _mm256_set1_ps(C0));
-
-            const EXP_BIAS: i32 = 0x7f;
-            const EXP_OFFSET: i32 = 23;
-
-            let exp_bias = _mm256_set1_epi32(EXP_BIAS);
-            let n_i32 = _mm256_cvtps_epi32(n);
-            let pow_2_n = _mm256_castsi256_ps(_mm256_slli_epi32(
-                _mm256_add_epi32(n_i32, exp_bias),
-                EXP_OFFSET,
-            ));
-
-            _mm256_mul_ps(pow_2_n, poly)
+            let estimate = _mm256_rsqrt_ps(x);
+            let mut r = estimate;
+            for _ in 0..steps {
+                // r * (3 - x * r^2) / 2
+                let e = _mm256_fnmadd_ps(_mm256_mul_ps(x, r), r, Self::set(3.0));
+                r = _mm256_mul_ps(_mm256_mul_ps(Self::set(0.5), r), e);
+            }
+            _mm256_blendv_ps(r, estimate, zero_or_infinite_ps(estimate))
         }
     }
 
+    #[inline(always)]
+    unsafe fn exp(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| exp::exp_pd(x)) }
+    }
+
     #[inline(always)]
     unsafe fn tanh(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| exp::tanh_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn log2(x: Self::Vector) -> Self::Vector {
+        unsafe { log::log2_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn log10(x: Self::Vector) -> Self::Vector {
+        unsafe { log::log10_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln_1p(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_1p_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sin(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_ps(x).0 }
+    }
+
+    #[inline(always)]
+    unsafe fn cos(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_ps(x).1 }
+    }
+
+    #[inline(always)]
+    unsafe fn tan(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::tan_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sincos(x: Self::Vector) -> (Self::Vector, Self::Vector) {
+        unsafe { trig::sincos_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn asin(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| inverse_trig::asin_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn acos(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| inverse_trig::acos_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn atan(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| inverse_trig::atan_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn atan2(y: Self::Vector, x: Self::Vector) -> Self::Vector {
+        unsafe { zip_pd(y, x, |y, x| inverse_trig::atan2_pd(y, x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn exp2(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| exp::exp2_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn exp10(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| exp::exp10_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn expm1(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| exp::expm1_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn cbrt(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| root::cbrt_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn hypot(x: Self::Vector, y: Self::Vector) -> Self::Vector {
+        unsafe { zip_pd(x, y, |x, y| root::hypot_pd(x, y)) }
+    }
+
+    #[inline(always)]
+    unsafe fn powf(x: Self::Vector, y: Self::Vector) -> Self::Vector {
+        unsafe { zip_pd(x, y, |x, y| pow::powf_pd(x, y)) }
+    }
+
+    #[inline(always)]
+    unsafe fn erf(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| erf::erf_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn erfc(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| erf::erfc_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn lgamma(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| gamma::lgamma_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn tgamma(x: Self::Vector) -> Self::Vector {
+        unsafe { map_pd(x, |x| gamma::tgamma_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn copysign(magnitude: Self::Vector, sign: Self::Vector) -> Self::Vector {
         unsafe {
-            let one = Self::set(1.0);
-            let two = Self::set(2.0);
+            let sign_mask = Self::set(-0.0);
+            _mm256_or_ps(
+                _mm256_andnot_ps(sign_mask, magnitude),
+                _mm256_and_ps(sign_mask, sign),
+            )
+        }
+    }
 
-            let mul_2x = <Self as SimdNumElement>::mul(x, two);
-            let exp_2x = <Self as SimdFloatingElement>::exp(mul_2x);
-            let exp_2x_plus_1 = <Self as SimdNumElement>::add(exp_2x, one);
+    #[inline(always)]
+    unsafe fn signum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let one = <Self as SimdFloatingElement>::copysign(Self::set(1.0), x);
+            let nan = _mm256_cmp_ps(x, x, _CMP_UNORD_Q);
+            _mm256_blendv_ps(one, x, nan)
+        }
+    }
 
-            let first_term = one;
-            let second_term = <Self as SimdNumElement>::div(two, exp_2x_plus_1);
+    #[inline(always)]
+    unsafe fn floor(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_ps(x, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC) }
+    }
 
-            <Self as SimdNumElement>::sub(first_term, second_term)
+    #[inline(always)]
+    unsafe fn ceil(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_ps(x, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC) }
+    }
+
+    #[inline(always)]
+    unsafe fn round(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            // x - trunc(x) is exact, so it tells the half-way cases apart without rounding.
+            let t = <Self as SimdFloatingElement>::trunc(x);
+            let away = _mm256_cmp_ps(
+                _mm256_andnot_ps(Self::set(-0.0), _mm256_sub_ps(x, t)),
+                Self::set(0.5),
+                _CMP_GE_OQ,
+            );
+            let step = <Self as SimdFloatingElement>::copysign(Self::set(1.0), x);
+            _mm256_blendv_ps(t, _mm256_add_ps(t, step), away)
         }
     }
+
+    #[inline(always)]
+    unsafe fn round_ties_even(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_ps(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC) }
+    }
+
+    #[inline(always)]
+    unsafe fn trunc(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_ps(x, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC) }
+    }
+
+    #[inline(always)]
+    unsafe fn fract(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_ps(x, <Self as SimdFloatingElement>::trunc(x)) }
+    }
 }
 
 unsafe impl SimdFloatingElement for f64 {
@@ -67,32 +313,257 @@ unsafe impl SimdFloatingElement for f64 {
         unsafe { _mm256_sqrt_pd(x) }
     }
 
+    #[inline(always)]
+    unsafe fn recip(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_div_pd(Self::set(1.0), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn rsqrt(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_div_pd(Self::set(1.0), _mm256_sqrt_pd(x)) }
+    }
+
+    #[inline(always)]
+    unsafe fn recip_approx(x: Self::Vector, _steps: u32) -> Self::Vector {
+        unsafe { <Self as SimdFloatingElement>::recip(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn rsqrt_approx(x: Self::Vector, _steps: u32) -> Self::Vector {
+        unsafe { <Self as SimdFloatingElement>::rsqrt(x) }
+    }
+
     #[inline(always)]
     unsafe fn exp(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::exp_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn tanh(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::tanh_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn log2(x: Self::Vector) -> Self::Vector {
+        unsafe { log::log2_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn log10(x: Self::Vector) -> Self::Vector {
+        unsafe { log::log10_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln_1p(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_1p_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sin(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_pd(x).0 }
+    }
+
+    #[inline(always)]
+    unsafe fn cos(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_pd(x).1 }
+    }
+
+    #[inline(always)]
+    unsafe fn tan(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::tan_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sincos(x: Self::Vector) -> (Self::Vector, Self::Vector) {
+        unsafe { trig::sincos_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn asin(x: Self::Vector) -> Self::Vector {
+        unsafe { inverse_trig::asin_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn acos(x: Self::Vector) -> Self::Vector {
+        unsafe { inverse_trig::acos_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn atan(x: Self::Vector) -> Self::Vector {
+        unsafe { inverse_trig::atan_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn atan2(y: Self::Vector, x: Self::Vector) -> Self::Vector {
+        unsafe { inverse_trig::atan2_pd(y, x) }
+    }
+
+    #[inline(always)]
+    unsafe fn exp2(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::exp2_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn exp10(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::exp10_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn expm1(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::expm1_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn cbrt(x: Self::Vector) -> Self::Vector {
+        unsafe { root::cbrt_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn hypot(x: Self::Vector, y: Self::Vector) -> Self::Vector {
+        unsafe { root::hypot_pd(x, y) }
+    }
+
+    #[inline(always)]
+    unsafe fn powf(x: Self::Vector, y: Self::Vector) -> Self::Vector {
+        unsafe { pow::powf_pd(x, y) }
+    }
+
+    #[inline(always)]
+    unsafe fn erf(x: Self::Vector) -> Self::Vector {
+        unsafe { erf::erf_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn erfc(x: Self::Vector) -> Self::Vector {
+        unsafe { erf::erfc_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn lgamma(x: Self::Vector) -> Self::Vector {
+        unsafe { gamma::lgamma_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn tgamma(x: Self::Vector) -> Self::Vector {
+        unsafe { gamma::tgamma_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn copysign(magnitude: Self::Vector, sign: Self::Vector) -> Self::Vector {
         unsafe {
-            let mut buff = [0.0f64; Self::VECTOR_LEN];
-            Self::store(buff.as_mut_ptr(), x);
-            for i in &mut buff {
-                *i = i.exp();
-            }
-            Self::load(buff.as_ptr())
+            let sign_mask = Self::set(-0.0);
+            _mm256_or_pd(
+                _mm256_andnot_pd(sign_mask, magnitude),
+                _mm256_and_pd(sign_mask, sign),
+            )
         }
     }
 
     #[inline(always)]
-    unsafe fn tanh(x: Self::Vector) -> Self::Vector {
+    unsafe fn signum(x: Self::Vector) -> Self::Vector {
         unsafe {
-            let one = Self::set(1.0);
-            let two = Self::set(2.0);
+            let one = <Self as SimdFloatingElement>::copysign(Self::set(1.0), x);
+            let nan = _mm256_cmp_pd(x, x, _CMP_UNORD_Q);
+            _mm256_blendv_pd(one, x, nan)
+        }
+    }
 
-            let mul_2x = <Self as SimdNumElement>::mul(x, two);
-            let exp_2x = <Self as SimdFloatingElement>::exp(mul_2x);
-            let exp_2x_plus_1 = <Self as SimdNumElement>::add(exp_2x, one);
+    #[inline(always)]
+    unsafe fn floor(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_pd(x, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC) }
+    }
 
-            let first_term = one;
-            let second_term = <Self as SimdNumElement>::div(two, exp_2x_plus_1);
+    #[inline(always)]
+    unsafe fn ceil(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_pd(x, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC) }
+    }
 
-            <Self as SimdNumElement>::sub(first_term, second_term)
+    #[inline(always)]
+    unsafe fn round(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            // x - trunc(x) is exact, so it tells the half-way cases apart without rounding.
+            let t = <Self as SimdFloatingElement>::trunc(x);
+            let away = _mm256_cmp_pd(
+                _mm256_andnot_pd(Self::set(-0.0), _mm256_sub_pd(x, t)),
+                Self::set(0.5),
+                _CMP_GE_OQ,
+            );
+            let step = <Self as SimdFloatingElement>::copysign(Self::set(1.0), x);
+            _mm256_blendv_pd(t, _mm256_add_pd(t, step), away)
         }
     }
+
+    #[inline(always)]
+    unsafe fn round_ties_even(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC) }
+    }
+
+    #[inline(always)]
+    unsafe fn trunc(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_round_pd(x, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC) }
+    }
+
+    #[inline(always)]
+    unsafe fn fract(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_pd(x, <Self as SimdFloatingElement>::trunc(x)) }
+    }
+}
+
+unsafe impl SimdFastElement for f32 {
+    #[inline(always)]
+    unsafe fn exp_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::exp_fast_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn tanh_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::tanh_fast_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_fast_ps(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sin_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_fast_ps(x).0 }
+    }
+
+    #[inline(always)]
+    unsafe fn cos_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_fast_ps(x).1 }
+    }
+}
+
+unsafe impl SimdFastElement for f64 {
+    #[inline(always)]
+    unsafe fn exp_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::exp_fast_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn tanh_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { exp::tanh_fast_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn ln_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { log::ln_fast_pd(x) }
+    }
+
+    #[inline(always)]
+    unsafe fn sin_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_fast_pd(x).0 }
+    }
+
+    #[inline(always)]
+    unsafe fn cos_fast(x: Self::Vector) -> Self::Vector {
+        unsafe { trig::sincos_fast_pd(x).1 }
+    }
 }
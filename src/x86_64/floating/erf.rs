@@ -0,0 +1,269 @@
+use super::exp::exp_dd_pd;
+use super::log::ln_of_dd_pd;
+use super::{mul_dd_pd, pick_pd, two_sum_pd};
+use core::arch::x86_64::*;
+
+/// Returns `(g, g_lo, sq, sq_lo)` with `erfc(a) = e^-(sq + sq_lo) * (g + g_lo)`, for `a >= 1` or
+/// NaN.
+///
+/// `g` is fitted on five intervals: as a polynomial in `a` on `[1, 2.25)`, and as
+/// `h(1 / a^2) / (a * sqrt(pi))` beyond. The leading coefficients are split in two since they
+/// dominate the result.
+#[inline(always)]
+unsafe fn erfc_tail_pd(a: __m256d) -> (__m256d, __m256d, __m256d, __m256d) {
+    unsafe {
+        const CENTER: [f64; 5] = [
+            1.25,
+            1.875,
+            0.154_320_987_654_320_98,
+            0.086_805_555_555_555_55,
+            0.031_25,
+        ];
+        const LEAD: [f64; 5] = [
+            3.678_229_164_523_611e-1,
+            2.694_299_851_646_704e-1,
+            9.360_828_857_993_989e-1,
+            9.612_945_253_499_471e-1,
+            9.850_557_060_634_584e-1,
+        ];
+        const LEAD_LO: [f64; 5] = [
+            1.387_401_093_925_035e-19,
+            2.483_457_972_413_471_8e-17,
+            2.784_736_818_953_842e-17,
+            4.187_071_018_124_1e-17,
+            -1.032_615_136_989_889e-17,
+        ];
+        const TAIL: [[f64; 14]; 5] = [
+            [
+                -2.088_218_759_646_098_5e-1,
+                1.067_955_714_965_988e-1,
+                -5.021_827_439_590_747e-2,
+                2.201_136_425_085_713_6e-2,
+                -9.081_627_632_959_959e-3,
+                3.553_109_903_236_836_5e-3,
+                -1.325_782_927_236_854_3e-3,
+                4.739_703_095_963_998_3e-4,
+                -1.629_601_211_113_122_2e-4,
+                5.405_409_099_640_076_4e-5,
+                -1.734_145_925_497_756_2e-5,
+                5.394_927_599_749_996_5e-6,
+                -1.661_076_703_062_511_2e-6,
+                4.881_108_117_869_3e-7,
+            ],
+            [
+                -1.180_167_227_279_983_7e-1,
+                4.814_863_004_967_349e-2,
+                -1.849_202_758_990_613_6e-2,
+                6.738_039_159_298_646e-3,
+                -2.343_281_666_593_558_6e-3,
+                7.814_620_115_705_871e-4,
+                -2.508_686_797_695_211e-4,
+                7.777_080_602_369_666e-5,
+                -2.334_418_422_231_074e-5,
+                6.800_149_170_017_310_6e-6,
+                -1.925_212_480_834_786_3e-6,
+                5.311_934_812_980_437e-7,
+                -1.478_832_173_901_385_3e-7,
+                3.882_574_445_894_281e-8,
+            ],
+            [
+                -3.490_033_578_611_338e-1,
+                3.276_987_956_734_896e-1,
+                -4.445_714_982_629_197e-1,
+                7.481_444_432_186_923e-1,
+                -1.457_121_543_927_928_4,
+                3.160_114_396_988_389_4,
+                -7.449_369_843_428_898,
+                1.877_885_447_440_397e1,
+                -5.003_998_391_152_113e1,
+                1.397_356_455_907_395e2,
+                -4.055_181_661_534_568_3e2,
+                1.220_730_780_878_072_4e3,
+                -4.069_578_029_492_264_2e3,
+                1.303_300_285_937_862e4,
+            ],
+            [
+                -4.004_374_430_173_182_6e-1,
+                4.439_605_541_017_321_3e-1,
+                -7.399_259_956_626_786e-1,
+                1.576_519_153_089_435_6,
+                -3.981_536_178_624_601,
+                1.141_761_361_211_554_3e1,
+                -3.617_435_752_882_684_6e1,
+                1.242_804_752_900_390_9e2,
+                -4.568_014_220_954_643e2,
+                1.778_203_593_920_706e3,
+                -7.268_715_437_729_975e3,
+                3.104_975_753_386_651e4,
+                -1.443_622_496_084_84e5,
+                6.650_863_527_622_21e5,
+            ],
+            [
+                -4.579_343_059_967_051_5e-1,
+                6.054_761_579_358_555e-1,
+                -1.269_686_152_702_744,
+                3.558_593_956_590_399,
+                -1.227_651_947_455_529_3e1,
+                4.967_755_206_914_217e1,
+                -2.284_954_570_947_644_5e2,
+                1.168_615_318_379_452_2e3,
+                -6.542_012_330_516_987e3,
+                3.956_446_630_691_172e4,
+                -2.515_129_630_216_245_4e5,
+                1.721_594_730_960_720_5e6,
+                -1.583_086_711_210_155_3e7,
+                1.228_225_500_969_789_8e8,
+            ],
+        ];
+        const FRAC_1_SQRT_PI_LO: f64 = 7.667_729_806_582_94e-18;
+
+        let one = _mm256_set1_pd(1.0);
+        let sq = _mm256_mul_pd(a, a);
+        let sq_lo = _mm256_fmsub_pd(a, a, sq);
+        let masks = [
+            _mm256_cmp_pd(a, _mm256_set1_pd(1.5), _CMP_GE_OQ),
+            _mm256_cmp_pd(a, _mm256_set1_pd(2.25), _CMP_GE_OQ),
+            _mm256_cmp_pd(a, _mm256_set1_pd(3.0), _CMP_GE_OQ),
+            _mm256_cmp_pd(a, _mm256_set1_pd(4.0), _CMP_GE_OQ),
+        ];
+        let inverse = masks[1];
+
+        // u = a - center, or 1 / a^2 - center with 1 / a^2 in double-double.
+        let s = _mm256_div_pd(one, sq);
+        let s_lo = _mm256_div_pd(_mm256_fnmadd_pd(s, sq_lo, _mm256_fnmadd_pd(s, sq, one)), sq);
+        let u = _mm256_sub_pd(_mm256_blendv_pd(a, s, inverse), pick_pd(&masks, CENTER));
+        let u = _mm256_add_pd(u, _mm256_and_pd(s_lo, inverse));
+
+        let mut q = pick_pd(&masks, TAIL.map(|c| c[13]));
+        for i in (0..13).rev() {
+            q = _mm256_fmadd_pd(q, u, pick_pd(&masks, TAIL.map(|c| c[i])));
+        }
+        let uq = _mm256_mul_pd(u, q);
+        let (g, g_lo) = two_sum_pd(pick_pd(&masks, LEAD), uq);
+        let g_lo = _mm256_add_pd(
+            g_lo,
+            _mm256_add_pd(pick_pd(&masks, LEAD_LO), _mm256_fmsub_pd(u, q, uq)),
+        );
+
+        // g = h / (a * sqrt(pi)) on the inverse intervals.
+        let d = _mm256_div_pd(g, a);
+        let d_lo = _mm256_div_pd(_mm256_add_pd(_mm256_fnmadd_pd(d, a, g), g_lo), a);
+        let (k, k_lo) = mul_dd_pd(
+            d,
+            d_lo,
+            _mm256_set1_pd(core::f64::consts::FRAC_2_SQRT_PI * 0.5),
+            _mm256_set1_pd(FRAC_1_SQRT_PI_LO),
+        );
+        (
+            _mm256_blendv_pd(g, k, inverse),
+            _mm256_blendv_pd(g_lo, k_lo, inverse),
+            sq,
+            sq_lo,
+        )
+    }
+}
+
+/// Returns a mask of the lanes where `erfc(a)` rounds to zero, before `a * a` or `1 / a` lose
+/// their low parts.
+#[inline(always)]
+unsafe fn underflows_pd(a: __m256d) -> __m256d {
+    unsafe { _mm256_cmp_pd(a, _mm256_set1_pd(32.0), _CMP_GE_OQ) }
+}
+
+/// Returns `(f, f_lo)` with `erf(x) = x * (f + f_lo)`, for `|x| < 1`.
+#[inline(always)]
+unsafe fn erf_small_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        // erf(x) = x * (2/sqrt(pi) + x^2 * p(x^2)), with 2/sqrt(pi) split in two.
+        const TWO_SQRT_PI_LO: f64 = 1.533_545_961_316_588e-17;
+        const ERF: [f64; 12] = [
+            -3.761_263_890_318_375e-1,
+            1.128_379_167_095_487_9e-1,
+            -2.686_617_064_507_679_2e-2,
+            5.223_977_624_818_014_5e-3,
+            -8.548_326_980_833_79e-4,
+            1.205_533_111_164_271e-4,
+            -1.492_559_526_683_118_2e-5,
+            1.646_100_048_412_136_8e-6,
+            -1.635_031_270_105_469_5e-7,
+            1.465_977_527_404_743_6e-8,
+            -1.137_284_885_679_167_4e-9,
+            5.957_176_147_748_911e-11,
+        ];
+
+        // The last two Horner steps are carried in double-double, since they are of the same
+        // magnitude as the result.
+        let s = _mm256_mul_pd(x, x);
+        let s_lo = _mm256_fmsub_pd(x, x, s);
+        let mut p = _mm256_set1_pd(ERF[11]);
+        for c in ERF[1..11].iter().rev() {
+            p = _mm256_fmadd_pd(p, s, _mm256_set1_pd(*c));
+        }
+        let (u, u_lo) = two_sum_pd(_mm256_set1_pd(ERF[0]), _mm256_mul_pd(s, p));
+        let t = _mm256_mul_pd(s, u);
+        let t_lo = _mm256_fmadd_pd(s_lo, u, _mm256_fmadd_pd(s, u_lo, _mm256_fmsub_pd(s, u, t)));
+        let (f, f_lo) = two_sum_pd(_mm256_set1_pd(core::f64::consts::FRAC_2_SQRT_PI), t);
+        (
+            f,
+            _mm256_add_pd(f_lo, _mm256_add_pd(t_lo, _mm256_set1_pd(TWO_SQRT_PI_LO))),
+        )
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn erf_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let sign = _mm256_set1_pd(-0.0);
+        let a = _mm256_andnot_pd(sign, x);
+
+        let (f, f_lo) = erf_small_pd(x);
+        let small = _mm256_fmadd_pd(x, f, _mm256_mul_pd(x, f_lo));
+
+        // erf(x) = sign(x) * (1 - erfc(|x|)) from 1 on.
+        let (g, _, sq, sq_lo) = erfc_tail_pd(a);
+        let erfc = _mm256_mul_pd(
+            exp_dd_pd(
+                _mm256_sub_pd(_mm256_setzero_pd(), sq),
+                _mm256_sub_pd(_mm256_setzero_pd(), sq_lo),
+            ),
+            g,
+        );
+        let erfc = _mm256_andnot_pd(underflows_pd(a), erfc);
+        let large = _mm256_sub_pd(_mm256_set1_pd(1.0), erfc);
+        let large = _mm256_or_pd(_mm256_andnot_pd(sign, large), _mm256_and_pd(x, sign));
+        _mm256_blendv_pd(
+            small,
+            large,
+            _mm256_cmp_pd(a, _mm256_set1_pd(1.0), _CMP_GE_OQ),
+        )
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn erfc_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let one = _mm256_set1_pd(1.0);
+        let a = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+
+        // erfc(x) = 1 - erf(x) below 1, with erf(x) in double-double since erfc(x) may be as
+        // small as 0.16.
+        let (f, f_lo) = erf_small_pd(x);
+        let e = _mm256_mul_pd(x, f);
+        let e_lo = _mm256_fmadd_pd(x, f_lo, _mm256_fmsub_pd(x, f, e));
+        let (s, err) = two_sum_pd(one, _mm256_sub_pd(_mm256_setzero_pd(), e));
+        let small = _mm256_add_pd(s, _mm256_sub_pd(err, e_lo));
+
+        // erfc(|x|) = e^(ln(g) - a^2) in one rounding, and erfc(x) = 2 - erfc(-x) for negative x.
+        let (g, g_lo, sq, sq_lo) = erfc_tail_pd(a);
+        let (l, l_lo) = ln_of_dd_pd(g, g_lo);
+        let (hi, lo) = two_sum_pd(_mm256_sub_pd(_mm256_setzero_pd(), sq), l);
+        let lo = _mm256_add_pd(lo, _mm256_sub_pd(l_lo, sq_lo));
+        let tail = _mm256_andnot_pd(underflows_pd(a), exp_dd_pd(hi, lo));
+        let large = _mm256_blendv_pd(
+            tail,
+            _mm256_sub_pd(_mm256_set1_pd(2.0), tail),
+            _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_LT_OQ),
+        );
+        _mm256_blendv_pd(small, large, _mm256_cmp_pd(a, one, _CMP_GE_OQ))
+    }
+}
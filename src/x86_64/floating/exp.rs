@@ -0,0 +1,348 @@
+use super::two_sum_pd;
+use core::arch::x86_64::*;
+
+/// `ln(2)` split so that `n * LN2_HI` is exact for any exponent `n`.
+const LN2_HI: f64 = 6.931_471_803_691_238e-1;
+const LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+
+/// Returns `2^n` for integral `n` in `[-1022, 1023]`.
+#[inline(always)]
+unsafe fn pow2i_pd(n: __m256d) -> __m256d {
+    unsafe {
+        // Adding 2^52 + 1023 leaves the biased exponent in the low mantissa bits.
+        const MAGIC: f64 = 4_503_599_627_370_496.0 + 1023.0;
+
+        let biased = _mm256_castpd_si256(_mm256_add_pd(n, _mm256_set1_pd(MAGIC)));
+        _mm256_castsi256_pd(_mm256_slli_epi64(biased, 52))
+    }
+}
+
+/// Returns `2^n * (1 + hi + lo)` for integral `n` in `[-2044, 2046]`, overflowing to infinity and
+/// underflowing to zero like a single multiplication would.
+#[inline(always)]
+unsafe fn scale_pd(n: __m256d, hi: __m256d, lo: __m256d) -> __m256d {
+    unsafe {
+        let (m, err) = two_sum_pd(_mm256_set1_pd(1.0), hi);
+        let m = _mm256_add_pd(m, _mm256_add_pd(err, lo));
+
+        // 2^n is applied in two steps since it may not be representable on its own.
+        let n1 = _mm256_floor_pd(_mm256_mul_pd(n, _mm256_set1_pd(0.5)));
+        let s1 = pow2i_pd(n1);
+        let s2 = pow2i_pd(_mm256_sub_pd(n, n1));
+        _mm256_mul_pd(_mm256_mul_pd(s1, m), s2)
+    }
+}
+
+/// Returns `e^(r + lo) - 1` as a double-double, for `|r| <= ln(2) / 2` and `lo` much smaller than
+/// `r`.
+#[inline(always)]
+unsafe fn expm1_poly_pd(r: __m256d, lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const C: [f64; 12] = [
+            1.0 / 2.0,
+            1.0 / 6.0,
+            1.0 / 24.0,
+            1.0 / 120.0,
+            1.0 / 720.0,
+            1.0 / 5_040.0,
+            1.0 / 40_320.0,
+            1.0 / 362_880.0,
+            1.0 / 3_628_800.0,
+            1.0 / 39_916_800.0,
+            1.0 / 479_001_600.0,
+            1.0 / 6_227_020_800.0,
+        ];
+
+        let mut poly = _mm256_set1_pd(C[11]);
+        for c in C[..11].iter().rev() {
+            poly = _mm256_fmadd_pd(poly, r, _mm256_set1_pd(*c));
+        }
+        let (hi, err) = two_sum_pd(r, _mm256_mul_pd(_mm256_mul_pd(r, r), poly));
+
+        // e^(r + lo) - 1 = (e^r - 1) + lo * e^r to first order.
+        (hi, _mm256_add_pd(err, _mm256_fmadd_pd(lo, hi, lo)))
+    }
+}
+
+/// Returns `(n, r, lo)` with `x = n * ln(2) + r + lo`, `n` integral and `|r| <= ln(2) / 2`.
+#[inline(always)]
+unsafe fn reduce_pd(x: __m256d) -> (__m256d, __m256d, __m256d) {
+    unsafe {
+        let n = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::LOG2_E)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+        let t = _mm256_fnmadd_pd(n, _mm256_set1_pd(LN2_HI), x);
+        let r = _mm256_fnmadd_pd(n, _mm256_set1_pd(LN2_LO), t);
+        let lo = _mm256_fnmadd_pd(n, _mm256_set1_pd(LN2_LO), _mm256_sub_pd(t, r));
+        (n, r, lo)
+    }
+}
+
+/// Clamps `x` to `[min, max]`, keeping NaN.
+#[inline(always)]
+unsafe fn clamp_pd(x: __m256d, min: f64, max: f64) -> __m256d {
+    unsafe {
+        // `min_pd` and `max_pd` return their second operand when either is NaN.
+        let x = _mm256_max_pd(_mm256_set1_pd(min), x);
+        _mm256_min_pd(_mm256_set1_pd(max), x)
+    }
+}
+
+/// Returns `e^(x + lo)` for a double-double `x + lo`. Infinite and NaN `x` are handled, and `lo`
+/// is ignored where `x` is out of range.
+#[inline(always)]
+pub(super) unsafe fn exp_dd_pd(x: __m256d, lo: __m256d) -> __m256d {
+    unsafe {
+        let clamped = clamp_pd(x, -746.0, 710.0);
+        let lo = _mm256_and_pd(lo, _mm256_cmp_pd(clamped, x, _CMP_EQ_OQ));
+        let x = clamped;
+        let (n, r, r_lo) = reduce_pd(x);
+        let (hi, lo) = expm1_poly_pd(r, _mm256_add_pd(r_lo, lo));
+        scale_pd(n, hi, lo)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp_pd(x: __m256d) -> __m256d {
+    unsafe { exp_dd_pd(x, _mm256_setzero_pd()) }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp2_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const LN2: f64 = core::f64::consts::LN_2;
+        const LN2_LO: f64 = 2.319_046_813_846_299_6e-17;
+
+        let x = clamp_pd(x, -1080.0, 1030.0);
+        let n = _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC);
+        let r = _mm256_sub_pd(x, n);
+
+        // r * ln(2) in double-double.
+        let hi = _mm256_mul_pd(r, _mm256_set1_pd(LN2));
+        let lo = _mm256_fmsub_pd(r, _mm256_set1_pd(LN2), hi);
+        let lo = _mm256_fmadd_pd(r, _mm256_set1_pd(LN2_LO), lo);
+        let (hi, lo) = expm1_poly_pd(hi, lo);
+        scale_pd(n, hi, lo)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp10_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const LN10: f64 = core::f64::consts::LN_10;
+        const LN10_LO: f64 = -2.170_756_223_382_249_4e-16;
+
+        let x = clamp_pd(x, -330.0, 310.0);
+
+        // x * ln(10) in double-double.
+        let a = _mm256_mul_pd(x, _mm256_set1_pd(LN10));
+        let lo = _mm256_fmsub_pd(x, _mm256_set1_pd(LN10), a);
+        let lo = _mm256_fmadd_pd(x, _mm256_set1_pd(LN10_LO), lo);
+
+        let (n, r, r_lo) = reduce_pd(a);
+        let (hi, lo) = expm1_poly_pd(r, _mm256_add_pd(r_lo, lo));
+        scale_pd(n, hi, lo)
+    }
+}
+
+/// Returns `2^n * (1 + hi + lo) - 1` as a double-double, for `n <= 1023`.
+#[inline(always)]
+unsafe fn expm1_scale_pd(n: __m256d, hi: __m256d, lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        // 2^n - 1 is kept exact as `a + e1`.
+        let s = pow2i_pd(n);
+        let (a, e1) = two_sum_pd(s, _mm256_set1_pd(-1.0));
+        let (sum, e2) = two_sum_pd(_mm256_mul_pd(s, hi), a);
+        (sum, _mm256_fmadd_pd(s, lo, _mm256_add_pd(e1, e2)))
+    }
+}
+
+/// Returns `e^x - 1` as a double-double `(hi, lo)`, for `x <= 709`.
+#[inline(always)]
+unsafe fn expm1_dd_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let (n, r, r_lo) = reduce_pd(clamp_pd(x, -40.0, 709.0));
+        let (hi, lo) = expm1_poly_pd(r, r_lo);
+        expm1_scale_pd(n, hi, lo)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn expm1_pd(x: __m256d) -> __m256d {
+    unsafe {
+        // e^-40 is below half an ULP of 1, so the result is -1 from there on.
+        let clamped = clamp_pd(x, -40.0, 710.0);
+        let (n, r, r_lo) = reduce_pd(clamped);
+        let (hi, lo) = expm1_poly_pd(r, r_lo);
+
+        // 2^n is only representable up to 2^1023.
+        let (sum, err) = expm1_scale_pd(n, hi, lo);
+        let result = _mm256_blendv_pd(
+            _mm256_add_pd(sum, err),
+            scale_pd(n, hi, lo),
+            _mm256_cmp_pd(n, _mm256_set1_pd(1023.0), _CMP_GT_OQ),
+        );
+
+        // Keeps the sign of zero.
+        _mm256_blendv_pd(result, x, _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_EQ_OQ))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tanh_pd(x: __m256d) -> __m256d {
+    unsafe {
+        // tanh|x| = -e / (2 + e) with e = expm1(-2|x|), which neither cancels near zero nor
+        // overflows for large |x|. The division is carried out in double-double.
+        let sign = _mm256_set1_pd(-0.0);
+        let abs = _mm256_andnot_pd(sign, x);
+        let (e, e_lo) = expm1_dd_pd(_mm256_mul_pd(abs, _mm256_set1_pd(-2.0)));
+        let (den, den_lo) = two_sum_pd(_mm256_set1_pd(2.0), e);
+        let den_lo = _mm256_add_pd(den_lo, e_lo);
+        let q = _mm256_div_pd(e, den);
+        let rem = _mm256_fnmadd_pd(q, den, e);
+        let rem = _mm256_fnmadd_pd(q, den_lo, _mm256_add_pd(rem, e_lo));
+        let result = _mm256_add_pd(q, _mm256_div_pd(rem, den));
+
+        // `result` is -tanh|x|, so it takes the sign of `x` instead.
+        _mm256_or_pd(_mm256_andnot_pd(sign, result), _mm256_and_pd(x, sign))
+    }
+}
+
+/// Returns `(n, e^r - 1)` with `e^x = 2^n * e^r`, computed in `f32` for `x` in `[-104, 89]`.
+#[inline(always)]
+unsafe fn expm1_parts_fast_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        const LN2_HI: f32 = 6.931_457_5e-1;
+        const LN2_LO: f32 = 1.428_606_8e-6;
+        const C: [f32; 6] = [
+            1.0 / 2.0,
+            1.0 / 6.0,
+            1.0 / 24.0,
+            1.0 / 120.0,
+            1.0 / 720.0,
+            1.0 / 5_040.0,
+        ];
+
+        let n = _mm256_round_ps(
+            _mm256_mul_ps(x, _mm256_set1_ps(core::f32::consts::LOG2_E)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+        let r = _mm256_fnmadd_ps(n, _mm256_set1_ps(LN2_HI), x);
+        let r = _mm256_fnmadd_ps(n, _mm256_set1_ps(LN2_LO), r);
+
+        let mut poly = _mm256_set1_ps(C[5]);
+        for c in C[..5].iter().rev() {
+            poly = _mm256_fmadd_ps(poly, r, _mm256_set1_ps(*c));
+        }
+        (n, _mm256_fmadd_ps(poly, _mm256_mul_ps(r, r), r))
+    }
+}
+
+/// Returns `2^n` for integral `n` in `[-126, 127]`.
+#[inline(always)]
+unsafe fn pow2i_ps(n: __m256) -> __m256 {
+    unsafe {
+        let biased = _mm256_add_epi32(_mm256_cvtps_epi32(n), _mm256_set1_epi32(127));
+        _mm256_castsi256_ps(_mm256_slli_epi32(biased, 23))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp_fast_ps(x: __m256) -> __m256 {
+    unsafe {
+        // `min_ps` and `max_ps` return their second operand when either is NaN.
+        let x = _mm256_max_ps(_mm256_set1_ps(-104.0), x);
+        let x = _mm256_min_ps(_mm256_set1_ps(89.0), x);
+        let (n, p) = expm1_parts_fast_ps(x);
+
+        // 2^n is applied in two steps since it may not be representable on its own.
+        let n1 = _mm256_floor_ps(_mm256_mul_ps(n, _mm256_set1_ps(0.5)));
+        let s1 = pow2i_ps(n1);
+        let s2 = pow2i_ps(_mm256_sub_ps(n, n1));
+        _mm256_mul_ps(_mm256_fmadd_ps(s1, p, s1), s2)
+    }
+}
+
+/// Returns `(n, e^r - 1)` with `e^x = 2^n * e^r`, for `x` in `[-746, 710]`. Unlike
+/// [`expm1_poly_pd`] the polynomial is of lower degree and nothing is carried in double-double.
+#[inline(always)]
+unsafe fn expm1_parts_fast_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        // Minimax for `(e^r - 1 - r) / r^2` on `[-ln(2) / 2, ln(2) / 2]`.
+        const C: [f64; 10] = [
+            5.000_000_000_000_004e-1,
+            1.666_666_666_666_693_5e-1,
+            4.166_666_666_659_437_5e-2,
+            8.333_333_333_083_892e-3,
+            1.388_888_892_534_080_3e-3,
+            1.984_127_055_375_298_7e-4,
+            2.480_151_318_132_290_5e-5,
+            2.755_651_614_400_299e-6,
+            2.762_275_462_460_613e-7,
+            2.537_958_739_571_797_8e-8,
+        ];
+
+        let n = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::LOG2_E)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+        let r = _mm256_fnmadd_pd(n, _mm256_set1_pd(LN2_HI), x);
+        let r = _mm256_fnmadd_pd(n, _mm256_set1_pd(LN2_LO), r);
+
+        let mut poly = _mm256_set1_pd(C[9]);
+        for c in C[..9].iter().rev() {
+            poly = _mm256_fmadd_pd(poly, r, _mm256_set1_pd(*c));
+        }
+        (n, _mm256_fmadd_pd(poly, _mm256_mul_pd(r, r), r))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn exp_fast_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let (n, p) = expm1_parts_fast_pd(clamp_pd(x, -746.0, 710.0));
+
+        // 2^n is applied in two steps since it may not be representable on its own.
+        let n1 = _mm256_floor_pd(_mm256_mul_pd(n, _mm256_set1_pd(0.5)));
+        let s1 = pow2i_pd(n1);
+        let s2 = pow2i_pd(_mm256_sub_pd(n, n1));
+        _mm256_mul_pd(_mm256_fmadd_pd(s1, p, s1), s2)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tanh_fast_ps(x: __m256) -> __m256 {
+    unsafe {
+        // tanh|x| = -e / (2 + e) with e = expm1(-2|x|), and e^-20 is far below an ULP of 1.
+        let sign = _mm256_set1_ps(-0.0);
+        let abs = _mm256_andnot_ps(sign, x);
+        let t = _mm256_max_ps(
+            _mm256_set1_ps(-20.0),
+            _mm256_mul_ps(abs, _mm256_set1_ps(-2.0)),
+        );
+        let (n, p) = expm1_parts_fast_ps(t);
+        let s = pow2i_ps(n);
+        let e = _mm256_fmadd_ps(s, p, _mm256_sub_ps(s, _mm256_set1_ps(1.0)));
+        let result = _mm256_div_ps(e, _mm256_add_ps(_mm256_set1_ps(2.0), e));
+        _mm256_or_ps(_mm256_andnot_ps(sign, result), _mm256_and_ps(x, sign))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tanh_fast_pd(x: __m256d) -> __m256d {
+    unsafe {
+        // tanh|x| = -e / (2 + e) with e = expm1(-2|x|), and e^-40 is far below an ULP of 1.
+        let sign = _mm256_set1_pd(-0.0);
+        let abs = _mm256_andnot_pd(sign, x);
+        let t = _mm256_max_pd(
+            _mm256_set1_pd(-40.0),
+            _mm256_mul_pd(abs, _mm256_set1_pd(-2.0)),
+        );
+        let (n, p) = expm1_parts_fast_pd(t);
+        let s = pow2i_pd(n);
+        let e = _mm256_fmadd_pd(s, p, _mm256_sub_pd(s, _mm256_set1_pd(1.0)));
+        let result = _mm256_div_pd(e, _mm256_add_pd(_mm256_set1_pd(2.0), e));
+        _mm256_or_pd(_mm256_andnot_pd(sign, result), _mm256_and_pd(x, sign))
+    }
+}
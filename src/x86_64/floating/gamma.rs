@@ -0,0 +1,273 @@
+use super::exp::exp_dd_pd;
+use super::log::{ln_dd_pd, ln_of_dd_pd, ln_pd};
+use super::trig::sinpi_abs_pd;
+use super::{mul_dd_pd, two_sum_pd};
+use core::arch::x86_64::*;
+
+/// `ln(pi)` split in two.
+const LN_PI_HI: f64 = 1.144_729_885_849_400_2;
+const LN_PI_LO: f64 = 1.026_595_116_270_782_6e-17;
+
+/// Below this, `ln(gamma(x)) = -ln(x)` to within rounding.
+const TINY: f64 = 1.387_778_780_781_445_7e-17;
+
+/// Returns `ln(gamma(x + x_lo))` as a double-double for `x >= TINY`, with an absolute error of
+/// about 2^-62. Other lanes give garbage.
+#[inline(always)]
+unsafe fn lgamma_positive_pd(x: __m256d, x_lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const SHIFT: f64 = 16.0;
+        const HALF_LN_2PI_HI: f64 = 9.189_385_332_046_728e-1;
+        const HALF_LN_2PI_LO: f64 = -3.878_294_158_067_241_4e-17;
+        const ONE_TWELFTH_LO: f64 = 4.625_929_269_271_485e-18;
+        // B(2k) / (2k (2k - 1)) for k from 2, after the leading 1/12.
+        const STIRLING: [f64; 9] = [
+            -1.0 / 360.0,
+            1.0 / 1260.0,
+            -1.0 / 1680.0,
+            1.0 / 1188.0,
+            -691.0 / 360_360.0,
+            1.0 / 156.0,
+            -3617.0 / 122_400.0,
+            43_867.0 / 244_188.0,
+            -174_611.0 / 125_400.0,
+        ];
+
+        let one = _mm256_set1_pd(1.0);
+        let shift = _mm256_set1_pd(SHIFT);
+
+        // gamma(x) = gamma(z) / p with z = x + n >= SHIFT and p = x (x + 1) ... (x + n - 1).
+        let (mut z, mut z_lo) = (x, x_lo);
+        let (mut p, mut p_lo) = (one, _mm256_setzero_pd());
+        for _ in 0..SHIFT as usize {
+            let below = _mm256_cmp_pd(z, shift, _CMP_LT_OQ);
+            if _mm256_movemask_pd(below) == 0 {
+                break;
+            }
+            let (next, next_lo) = mul_dd_pd(p, p_lo, z, z_lo);
+            p = _mm256_blendv_pd(p, next, below);
+            p_lo = _mm256_blendv_pd(p_lo, next_lo, below);
+            let (next, err) = two_sum_pd(z, one);
+            z = _mm256_blendv_pd(z, next, below);
+            z_lo = _mm256_blendv_pd(z_lo, _mm256_add_pd(z_lo, err), below);
+        }
+
+        // Stirling's series, ln(gamma(z)) = (z - 1/2) ln(z) - z + ln(2 pi) / 2 + 1 / (12 z) - ...
+        let (l, l_lo) = ln_of_dd_pd(z, z_lo);
+        let (m, m_lo) = mul_dd_pd(_mm256_sub_pd(z, _mm256_set1_pd(0.5)), z_lo, l, l_lo);
+        let (s, e1) = two_sum_pd(m, _mm256_sub_pd(_mm256_setzero_pd(), z));
+        let (s, e2) = two_sum_pd(s, _mm256_set1_pd(HALF_LN_2PI_HI));
+
+        let r = _mm256_div_pd(one, z);
+        let r_lo = _mm256_div_pd(_mm256_fnmadd_pd(r, z_lo, _mm256_fnmadd_pd(r, z, one)), z);
+        let (t, t_lo) = mul_dd_pd(
+            r,
+            r_lo,
+            _mm256_set1_pd(1.0 / 12.0),
+            _mm256_set1_pd(ONE_TWELFTH_LO),
+        );
+        let r2 = _mm256_mul_pd(r, r);
+        let mut series = _mm256_set1_pd(STIRLING[8]);
+        for c in STIRLING[..8].iter().rev() {
+            series = _mm256_fmadd_pd(series, r2, _mm256_set1_pd(*c));
+        }
+        let series = _mm256_mul_pd(_mm256_mul_pd(r, r2), series);
+        let (s, e3) = two_sum_pd(s, t);
+
+        let (lp, lp_lo) = ln_of_dd_pd(p, p_lo);
+        let (s, e4) = two_sum_pd(s, _mm256_sub_pd(_mm256_setzero_pd(), lp));
+
+        let lo = _mm256_add_pd(
+            _mm256_add_pd(_mm256_sub_pd(m_lo, z_lo), _mm256_add_pd(e1, e2)),
+            _mm256_add_pd(_mm256_add_pd(e3, e4), _mm256_set1_pd(HALF_LN_2PI_LO)),
+        );
+        let lo = _mm256_add_pd(
+            lo,
+            _mm256_add_pd(
+                _mm256_add_pd(t_lo, series),
+                _mm256_sub_pd(_mm256_setzero_pd(), lp_lo),
+            ),
+        );
+
+        // (z - 1/2) ln(z) overflows for huge z, where its rounding error is not finite.
+        let finite = _mm256_cmp_pd(
+            _mm256_andnot_pd(_mm256_set1_pd(-0.0), m),
+            _mm256_set1_pd(f64::INFINITY),
+            _CMP_LT_OQ,
+        );
+        let hi = _mm256_add_pd(s, lo);
+        let lo = _mm256_sub_pd(lo, _mm256_sub_pd(hi, s));
+        (_mm256_blendv_pd(m, hi, finite), _mm256_and_pd(lo, finite))
+    }
+}
+
+/// Returns `ln|gamma(x)|` as a double-double for finite `|x| >= TINY` that is not a non-positive
+/// integer, reflecting negative `x` with `gamma(x) gamma(1 - x) = pi / sin(pi x)`.
+#[inline(always)]
+unsafe fn lgamma_dd_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let negative = _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_LT_OQ);
+        let (y, y_lo) = two_sum_pd(_mm256_set1_pd(1.0), _mm256_sub_pd(_mm256_setzero_pd(), x));
+        let (g, g_lo) = lgamma_positive_pd(
+            _mm256_blendv_pd(x, y, negative),
+            _mm256_and_pd(y_lo, negative),
+        );
+
+        // ln|gamma(x)| = ln(pi) - ln|sin(pi x)| - ln(gamma(1 - x)) for negative x.
+        let (ls, ls_lo) = ln_dd_pd(sinpi_abs_pd(x));
+        let (r, e1) = two_sum_pd(
+            _mm256_set1_pd(LN_PI_HI),
+            _mm256_sub_pd(_mm256_setzero_pd(), ls),
+        );
+        let (r, e2) = two_sum_pd(r, _mm256_sub_pd(_mm256_setzero_pd(), g));
+        let r_lo = _mm256_add_pd(
+            _mm256_add_pd(e1, e2),
+            _mm256_sub_pd(_mm256_set1_pd(LN_PI_LO), _mm256_add_pd(ls_lo, g_lo)),
+        );
+        let hi = _mm256_add_pd(r, r_lo);
+        let r_lo = _mm256_sub_pd(r_lo, _mm256_sub_pd(hi, r));
+
+        (
+            _mm256_blendv_pd(g, hi, negative),
+            _mm256_blendv_pd(g_lo, r_lo, negative),
+        )
+    }
+}
+
+/// Returns `ln(gamma(base + t)) = t * (c0 + c0_lo + t * q(t))` for `|t| <= 1/8`, near a zero of
+/// `ln(gamma)` where the double-double evaluation is not accurate enough relative to the result.
+#[inline(always)]
+unsafe fn lgamma_near_zero_pd(t: __m256d, c0: f64, c0_lo: f64, q: &[f64]) -> __m256d {
+    unsafe {
+        let mut p = _mm256_set1_pd(q[q.len() - 1]);
+        for c in q[..q.len() - 1].iter().rev() {
+            p = _mm256_fmadd_pd(p, t, _mm256_set1_pd(*c));
+        }
+        _mm256_fmadd_pd(
+            t,
+            _mm256_set1_pd(c0),
+            _mm256_mul_pd(t, _mm256_fmadd_pd(t, p, _mm256_set1_pd(c0_lo))),
+        )
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn lgamma_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const C0_NEAR_1: f64 = -5.772_156_649_015_329e-1;
+        const C0_NEAR_1_LO: f64 = 4.942_915_152_430_645e-18;
+        const NEAR_1: [f64; 14] = [
+            8.224_670_334_241_132e-1,
+            -4.006_856_343_865_314_3e-1,
+            2.705_808_084_277_731e-1,
+            -2.073_855_510_286_632_4e-1,
+            1.695_571_770_090_976_7e-1,
+            -1.440_498_967_798_514e-1,
+            1.255_096_650_424_735_4e-1,
+            -1.113_342_616_496_964_4e-1,
+            1.001_002_750_423_873_9e-1,
+            -9.095_478_680_277_302e-2,
+            8.327_745_465_136_459e-2,
+            -7.686_060_566_443_692e-2,
+            7.495_427_366_391_531e-2,
+            -6.998_352_362_194_853e-2,
+        ];
+        const C0_NEAR_2: f64 = 4.227_843_350_984_671_3e-1;
+        const C0_NEAR_2_LO: f64 = 4.942_915_152_430_645e-18;
+        const NEAR_2: [f64; 12] = [
+            3.224_670_334_241_132e-1,
+            -6.735_230_105_319_81e-2,
+            2.058_080_842_778_469_2e-2,
+            -7.385_551_028_674_052_5e-3,
+            2.890_510_330_633_5e-3,
+            -1.192_753_911_652_904_4e-3,
+            5.096_695_542_256_825e-4,
+            -2.231_547_721_972_238_2e-4,
+            9.945_387_730_802_947e-5,
+            -4.492_454_203_844_993e-5,
+            2.071_360_325_213_438e-5,
+            -9.535_696_732_434_743e-6,
+        ];
+
+        let sign = _mm256_set1_pd(-0.0);
+        let ax = _mm256_andnot_pd(sign, x);
+        let inf = _mm256_set1_pd(f64::INFINITY);
+
+        let (hi, lo) = lgamma_dd_pd(x);
+        let result = _mm256_add_pd(hi, lo);
+
+        let eighth = _mm256_set1_pd(0.125);
+        let t1 = _mm256_sub_pd(x, _mm256_set1_pd(1.0));
+        let near_1 = lgamma_near_zero_pd(t1, C0_NEAR_1, C0_NEAR_1_LO, &NEAR_1);
+        let result = _mm256_blendv_pd(
+            result,
+            near_1,
+            _mm256_cmp_pd(_mm256_andnot_pd(sign, t1), eighth, _CMP_LE_OQ),
+        );
+        let t2 = _mm256_sub_pd(x, _mm256_set1_pd(2.0));
+        let near_2 = lgamma_near_zero_pd(t2, C0_NEAR_2, C0_NEAR_2_LO, &NEAR_2);
+        let result = _mm256_blendv_pd(
+            result,
+            near_2,
+            _mm256_cmp_pd(_mm256_andnot_pd(sign, t2), eighth, _CMP_LE_OQ),
+        );
+
+        let tiny = _mm256_cmp_pd(ax, _mm256_set1_pd(TINY), _CMP_LT_OQ);
+        let result = _mm256_blendv_pd(result, _mm256_sub_pd(_mm256_setzero_pd(), ln_pd(ax)), tiny);
+
+        // Poles at non-positive integers, and infinities, give positive infinity.
+        let pole = _mm256_and_pd(
+            _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_LE_OQ),
+            _mm256_cmp_pd(
+                _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC),
+                x,
+                _CMP_EQ_OQ,
+            ),
+        );
+        let pole = _mm256_or_pd(pole, _mm256_cmp_pd(ax, inf, _CMP_EQ_OQ));
+        _mm256_blendv_pd(result, inf, pole)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tgamma_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let zero = _mm256_setzero_pd();
+        let sign = _mm256_set1_pd(-0.0);
+        let ax = _mm256_andnot_pd(sign, x);
+
+        let (hi, lo) = lgamma_dd_pd(x);
+        let result = exp_dd_pd(hi, lo);
+
+        // gamma(x) is negative between -2k - 1 and -2k, where ceil(x) is even.
+        let ceil = _mm256_round_pd(x, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC);
+        let half = _mm256_mul_pd(ceil, _mm256_set1_pd(0.5));
+        let even = _mm256_cmp_pd(
+            _mm256_round_pd(half, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC),
+            half,
+            _CMP_EQ_OQ,
+        );
+        let negative = _mm256_and_pd(_mm256_cmp_pd(x, zero, _CMP_LT_OQ), even);
+        let result = _mm256_or_pd(result, _mm256_and_pd(negative, sign));
+
+        // gamma(x) = 1/x to within rounding near zero, including the signed infinities at zero.
+        let tiny = _mm256_cmp_pd(ax, _mm256_set1_pd(TINY), _CMP_LT_OQ);
+        let result = _mm256_blendv_pd(result, _mm256_div_pd(_mm256_set1_pd(1.0), x), tiny);
+
+        // Negative integers and negative infinity give NaN, positive infinity gives itself.
+        let pole = _mm256_and_pd(
+            _mm256_cmp_pd(x, zero, _CMP_LT_OQ),
+            _mm256_cmp_pd(
+                _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC),
+                x,
+                _CMP_EQ_OQ,
+            ),
+        );
+        let result = _mm256_blendv_pd(result, _mm256_set1_pd(f64::NAN), pole);
+        _mm256_blendv_pd(
+            result,
+            x,
+            _mm256_cmp_pd(x, _mm256_set1_pd(f64::INFINITY), _CMP_EQ_OQ),
+        )
+    }
+}
@@ -0,0 +1,245 @@
+use super::pick_pd;
+use core::arch::x86_64::*;
+
+const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+const PIO2_LO: f64 = 6.123_233_995_736_766e-17;
+
+/// Returns `atan(t)` for `t >= 0` or NaN.
+#[inline(always)]
+unsafe fn atan_abs_pd(t: __m256d) -> __m256d {
+    unsafe {
+        const AT: [f64; 11] = [
+            3.333_333_333_333_293e-1,
+            -1.999_999_999_987_648_3e-1,
+            1.428_571_427_250_346_6e-1,
+            -1.111_111_040_546_235_6e-1,
+            9.090_887_133_436_507e-2,
+            -7.691_876_205_044_83e-2,
+            6.661_073_137_387_531e-2,
+            -5.833_570_133_790_573_5e-2,
+            4.976_877_994_615_932_4e-2,
+            -3.653_157_274_421_691_6e-2,
+            1.628_582_011_536_578_2e-2,
+        ];
+
+        // Reduces `t` to `u` in [-7/16, 7/16] with `atan(t) = hi + lo + atan(u)`.
+        let masks = [
+            _mm256_cmp_pd(t, _mm256_set1_pd(7.0 / 16.0), _CMP_GE_OQ),
+            _mm256_cmp_pd(t, _mm256_set1_pd(11.0 / 16.0), _CMP_GE_OQ),
+            _mm256_cmp_pd(t, _mm256_set1_pd(19.0 / 16.0), _CMP_GE_OQ),
+            _mm256_cmp_pd(t, _mm256_set1_pd(39.0 / 16.0), _CMP_GE_OQ),
+        ];
+        let hi = pick_pd(
+            &masks,
+            [
+                0.0,
+                4.636_476_090_008_061e-1,
+                core::f64::consts::FRAC_PI_4,
+                9.827_937_232_473_29e-1,
+                PIO2_HI,
+            ],
+        );
+        let lo = pick_pd(
+            &masks,
+            [
+                0.0,
+                2.269_877_745_296_168_7e-17,
+                3.061_616_997_868_383e-17,
+                1.390_331_103_123_099_8e-17,
+                PIO2_LO,
+            ],
+        );
+        let num = _mm256_fmadd_pd(
+            pick_pd(&masks, [1.0, 2.0, 1.0, 1.0, 0.0]),
+            t,
+            pick_pd(&masks, [0.0, -1.0, -1.0, -1.5, 0.0]),
+        );
+        let num = _mm256_blendv_pd(num, _mm256_set1_pd(-1.0), masks[3]);
+        let den = _mm256_fmadd_pd(
+            pick_pd(&masks, [0.0, 1.0, 1.0, 1.5, 1.0]),
+            t,
+            pick_pd(&masks, [1.0, 2.0, 1.0, 1.0, 0.0]),
+        );
+        let u = _mm256_div_pd(num, den);
+
+        let z = _mm256_mul_pd(u, u);
+        let w = _mm256_mul_pd(z, z);
+        let s1 = _mm256_fmadd_pd(w, _mm256_set1_pd(AT[10]), _mm256_set1_pd(AT[8]));
+        let s1 = _mm256_fmadd_pd(w, s1, _mm256_set1_pd(AT[6]));
+        let s1 = _mm256_fmadd_pd(w, s1, _mm256_set1_pd(AT[4]));
+        let s1 = _mm256_fmadd_pd(w, s1, _mm256_set1_pd(AT[2]));
+        let s1 = _mm256_mul_pd(z, _mm256_fmadd_pd(w, s1, _mm256_set1_pd(AT[0])));
+        let s2 = _mm256_fmadd_pd(w, _mm256_set1_pd(AT[9]), _mm256_set1_pd(AT[7]));
+        let s2 = _mm256_fmadd_pd(w, s2, _mm256_set1_pd(AT[5]));
+        let s2 = _mm256_fmadd_pd(w, s2, _mm256_set1_pd(AT[3]));
+        let s2 = _mm256_mul_pd(w, _mm256_fmadd_pd(w, s2, _mm256_set1_pd(AT[1])));
+
+        let tail = _mm256_fmsub_pd(u, _mm256_add_pd(s1, s2), lo);
+        _mm256_sub_pd(hi, _mm256_sub_pd(tail, u))
+    }
+}
+
+/// Returns the rational approximation `R(z)` with `asin(x) = x + x * R(x^2)` for `|x| < 0.5`.
+#[inline(always)]
+unsafe fn asin_r_pd(z: __m256d) -> __m256d {
+    unsafe {
+        const PS: [f64; 6] = [
+            1.666_666_666_666_666_6e-1,
+            -3.255_658_186_224_009e-1,
+            2.012_125_321_348_629_3e-1,
+            -4.005_553_450_067_941e-2,
+            7.915_349_942_898_145e-4,
+            3.479_331_075_960_212e-5,
+        ];
+        const QS: [f64; 4] = [
+            -2.403_394_911_734_414,
+            2.020_945_760_233_505_7,
+            -6.882_839_716_054_533e-1,
+            7.703_815_055_590_194e-2,
+        ];
+
+        let p = _mm256_fmadd_pd(z, _mm256_set1_pd(PS[5]), _mm256_set1_pd(PS[4]));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(PS[3]));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(PS[2]));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(PS[1]));
+        let p = _mm256_mul_pd(z, _mm256_fmadd_pd(z, p, _mm256_set1_pd(PS[0])));
+        let q = _mm256_fmadd_pd(z, _mm256_set1_pd(QS[3]), _mm256_set1_pd(QS[2]));
+        let q = _mm256_fmadd_pd(z, q, _mm256_set1_pd(QS[1]));
+        let q = _mm256_fmadd_pd(z, q, _mm256_set1_pd(QS[0]));
+        let q = _mm256_fmadd_pd(z, q, _mm256_set1_pd(1.0));
+        _mm256_div_pd(p, q)
+    }
+}
+
+/// Returns `(hi, lo)` where `hi` is `s` with the low half of its mantissa cleared, so `hi * hi`
+/// is exact, and `hi + lo` is `sqrt(z)` for `s = sqrt(z)`.
+#[inline(always)]
+unsafe fn split_sqrt_pd(z: __m256d, s: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let hi = _mm256_and_pd(
+            s,
+            _mm256_castsi256_pd(_mm256_set1_epi64x(0xffff_ffff_0000_0000u64 as i64)),
+        );
+        let lo = _mm256_div_pd(_mm256_fnmadd_pd(hi, hi, z), _mm256_add_pd(s, hi));
+        (hi, lo)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn asin_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let two = _mm256_set1_pd(2.0);
+        let pio2_hi = _mm256_set1_pd(PIO2_HI);
+        let pio2_lo = _mm256_set1_pd(PIO2_LO);
+
+        let a = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+        let small = _mm256_cmp_pd(a, _mm256_set1_pd(0.5), _CMP_LT_OQ);
+        let z_big = _mm256_mul_pd(_mm256_sub_pd(_mm256_set1_pd(1.0), a), _mm256_set1_pd(0.5));
+        let z = _mm256_blendv_pd(z_big, _mm256_mul_pd(a, a), small);
+        let r = asin_r_pd(z);
+        let s = _mm256_sqrt_pd(z_big);
+
+        // asin(a) = pi/2 - 2 * asin(sqrt((1 - a) / 2)) for a >= 0.5.
+        let small_result = _mm256_fmadd_pd(a, r, a);
+        let near_one = _mm256_fmsub_pd(two, _mm256_fmadd_pd(s, r, s), pio2_lo);
+        let near_one = _mm256_sub_pd(pio2_hi, near_one);
+        let (f, c) = split_sqrt_pd(z_big, s);
+        let half_pio2 = _mm256_mul_pd(_mm256_set1_pd(0.5), pio2_hi);
+        let p = _mm256_fnmadd_pd(two, c, pio2_lo);
+        let q = _mm256_fnmadd_pd(two, f, half_pio2);
+        let mid = _mm256_sub_pd(_mm256_sub_pd(_mm256_mul_pd(_mm256_mul_pd(two, s), r), p), q);
+        let mid = _mm256_sub_pd(half_pio2, mid);
+
+        let result = _mm256_blendv_pd(
+            mid,
+            near_one,
+            _mm256_cmp_pd(a, _mm256_set1_pd(0.975), _CMP_GE_OQ),
+        );
+        let result = _mm256_blendv_pd(result, small_result, small);
+        _mm256_or_pd(result, _mm256_and_pd(x, _mm256_set1_pd(-0.0)))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn acos_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let two = _mm256_set1_pd(2.0);
+        let pio2_hi = _mm256_set1_pd(PIO2_HI);
+        let pio2_lo = _mm256_set1_pd(PIO2_LO);
+
+        let a = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+        let small = _mm256_cmp_pd(a, _mm256_set1_pd(0.5), _CMP_LT_OQ);
+        let z_big = _mm256_mul_pd(_mm256_sub_pd(_mm256_set1_pd(1.0), a), _mm256_set1_pd(0.5));
+        let z = _mm256_blendv_pd(z_big, _mm256_mul_pd(x, x), small);
+        let r = asin_r_pd(z);
+        let s = _mm256_sqrt_pd(z_big);
+
+        // acos(x) = 2 * asin(sqrt((1 - x) / 2)) and acos(-x) = pi - acos(x) for x >= 0.5.
+        let small_result = _mm256_sub_pd(x, _mm256_fnmadd_pd(x, r, pio2_lo));
+        let small_result = _mm256_sub_pd(pio2_hi, small_result);
+        let negative = _mm256_add_pd(s, _mm256_fmsub_pd(r, s, pio2_lo));
+        let negative = _mm256_mul_pd(two, _mm256_sub_pd(pio2_hi, negative));
+        let (f, c) = split_sqrt_pd(z_big, s);
+        let c = _mm256_and_pd(c, _mm256_cmp_pd(s, _mm256_setzero_pd(), _CMP_NEQ_UQ));
+        let positive = _mm256_mul_pd(two, _mm256_add_pd(f, _mm256_fmadd_pd(r, s, c)));
+
+        let result = _mm256_blendv_pd(positive, negative, x);
+        _mm256_blendv_pd(result, small_result, small)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn atan_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let sign = _mm256_set1_pd(-0.0);
+        let result = atan_abs_pd(_mm256_andnot_pd(sign, x));
+        _mm256_or_pd(result, _mm256_and_pd(x, sign))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn atan2_pd(y: __m256d, x: __m256d) -> __m256d {
+    unsafe {
+        const PI_HI: f64 = core::f64::consts::PI;
+        const PI_LO: f64 = 1.224_646_799_147_353_2e-16;
+
+        let sign = _mm256_set1_pd(-0.0);
+        let ay = _mm256_andnot_pd(sign, y);
+        let ax = _mm256_andnot_pd(sign, x);
+        let ratio = _mm256_div_pd(ay, ax);
+        let result = atan_abs_pd(ratio);
+
+        // 0/0 and inf/inf are NaN, but have well defined angles.
+        let zero = _mm256_setzero_pd();
+        let inf = _mm256_set1_pd(f64::INFINITY);
+        let both_zero = _mm256_and_pd(
+            _mm256_cmp_pd(ay, zero, _CMP_EQ_OQ),
+            _mm256_cmp_pd(ax, zero, _CMP_EQ_OQ),
+        );
+        let both_inf = _mm256_and_pd(
+            _mm256_cmp_pd(ay, inf, _CMP_EQ_OQ),
+            _mm256_cmp_pd(ax, inf, _CMP_EQ_OQ),
+        );
+        let result = _mm256_blendv_pd(result, zero, both_zero);
+        let result = _mm256_blendv_pd(
+            result,
+            _mm256_set1_pd(core::f64::consts::FRAC_PI_4),
+            both_inf,
+        );
+
+        // Negative `x`, including -0, mirrors the angle into the left half plane. Past a ratio of
+        // 2^60 the angle rounds to pi/2 on either side, which the mirroring would lose to
+        // rounding, so those lanes keep it.
+        let mirrored = _mm256_sub_pd(
+            _mm256_set1_pd(PI_HI),
+            _mm256_sub_pd(result, _mm256_set1_pd(PI_LO)),
+        );
+        let steep = _mm256_cmp_pd(
+            ratio,
+            _mm256_set1_pd(1_152_921_504_606_846_976.0),
+            _CMP_GT_OQ,
+        );
+        let result = _mm256_blendv_pd(result, mirrored, _mm256_andnot_pd(steep, x));
+        _mm256_or_pd(result, _mm256_and_pd(y, sign))
+    }
+}
@@ -0,0 +1,484 @@
+use super::two_sum_pd;
+use core::arch::x86_64::*;
+
+/// `ln(2)` split so that `k * LN2_HI` is exact for any exponent `k`.
+const LN2_HI: f64 = 6.931_471_803_691_238e-1;
+const LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+
+/// Splits positive `x` into `(k, f)` with `x = 2^k * (1 + f)` and `sqrt(1/2) <= 1 + f < sqrt(2)`.
+/// Lanes that are not positive and finite give garbage.
+#[inline(always)]
+unsafe fn split_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        // Subnormals are scaled into the normal range first.
+        let tiny = _mm256_cmp_ps(x, _mm256_set1_ps(f32::MIN_POSITIVE), _CMP_LT_OQ);
+        let x = _mm256_blendv_ps(x, _mm256_mul_ps(x, _mm256_set1_ps(33_554_432.0)), tiny);
+        let bias = _mm256_and_ps(tiny, _mm256_set1_ps(25.0));
+
+        let ix = _mm256_add_epi32(
+            _mm256_castps_si256(x),
+            _mm256_set1_epi32(0x3f80_0000 - 0x3f35_04f3),
+        );
+        let k = _mm256_sub_epi32(_mm256_srli_epi32(ix, 23), _mm256_set1_epi32(0x7f));
+        let k = _mm256_sub_ps(_mm256_cvtepi32_ps(k), bias);
+        let m = _mm256_castsi256_ps(_mm256_add_epi32(
+            _mm256_and_si256(ix, _mm256_set1_epi32(0x007f_ffff)),
+            _mm256_set1_epi32(0x3f35_04f3),
+        ));
+
+        (k, _mm256_sub_ps(m, _mm256_set1_ps(1.0)))
+    }
+}
+
+/// Splits positive `x` into `(k, f, c)` with `x = 2^k * (1 + f)`, `sqrt(1/2) <= 1 + f < sqrt(2)`
+/// and `ln(1 + f) = f + c`. Lanes that are not positive and finite give garbage.
+#[inline(always)]
+unsafe fn ln_parts_ps(x: __m256) -> (__m256, __m256, __m256) {
+    unsafe {
+        const LG1: f32 = 0.666_666_6;
+        const LG2: f32 = 0.400_009_72;
+        const LG3: f32 = 0.284_987_87;
+        const LG4: f32 = 0.242_790_79;
+
+        let (k, f) = split_ps(x);
+        let s = _mm256_div_ps(f, _mm256_add_ps(_mm256_set1_ps(2.0), f));
+        let z = _mm256_mul_ps(s, s);
+        let w = _mm256_mul_ps(z, z);
+        let t1 = _mm256_mul_ps(
+            w,
+            _mm256_fmadd_ps(w, _mm256_set1_ps(LG4), _mm256_set1_ps(LG2)),
+        );
+        let t2 = _mm256_mul_ps(
+            z,
+            _mm256_fmadd_ps(w, _mm256_set1_ps(LG3), _mm256_set1_ps(LG1)),
+        );
+        let r = _mm256_add_ps(t2, t1);
+        let hfsq = _mm256_mul_ps(_mm256_mul_ps(_mm256_set1_ps(0.5), f), f);
+        let c = _mm256_fmsub_ps(s, _mm256_add_ps(hfsq, r), hfsq);
+
+        (k, f, c)
+    }
+}
+
+/// Returns `(k, f, c)` like [`ln_parts_ps`], with a polynomial of one degree less.
+#[inline(always)]
+unsafe fn ln_parts_fast_ps(x: __m256) -> (__m256, __m256, __m256) {
+    unsafe {
+        const LG1: f32 = 0.666_667_76;
+        const LG2: f32 = 0.399_775_42;
+        const LG3: f32 = 0.298_717_3;
+
+        let (k, f) = split_ps(x);
+        let s = _mm256_div_ps(f, _mm256_add_ps(_mm256_set1_ps(2.0), f));
+        let z = _mm256_mul_ps(s, s);
+        let w = _mm256_mul_ps(z, z);
+        let t1 = _mm256_mul_ps(w, _mm256_set1_ps(LG2));
+        let t2 = _mm256_mul_ps(
+            z,
+            _mm256_fmadd_ps(w, _mm256_set1_ps(LG3), _mm256_set1_ps(LG1)),
+        );
+        let r = _mm256_add_ps(t2, t1);
+        let hfsq = _mm256_mul_ps(_mm256_mul_ps(_mm256_set1_ps(0.5), f), f);
+        let c = _mm256_fmsub_ps(s, _mm256_add_ps(hfsq, r), hfsq);
+
+        (k, f, c)
+    }
+}
+
+/// Splits positive `x` into `(k, f)` with `x = 2^k * (1 + f)` and `sqrt(1/2) <= 1 + f < sqrt(2)`.
+/// Lanes that are not positive and finite give garbage.
+#[inline(always)]
+unsafe fn split_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const MAGIC: f64 = 4_503_599_627_370_496.0;
+
+        // Subnormals are scaled into the normal range first.
+        let tiny = _mm256_cmp_pd(x, _mm256_set1_pd(f64::MIN_POSITIVE), _CMP_LT_OQ);
+        let x = _mm256_blendv_pd(
+            x,
+            _mm256_mul_pd(x, _mm256_set1_pd(18_014_398_509_481_984.0)),
+            tiny,
+        );
+        let bias = _mm256_and_pd(tiny, _mm256_set1_pd(54.0));
+
+        let ix = _mm256_add_epi64(
+            _mm256_castpd_si256(x),
+            _mm256_set1_epi64x((0x3ff0_0000 - 0x3fe6_a09e) << 32),
+        );
+
+        // The biased exponent is converted exactly by placing it in the mantissa of 2^52.
+        let k = _mm256_castsi256_pd(_mm256_or_si256(
+            _mm256_srli_epi64(ix, 52),
+            _mm256_castpd_si256(_mm256_set1_pd(MAGIC)),
+        ));
+        let k = _mm256_sub_pd(_mm256_sub_pd(k, _mm256_set1_pd(MAGIC + 1023.0)), bias);
+        let m = _mm256_castsi256_pd(_mm256_add_epi64(
+            _mm256_and_si256(ix, _mm256_set1_epi64x(0x000f_ffff_ffff_ffff)),
+            _mm256_set1_epi64x(0x3fe6_a09e << 32),
+        ));
+
+        (k, _mm256_sub_pd(m, _mm256_set1_pd(1.0)))
+    }
+}
+
+/// Splits positive `x` into `(k, f, c)` with `x = 2^k * (1 + f)`, `sqrt(1/2) <= 1 + f < sqrt(2)`
+/// and `ln(1 + f) = f + c`. Lanes that are not positive and finite give garbage.
+#[inline(always)]
+unsafe fn ln_parts_pd(x: __m256d) -> (__m256d, __m256d, __m256d) {
+    unsafe {
+        const LG1: f64 = 6.666_666_666_666_735e-1;
+        const LG2: f64 = 3.999_999_999_940_942e-1;
+        const LG3: f64 = 2.857_142_874_366_239e-1;
+        const LG4: f64 = 2.222_219_843_214_978_4e-1;
+        const LG5: f64 = 1.818_357_216_161_805e-1;
+        const LG6: f64 = 1.531_383_769_920_937_3e-1;
+        const LG7: f64 = 1.479_819_860_511_658_6e-1;
+
+        let (k, f) = split_pd(x);
+        let s = _mm256_div_pd(f, _mm256_add_pd(_mm256_set1_pd(2.0), f));
+        let z = _mm256_mul_pd(s, s);
+        let w = _mm256_mul_pd(z, z);
+        let t1 = _mm256_fmadd_pd(w, _mm256_set1_pd(LG6), _mm256_set1_pd(LG4));
+        let t1 = _mm256_mul_pd(w, _mm256_fmadd_pd(w, t1, _mm256_set1_pd(LG2)));
+        let t2 = _mm256_fmadd_pd(w, _mm256_set1_pd(LG7), _mm256_set1_pd(LG5));
+        let t2 = _mm256_fmadd_pd(w, t2, _mm256_set1_pd(LG3));
+        let t2 = _mm256_mul_pd(z, _mm256_fmadd_pd(w, t2, _mm256_set1_pd(LG1)));
+        let r = _mm256_add_pd(t2, t1);
+        let hfsq = _mm256_mul_pd(_mm256_mul_pd(_mm256_set1_pd(0.5), f), f);
+        let c = _mm256_fmsub_pd(s, _mm256_add_pd(hfsq, r), hfsq);
+
+        (k, f, c)
+    }
+}
+
+/// Returns `(k, f, c)` like [`ln_parts_pd`], with a polynomial of one degree less.
+#[inline(always)]
+unsafe fn ln_parts_fast_pd(x: __m256d) -> (__m256d, __m256d, __m256d) {
+    unsafe {
+        const LG1: f64 = 6.666_666_666_658_72e-1;
+        const LG2: f64 = 4.000_000_005_227_497_5e-1;
+        const LG3: f64 = 2.857_141_712_958_009e-1;
+        const LG4: f64 = 2.222_337_169_839_756e-1;
+        const LG5: f64 = 1.812_364_207_926_407e-1;
+        const LG6: f64 = 1.681_982_773_947_895_4e-1;
+
+        let (k, f) = split_pd(x);
+        let s = _mm256_div_pd(f, _mm256_add_pd(_mm256_set1_pd(2.0), f));
+        let z = _mm256_mul_pd(s, s);
+        let w = _mm256_mul_pd(z, z);
+        let t1 = _mm256_fmadd_pd(w, _mm256_set1_pd(LG6), _mm256_set1_pd(LG4));
+        let t1 = _mm256_mul_pd(w, _mm256_fmadd_pd(w, t1, _mm256_set1_pd(LG2)));
+        let t2 = _mm256_fmadd_pd(w, _mm256_set1_pd(LG5), _mm256_set1_pd(LG3));
+        let t2 = _mm256_mul_pd(z, _mm256_fmadd_pd(w, t2, _mm256_set1_pd(LG1)));
+        let r = _mm256_add_pd(t2, t1);
+        let hfsq = _mm256_mul_pd(_mm256_mul_pd(_mm256_set1_pd(0.5), f), f);
+        let c = _mm256_fmsub_pd(s, _mm256_add_pd(hfsq, r), hfsq);
+
+        (k, f, c)
+    }
+}
+
+/// Replaces `result` with the IEEE result of a logarithm of `x` where `x` is zero, negative, infinite or NaN.
+#[inline(always)]
+unsafe fn special_ps(x: __m256, result: __m256) -> __m256 {
+    unsafe {
+        let zero = _mm256_setzero_ps();
+        let result = _mm256_blendv_ps(
+            result,
+            _mm256_set1_ps(f32::NAN),
+            _mm256_cmp_ps(x, zero, _CMP_LT_OQ),
+        );
+        let result = _mm256_blendv_ps(
+            result,
+            _mm256_set1_ps(f32::NEG_INFINITY),
+            _mm256_cmp_ps(x, zero, _CMP_EQ_OQ),
+        );
+        _mm256_blendv_ps(
+            result,
+            x,
+            _mm256_cmp_ps(x, _mm256_set1_ps(f32::INFINITY), _CMP_NLT_UQ),
+        )
+    }
+}
+
+/// Replaces `result` with the IEEE result of a logarithm of `x` where `x` is zero, negative, infinite or NaN.
+#[inline(always)]
+pub(super) unsafe fn special_pd(x: __m256d, result: __m256d) -> __m256d {
+    unsafe {
+        let zero = _mm256_setzero_pd();
+        let result = _mm256_blendv_pd(
+            result,
+            _mm256_set1_pd(f64::NAN),
+            _mm256_cmp_pd(x, zero, _CMP_LT_OQ),
+        );
+        let result = _mm256_blendv_pd(
+            result,
+            _mm256_set1_pd(f64::NEG_INFINITY),
+            _mm256_cmp_pd(x, zero, _CMP_EQ_OQ),
+        );
+        _mm256_blendv_pd(
+            result,
+            x,
+            _mm256_cmp_pd(x, _mm256_set1_pd(f64::INFINITY), _CMP_NLT_UQ),
+        )
+    }
+}
+
+/// Returns `k * ln(2) + f + c`.
+#[inline(always)]
+unsafe fn combine_ln_ps(k: __m256, f: __m256, c: __m256) -> __m256 {
+    unsafe {
+        const LN2_HI: f32 = 6.931_381e-1;
+        const LN2_LO: f32 = 9.058_001e-6;
+
+        let lo = _mm256_fmadd_ps(k, _mm256_set1_ps(LN2_LO), c);
+        _mm256_fmadd_ps(k, _mm256_set1_ps(LN2_HI), _mm256_add_ps(f, lo))
+    }
+}
+
+/// Returns `k * ln(2) + f + c`.
+#[inline(always)]
+unsafe fn combine_ln_pd(k: __m256d, f: __m256d, c: __m256d) -> __m256d {
+    unsafe {
+        let lo = _mm256_fmadd_pd(k, _mm256_set1_pd(LN2_LO), c);
+        _mm256_fmadd_pd(k, _mm256_set1_pd(LN2_HI), _mm256_add_pd(f, lo))
+    }
+}
+
+/// Returns `(f + c) * (hi + lo)` keeping the rounding error of `f + c`.
+#[inline(always)]
+unsafe fn scale_ps(f: __m256, c: __m256, hi: f32, lo: f32) -> __m256 {
+    unsafe {
+        let t = _mm256_add_ps(f, c);
+        let err = _mm256_add_ps(_mm256_sub_ps(f, t), c);
+        let lo = _mm256_fmadd_ps(
+            t,
+            _mm256_set1_ps(lo),
+            _mm256_mul_ps(err, _mm256_set1_ps(hi)),
+        );
+        _mm256_fmadd_ps(t, _mm256_set1_ps(hi), lo)
+    }
+}
+
+/// Returns `(f + c) * (hi + lo)` keeping the rounding error of `f + c`.
+#[inline(always)]
+unsafe fn scale_pd(f: __m256d, c: __m256d, hi: f64, lo: f64) -> __m256d {
+    unsafe {
+        let t = _mm256_add_pd(f, c);
+        let err = _mm256_add_pd(_mm256_sub_pd(f, t), c);
+        let lo = _mm256_fmadd_pd(
+            t,
+            _mm256_set1_pd(lo),
+            _mm256_mul_pd(err, _mm256_set1_pd(hi)),
+        );
+        _mm256_fmadd_pd(t, _mm256_set1_pd(hi), lo)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_ps(x: __m256) -> __m256 {
+    unsafe {
+        let (k, f, c) = ln_parts_ps(x);
+        special_ps(x, combine_ln_ps(k, f, c))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let (k, f, c) = ln_parts_pd(x);
+        special_pd(x, combine_ln_pd(k, f, c))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_fast_ps(x: __m256) -> __m256 {
+    unsafe {
+        let (k, f, c) = ln_parts_fast_ps(x);
+        special_ps(x, combine_ln_ps(k, f, c))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_fast_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let (k, f, c) = ln_parts_fast_pd(x);
+        special_pd(x, combine_ln_pd(k, f, c))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn log2_ps(x: __m256) -> __m256 {
+    unsafe {
+        const LOG2_E_HI: f32 = core::f32::consts::LOG2_E;
+        const LOG2_E_LO: f32 = 1.925_963e-8;
+
+        let (k, f, c) = ln_parts_ps(x);
+        let result = _mm256_add_ps(k, scale_ps(f, c, LOG2_E_HI, LOG2_E_LO));
+        special_ps(x, result)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn log2_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const LOG2_E_HI: f64 = core::f64::consts::LOG2_E;
+        const LOG2_E_LO: f64 = 2.035_527_374_093_103_3e-17;
+
+        let (k, f, c) = ln_parts_pd(x);
+        let result = _mm256_add_pd(k, scale_pd(f, c, LOG2_E_HI, LOG2_E_LO));
+        special_pd(x, result)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn log10_ps(x: __m256) -> __m256 {
+    unsafe {
+        const LOG10_E_HI: f32 = core::f32::consts::LOG10_E;
+        const LOG10_E_LO: f32 = -1.010_305e-8;
+        const LOG10_2_HI: f32 = core::f32::consts::LOG10_2;
+        const LOG10_2_LO: f32 = -1.432_098_9e-8;
+
+        let (k, f, c) = ln_parts_ps(x);
+        let lo = _mm256_fmadd_ps(
+            k,
+            _mm256_set1_ps(LOG10_2_LO),
+            scale_ps(f, c, LOG10_E_HI, LOG10_E_LO),
+        );
+        let result = _mm256_fmadd_ps(k, _mm256_set1_ps(LOG10_2_HI), lo);
+        special_ps(x, result)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn log10_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const LOG10_E_HI: f64 = core::f64::consts::LOG10_E;
+        const LOG10_E_LO: f64 = 1.098_319_650_216_765e-17;
+        const LOG10_2_HI: f64 = core::f64::consts::LOG10_2;
+        const LOG10_2_LO: f64 = -2.803_728_127_785_170_4e-18;
+
+        let (k, f, c) = ln_parts_pd(x);
+        let lo = _mm256_fmadd_pd(
+            k,
+            _mm256_set1_pd(LOG10_2_LO),
+            scale_pd(f, c, LOG10_E_HI, LOG10_E_LO),
+        );
+        let result = _mm256_fmadd_pd(k, _mm256_set1_pd(LOG10_2_HI), lo);
+        special_pd(x, result)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_1p_ps(x: __m256) -> __m256 {
+    unsafe {
+        let one = _mm256_set1_ps(1.0);
+        let u = _mm256_add_ps(one, x);
+
+        // `(x - (u - 1)) / u` corrects for the rounding of `1 + x`.
+        let correction = _mm256_div_ps(_mm256_sub_ps(x, _mm256_sub_ps(u, one)), u);
+        let finite = _mm256_and_ps(
+            _mm256_cmp_ps(u, _mm256_setzero_ps(), _CMP_GT_OQ),
+            _mm256_cmp_ps(u, _mm256_set1_ps(f32::INFINITY), _CMP_LT_OQ),
+        );
+        let correction = _mm256_and_ps(correction, finite);
+
+        let (k, f, c) = ln_parts_ps(u);
+        let result = special_ps(u, combine_ln_ps(k, f, _mm256_add_ps(c, correction)));
+        _mm256_blendv_ps(result, x, _mm256_cmp_ps(u, one, _CMP_EQ_OQ))
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn ln_1p_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let one = _mm256_set1_pd(1.0);
+        let u = _mm256_add_pd(one, x);
+
+        // `(x - (u - 1)) / u` corrects for the rounding of `1 + x`.
+        let correction = _mm256_div_pd(_mm256_sub_pd(x, _mm256_sub_pd(u, one)), u);
+        let finite = _mm256_and_pd(
+            _mm256_cmp_pd(u, _mm256_setzero_pd(), _CMP_GT_OQ),
+            _mm256_cmp_pd(u, _mm256_set1_pd(f64::INFINITY), _CMP_LT_OQ),
+        );
+        let correction = _mm256_and_pd(correction, finite);
+
+        let (k, f, c) = ln_parts_pd(u);
+        let result = special_pd(u, combine_ln_pd(k, f, _mm256_add_pd(c, correction)));
+        _mm256_blendv_pd(result, x, _mm256_cmp_pd(u, one, _CMP_EQ_OQ))
+    }
+}
+
+/// Returns `ln(x)` as a double-double `(hi, lo)` accurate to about 2^-68, for positive finite `x`.
+/// Other lanes give garbage.
+#[inline(always)]
+pub(super) unsafe fn ln_dd_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const TWO_THIRDS_HI: f64 = 2.0 / 3.0;
+        const TWO_THIRDS_LO: f64 = 3.700_743_415_417_188e-17;
+        const C: [f64; 12] = [
+            2.0 / 5.0,
+            2.0 / 7.0,
+            2.0 / 9.0,
+            2.0 / 11.0,
+            2.0 / 13.0,
+            2.0 / 15.0,
+            2.0 / 17.0,
+            2.0 / 19.0,
+            2.0 / 21.0,
+            2.0 / 23.0,
+            2.0 / 25.0,
+            2.0 / 27.0,
+        ];
+
+        let (k, f) = split_pd(x);
+
+        // s = f / (2 + f) in double-double, `2 + f` itself may not be exact.
+        let two = _mm256_set1_pd(2.0);
+        let den = _mm256_add_pd(two, f);
+        let den_lo = _mm256_sub_pd(f, _mm256_sub_pd(den, two));
+        let s = _mm256_div_pd(f, den);
+        let rem = _mm256_fnmadd_pd(s, den, f);
+        let rem = _mm256_fnmadd_pd(s, den_lo, rem);
+        let s_lo = _mm256_div_pd(rem, den);
+
+        // ln(1 + f) = 2 * atanh(s) = 2s + 2s^3/3 + 2s^5/5 + ..., with 2s^3/3 in double-double.
+        let z = _mm256_mul_pd(s, s);
+        let z_lo = _mm256_fmsub_pd(s, s, z);
+        let s3 = _mm256_mul_pd(z, s);
+        let s3_lo = _mm256_fmadd_pd(z_lo, s, _mm256_fmsub_pd(z, s, s3));
+        let lead = _mm256_mul_pd(s3, _mm256_set1_pd(TWO_THIRDS_HI));
+        let lead_lo = _mm256_fmsub_pd(s3, _mm256_set1_pd(TWO_THIRDS_HI), lead);
+        let lead_lo = _mm256_fmadd_pd(s3_lo, _mm256_set1_pd(TWO_THIRDS_HI), lead_lo);
+        let lead_lo = _mm256_fmadd_pd(s3, _mm256_set1_pd(TWO_THIRDS_LO), lead_lo);
+
+        let mut poly = _mm256_set1_pd(C[11]);
+        for c in C[..11].iter().rev() {
+            poly = _mm256_fmadd_pd(poly, z, _mm256_set1_pd(*c));
+        }
+        let rest = _mm256_mul_pd(_mm256_mul_pd(s3, z), poly);
+
+        let (hi, e1) = two_sum_pd(
+            _mm256_mul_pd(k, _mm256_set1_pd(LN2_HI)),
+            _mm256_add_pd(s, s),
+        );
+        let (hi, e2) = two_sum_pd(hi, lead);
+        // The derivative of 2 * atanh(s) is 2 / (1 - s^2), about 2 + 2s^2.
+        let s_lo = _mm256_add_pd(s_lo, s_lo);
+        let lo = _mm256_add_pd(_mm256_add_pd(e1, e2), _mm256_fmadd_pd(s_lo, z, s_lo));
+        let lo = _mm256_add_pd(lo, _mm256_add_pd(lead_lo, rest));
+        let lo = _mm256_fmadd_pd(k, _mm256_set1_pd(LN2_LO), lo);
+
+        let result = _mm256_add_pd(hi, lo);
+        (result, _mm256_sub_pd(lo, _mm256_sub_pd(result, hi)))
+    }
+}
+
+/// Returns `ln(x + x_lo)` as a double-double for a positive finite double-double `x + x_lo`.
+#[inline(always)]
+pub(super) unsafe fn ln_of_dd_pd(x: __m256d, x_lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let (l, l_lo) = ln_dd_pd(x);
+        (l, _mm256_add_pd(l_lo, _mm256_div_pd(x_lo, x)))
+    }
+}
@@ -0,0 +1,73 @@
+use super::exp::exp_dd_pd;
+use super::log::{ln_dd_pd, special_pd};
+use core::arch::x86_64::*;
+
+#[inline(always)]
+pub(super) unsafe fn powf_pd(x: __m256d, y: __m256d) -> __m256d {
+    unsafe {
+        let sign = _mm256_set1_pd(-0.0);
+        let one = _mm256_set1_pd(1.0);
+        let inf = _mm256_set1_pd(f64::INFINITY);
+        let ax = _mm256_andnot_pd(sign, x);
+
+        // |x|^y = e^(y * ln|x|), with ln|x| and the product kept in double-double.
+        let (l, l_lo) = ln_dd_pd(ax);
+        let finite = _mm256_and_pd(
+            _mm256_cmp_pd(ax, _mm256_setzero_pd(), _CMP_GT_OQ),
+            _mm256_cmp_pd(ax, inf, _CMP_LT_OQ),
+        );
+        let l = special_pd(ax, l);
+        let l_lo = _mm256_and_pd(l_lo, finite);
+        let p = _mm256_mul_pd(y, l);
+        let p_lo = _mm256_fmadd_pd(y, l_lo, _mm256_fmsub_pd(y, l, p));
+        // The result has already overflowed or underflowed once |p| reaches 1024, where `p_lo`
+        // may not be finite or small.
+        let p_lo = _mm256_and_pd(
+            p_lo,
+            _mm256_cmp_pd(
+                _mm256_andnot_pd(sign, p),
+                _mm256_set1_pd(1024.0),
+                _CMP_LT_OQ,
+            ),
+        );
+        let result = exp_dd_pd(p, p_lo);
+
+        // Negative `x` gives a negative result for odd integral `y`, and NaN for finite
+        // non-integral `y`.
+        let rounded = _mm256_round_pd(y, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC);
+        let integral = _mm256_cmp_pd(rounded, y, _CMP_EQ_OQ);
+        let half = _mm256_mul_pd(y, _mm256_set1_pd(0.5));
+        let odd = _mm256_and_pd(
+            integral,
+            _mm256_cmp_pd(
+                _mm256_round_pd(half, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC),
+                half,
+                _CMP_NEQ_OQ,
+            ),
+        );
+        let result = _mm256_xor_pd(result, _mm256_and_pd(_mm256_and_pd(x, sign), odd));
+        let negative_finite = _mm256_and_pd(
+            _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_LT_OQ),
+            _mm256_cmp_pd(x, _mm256_set1_pd(f64::NEG_INFINITY), _CMP_GT_OQ),
+        );
+        let result = _mm256_blendv_pd(
+            result,
+            _mm256_set1_pd(f64::NAN),
+            _mm256_andnot_pd(integral, negative_finite),
+        );
+
+        // x^0, 1^y and (-1)^inf are 1, even for NaN.
+        let unit = _mm256_or_pd(
+            _mm256_cmp_pd(y, _mm256_setzero_pd(), _CMP_EQ_OQ),
+            _mm256_cmp_pd(x, one, _CMP_EQ_OQ),
+        );
+        let unit = _mm256_or_pd(
+            unit,
+            _mm256_and_pd(
+                _mm256_cmp_pd(ax, one, _CMP_EQ_OQ),
+                _mm256_cmp_pd(_mm256_andnot_pd(sign, y), inf, _CMP_EQ_OQ),
+            ),
+        );
+        _mm256_blendv_pd(result, one, unit)
+    }
+}
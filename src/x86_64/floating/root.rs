@@ -0,0 +1,162 @@
+use super::two_sum_pd;
+use core::arch::x86_64::*;
+
+#[inline(always)]
+pub(super) unsafe fn cbrt_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const MAGIC: f64 = 4_503_599_627_370_496.0;
+        const CBRT_2: f64 = 1.259_921_049_894_873_2;
+        const CBRT_4: f64 = 1.587_401_051_968_199_6;
+        // cbrt(1.5 + u) on [-0.5, 0.5], within 1.4e-5.
+        const C: [f64; 5] = [
+            1.144_714_2,
+            2.542_836_6e-1,
+            -5.647_832_5e-2,
+            2.246_576_1e-2,
+            -1.010_221_2e-2,
+        ];
+
+        let one = _mm256_set1_pd(1.0);
+        let third = _mm256_set1_pd(1.0 / 3.0);
+        let a = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+        let finite_nonzero = _mm256_and_pd(
+            _mm256_cmp_pd(a, _mm256_setzero_pd(), _CMP_GT_OQ),
+            _mm256_cmp_pd(a, _mm256_set1_pd(f64::INFINITY), _CMP_LT_OQ),
+        );
+
+        // Subnormals are scaled into the normal range first, by a power of 2 with an integer
+        // cube root.
+        let tiny = _mm256_cmp_pd(a, _mm256_set1_pd(f64::MIN_POSITIVE), _CMP_LT_OQ);
+        let a = _mm256_blendv_pd(
+            a,
+            _mm256_mul_pd(a, _mm256_set1_pd(18_014_398_509_481_984.0)),
+            tiny,
+        );
+
+        // a = 2^(3q + r) * m with 1 <= m < 2 and r in {0, 1, 2}.
+        let ix = _mm256_castpd_si256(a);
+        let e = _mm256_sub_pd(
+            _mm256_castsi256_pd(_mm256_or_si256(
+                _mm256_srli_epi64(ix, 52),
+                _mm256_castpd_si256(_mm256_set1_pd(MAGIC)),
+            )),
+            _mm256_set1_pd(MAGIC + 1023.0),
+        );
+        let q = _mm256_round_pd(
+            _mm256_mul_pd(_mm256_add_pd(e, _mm256_set1_pd(0.5)), third),
+            _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC,
+        );
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(3.0), e);
+        let m = _mm256_castsi256_pd(_mm256_or_si256(
+            _mm256_and_si256(ix, _mm256_set1_epi64x(0x000f_ffff_ffff_ffff)),
+            _mm256_castpd_si256(one),
+        ));
+
+        let u = _mm256_sub_pd(m, _mm256_set1_pd(1.5));
+        let mut y = _mm256_set1_pd(C[4]);
+        for c in C[..4].iter().rev() {
+            y = _mm256_fmadd_pd(y, u, _mm256_set1_pd(*c));
+        }
+
+        // Solve y^3 = m * 2^r, which is exact and in [1, 8).
+        let r1 = _mm256_cmp_pd(r, one, _CMP_GE_OQ);
+        let r2 = _mm256_cmp_pd(r, _mm256_set1_pd(2.0), _CMP_GE_OQ);
+        let m = _mm256_mul_pd(
+            m,
+            _mm256_blendv_pd(
+                _mm256_blendv_pd(one, _mm256_set1_pd(2.0), r1),
+                _mm256_set1_pd(4.0),
+                r2,
+            ),
+        );
+        let y = _mm256_mul_pd(
+            y,
+            _mm256_blendv_pd(
+                _mm256_blendv_pd(one, _mm256_set1_pd(CBRT_2), r1),
+                _mm256_set1_pd(CBRT_4),
+                r2,
+            ),
+        );
+
+        // A plain Newton-Raphson step, then one with the residual y^3 - m computed exactly enough
+        // that only the final addition rounds.
+        let y = _mm256_mul_pd(
+            _mm256_fmadd_pd(
+                _mm256_set1_pd(2.0),
+                y,
+                _mm256_div_pd(m, _mm256_mul_pd(y, y)),
+            ),
+            third,
+        );
+        let y2 = _mm256_mul_pd(y, y);
+        let y2_lo = _mm256_fmsub_pd(y, y, y2);
+        let residual = _mm256_fmadd_pd(y2_lo, y, _mm256_fmsub_pd(y2, y, m));
+        let y = _mm256_sub_pd(
+            y,
+            _mm256_div_pd(residual, _mm256_mul_pd(_mm256_set1_pd(3.0), y2)),
+        );
+
+        // Multiply by 2^q, undoing the subnormal scaling of 2^54 = (2^18)^3.
+        let q = _mm256_sub_pd(q, _mm256_and_pd(tiny, _mm256_set1_pd(18.0)));
+        let scale = _mm256_castsi256_pd(_mm256_slli_epi64(
+            _mm256_castpd_si256(_mm256_add_pd(q, _mm256_set1_pd(MAGIC + 1023.0))),
+            52,
+        ));
+        let result = _mm256_or_pd(
+            _mm256_mul_pd(y, scale),
+            _mm256_and_pd(x, _mm256_set1_pd(-0.0)),
+        );
+
+        // Zeros, infinities and NaN are their own cube roots.
+        _mm256_blendv_pd(x, result, finite_nonzero)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn hypot_pd(x: __m256d, y: __m256d) -> __m256d {
+    unsafe {
+        let sign = _mm256_set1_pd(-0.0);
+        let a = _mm256_andnot_pd(sign, x);
+        let b = _mm256_andnot_pd(sign, y);
+        let big = _mm256_max_pd(a, b);
+
+        // Scale by a power of 2 so that neither square overflows or loses accuracy as a
+        // subnormal.
+        let small = _mm256_set1_pd(f64::from_bits(0x20b0_0000_0000_0000)); // 2^-500
+        let large = _mm256_set1_pd(f64::from_bits(0x5f30_0000_0000_0000)); // 2^500
+        let up = _mm256_set1_pd(f64::from_bits(0x6570_0000_0000_0000)); // 2^600
+        let down = _mm256_set1_pd(f64::from_bits(0x1a70_0000_0000_0000)); // 2^-600
+        let is_small = _mm256_cmp_pd(big, small, _CMP_LT_OQ);
+        let is_large = _mm256_cmp_pd(big, large, _CMP_GT_OQ);
+        let one = _mm256_set1_pd(1.0);
+        let scale = _mm256_blendv_pd(_mm256_blendv_pd(one, up, is_small), down, is_large);
+        let unscale = _mm256_blendv_pd(_mm256_blendv_pd(one, down, is_small), up, is_large);
+        let a = _mm256_mul_pd(a, scale);
+        let b = _mm256_mul_pd(b, scale);
+
+        // a^2 + b^2 in double-double, and a correction of its square root.
+        let a2 = _mm256_mul_pd(a, a);
+        let b2 = _mm256_mul_pd(b, b);
+        let (s, s_lo) = two_sum_pd(a2, b2);
+        let s_lo = _mm256_add_pd(
+            s_lo,
+            _mm256_add_pd(_mm256_fmsub_pd(a, a, a2), _mm256_fmsub_pd(b, b, b2)),
+        );
+        let h = _mm256_sqrt_pd(s);
+        let correction = _mm256_div_pd(
+            _mm256_add_pd(_mm256_fnmadd_pd(h, h, s), s_lo),
+            _mm256_add_pd(h, h),
+        );
+        let nonzero = _mm256_cmp_pd(h, _mm256_setzero_pd(), _CMP_GT_OQ);
+        let h = _mm256_add_pd(h, _mm256_and_pd(correction, nonzero));
+        let h = _mm256_mul_pd(h, unscale);
+
+        // An infinite side gives infinity even if the other is NaN.
+        let inf = _mm256_set1_pd(f64::INFINITY);
+        let infinite = _mm256_or_pd(
+            _mm256_cmp_pd(a, inf, _CMP_EQ_OQ),
+            _mm256_cmp_pd(b, inf, _CMP_EQ_OQ),
+        );
+        _mm256_blendv_pd(h, inf, infinite)
+    }
+}
@@ -0,0 +1,498 @@
+use super::two_sum_pd;
+use core::arch::x86_64::*;
+
+/// Largest `|x|` handled by the vector path, larger lanes are patched with the scalar functions.
+const LIMIT: f64 = 1_048_576.0;
+
+/// `pi/2` split into parts of 33 bits, so that `q * PIO2_n` is exact for `|q| < 2^20`.
+const PIO2_1: f64 = 1.570_796_326_734_125_6;
+const PIO2_2: f64 = 6.077_100_506_303_966e-11;
+const PIO2_3: f64 = 2.022_266_248_711_166_5e-21;
+const PIO2_3T: f64 = 8.478_427_660_368_9e-32;
+
+/// Replaces the lanes of `sin` and `cos` set in the bitmask `large` with the scalar functions of
+/// the lanes of `x`.
+#[inline(never)]
+unsafe fn patch_sincos_ps(x: __m256, large: i32, sin: __m256, cos: __m256) -> (__m256, __m256) {
+    unsafe {
+        let mut x_buff = [0.0f32; 8];
+        let mut sin_buff = [0.0f32; 8];
+        let mut cos_buff = [0.0f32; 8];
+        _mm256_storeu_ps(x_buff.as_mut_ptr(), x);
+        _mm256_storeu_ps(sin_buff.as_mut_ptr(), sin);
+        _mm256_storeu_ps(cos_buff.as_mut_ptr(), cos);
+
+        let mut large = large;
+        while large != 0 {
+            let i = large.trailing_zeros() as usize;
+            (sin_buff[i], cos_buff[i]) = x_buff[i].sin_cos();
+            large &= large - 1;
+        }
+
+        (
+            _mm256_loadu_ps(sin_buff.as_ptr()),
+            _mm256_loadu_ps(cos_buff.as_ptr()),
+        )
+    }
+}
+
+/// Replaces the lanes of `tan` set in the bitmask `large` with the scalar function of the lanes
+/// of `x`.
+#[inline(never)]
+unsafe fn patch_tan_ps(x: __m256, large: i32, tan: __m256) -> __m256 {
+    unsafe {
+        let mut x_buff = [0.0f32; 8];
+        let mut tan_buff = [0.0f32; 8];
+        _mm256_storeu_ps(x_buff.as_mut_ptr(), x);
+        _mm256_storeu_ps(tan_buff.as_mut_ptr(), tan);
+
+        let mut large = large;
+        while large != 0 {
+            let i = large.trailing_zeros() as usize;
+            tan_buff[i] = x_buff[i].tan();
+            large &= large - 1;
+        }
+
+        _mm256_loadu_ps(tan_buff.as_ptr())
+    }
+}
+
+/// Replaces the lanes of `sin` and `cos` set in the bitmask `large` with the scalar functions of
+/// the lanes of `x`.
+#[inline(never)]
+unsafe fn patch_sincos_pd(
+    x: __m256d,
+    large: i32,
+    sin: __m256d,
+    cos: __m256d,
+) -> (__m256d, __m256d) {
+    unsafe {
+        let mut x_buff = [0.0f64; 4];
+        let mut sin_buff = [0.0f64; 4];
+        let mut cos_buff = [0.0f64; 4];
+        _mm256_storeu_pd(x_buff.as_mut_ptr(), x);
+        _mm256_storeu_pd(sin_buff.as_mut_ptr(), sin);
+        _mm256_storeu_pd(cos_buff.as_mut_ptr(), cos);
+
+        let mut large = large;
+        while large != 0 {
+            let i = large.trailing_zeros() as usize;
+            (sin_buff[i], cos_buff[i]) = x_buff[i].sin_cos();
+            large &= large - 1;
+        }
+
+        (
+            _mm256_loadu_pd(sin_buff.as_ptr()),
+            _mm256_loadu_pd(cos_buff.as_ptr()),
+        )
+    }
+}
+
+/// Returns `(sin(x), cos(x))` in `f64` for 4 `f32` lanes with `|x| <= LIMIT` or NaN. Working in
+/// `f64` means the result is only rounded once, when it is narrowed back to `f32`.
+#[inline(always)]
+unsafe fn sincos_half_ps(x: __m128) -> (__m256d, __m256d) {
+    unsafe {
+        const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+        const PIO2_LO: f64 = 6.123_233_995_736_766e-17;
+        const S1: f64 = -1.666_666_664_162_652_4e-1;
+        const S2: f64 = 8.333_329_385_889_463e-3;
+        const S3: f64 = -1.983_933_483_609_663_2e-4;
+        const S4: f64 = 2.718_311_493_989_822e-6;
+        const C0: f64 = -4.999_999_972_510_31e-1;
+        const C1: f64 = 4.166_662_332_373_906e-2;
+        const C2: f64 = -1.388_676_377_460_993e-3;
+        const C3: f64 = 2.439_044_879_627_741e-5;
+
+        let x = _mm256_cvtps_pd(x);
+        let q = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::FRAC_2_PI)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_HI), x);
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_LO), r);
+
+        let z = _mm256_mul_pd(r, r);
+        let w = _mm256_mul_pd(z, z);
+
+        let v = _mm256_mul_pd(z, r);
+        let sin = _mm256_fmadd_pd(z, _mm256_set1_pd(S2), _mm256_set1_pd(S1));
+        let sin = _mm256_fmadd_pd(v, sin, r);
+        let tail = _mm256_fmadd_pd(z, _mm256_set1_pd(S4), _mm256_set1_pd(S3));
+        let sin = _mm256_fmadd_pd(_mm256_mul_pd(v, w), tail, sin);
+
+        let cos = _mm256_fmadd_pd(z, _mm256_set1_pd(C0), _mm256_set1_pd(1.0));
+        let cos = _mm256_fmadd_pd(w, _mm256_set1_pd(C1), cos);
+        let tail = _mm256_fmadd_pd(z, _mm256_set1_pd(C3), _mm256_set1_pd(C2));
+        let cos = _mm256_fmadd_pd(_mm256_mul_pd(w, z), tail, cos);
+
+        quadrant_pd(x, q, sin, cos)
+    }
+}
+
+/// Returns `(sin(x), cos(x))` for `|x| <= LIMIT` and NaN.
+#[inline(always)]
+unsafe fn sincos_kernel_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        let (sin_lo, cos_lo) = sincos_half_ps(_mm256_castps256_ps128(x));
+        let (sin_hi, cos_hi) = sincos_half_ps(_mm256_extractf128_ps(x, 1));
+        (
+            _mm256_set_m128(_mm256_cvtpd_ps(sin_hi), _mm256_cvtpd_ps(sin_lo)),
+            _mm256_set_m128(_mm256_cvtpd_ps(cos_hi), _mm256_cvtpd_ps(cos_lo)),
+        )
+    }
+}
+
+/// Returns `tan(x)` for `|x| <= LIMIT` and NaN.
+#[inline(always)]
+unsafe fn tan_kernel_ps(x: __m256) -> __m256 {
+    unsafe {
+        let (sin_lo, cos_lo) = sincos_half_ps(_mm256_castps256_ps128(x));
+        let (sin_hi, cos_hi) = sincos_half_ps(_mm256_extractf128_ps(x, 1));
+        _mm256_set_m128(
+            _mm256_cvtpd_ps(_mm256_div_pd(sin_hi, cos_hi)),
+            _mm256_cvtpd_ps(_mm256_div_pd(sin_lo, cos_lo)),
+        )
+    }
+}
+
+/// Maps `sin(r)` and `cos(r)` to `sin(x)` and `cos(x)` for `x = q * pi/2 + r`.
+#[inline(always)]
+unsafe fn quadrant_pd(x: __m256d, q: __m256d, sin: __m256d, cos: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let q = _mm256_cvtepi32_epi64(_mm256_cvtpd_epi32(q));
+
+        // Odd quadrants swap sin and cos, and the sign follows the quadrant.
+        let one = _mm256_set1_epi64x(1);
+        let two = _mm256_set1_epi64x(2);
+        let swap = _mm256_castsi256_pd(_mm256_cmpeq_epi64(_mm256_and_si256(q, one), one));
+        let sin_sign = _mm256_castsi256_pd(_mm256_slli_epi64(_mm256_and_si256(q, two), 62));
+        let cos_sign = _mm256_castsi256_pd(_mm256_slli_epi64(
+            _mm256_and_si256(_mm256_add_epi64(q, one), two),
+            62,
+        ));
+
+        let sin_x = _mm256_xor_pd(_mm256_blendv_pd(sin, cos, swap), sin_sign);
+        let cos_x = _mm256_xor_pd(_mm256_blendv_pd(cos, sin, swap), cos_sign);
+
+        // Keeps the sign of zero, which the reduction loses.
+        let zero = _mm256_cmp_pd(x, _mm256_setzero_pd(), _CMP_EQ_OQ);
+        (_mm256_blendv_pd(sin_x, x, zero), cos_x)
+    }
+}
+
+/// Returns `(sin(r + lo), cos(r + lo))` for `|r| <= pi/4` and `lo` much smaller than `r`.
+#[inline(always)]
+unsafe fn sincos_poly_pd(r: __m256d, lo: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const S1: f64 = -1.666_666_666_666_663_2e-1;
+        const S2: f64 = 8.333_333_333_322_49e-3;
+        const S3: f64 = -1.984_126_982_985_795e-4;
+        const S4: f64 = 2.755_731_370_707_006_8e-6;
+        const S5: f64 = -2.505_076_025_340_686_3e-8;
+        const S6: f64 = 1.589_690_995_211_55e-10;
+        const C1: f64 = 4.166_666_666_666_66e-2;
+        const C2: f64 = -1.388_888_888_887_411e-3;
+        const C3: f64 = 2.480_158_728_947_673e-5;
+        const C4: f64 = -2.755_731_435_139_066_3e-7;
+        const C5: f64 = 2.087_572_321_298_175e-9;
+        const C6: f64 = -1.135_964_755_778_819_5e-11;
+
+        let half = _mm256_set1_pd(0.5);
+        let one = _mm256_set1_pd(1.0);
+        let z = _mm256_mul_pd(r, r);
+        let w = _mm256_mul_pd(z, z);
+
+        let v = _mm256_mul_pd(z, r);
+        let p = _mm256_fmadd_pd(z, _mm256_set1_pd(S6), _mm256_set1_pd(S5));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S4));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S3));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S2));
+        let sin = _mm256_mul_pd(z, _mm256_fnmadd_pd(v, p, _mm256_mul_pd(half, lo)));
+        let sin = _mm256_fnmadd_pd(v, _mm256_set1_pd(S1), _mm256_sub_pd(sin, lo));
+        let sin = _mm256_sub_pd(r, sin);
+
+        let p1 = _mm256_fmadd_pd(z, _mm256_set1_pd(C3), _mm256_set1_pd(C2));
+        let p1 = _mm256_mul_pd(z, _mm256_fmadd_pd(z, p1, _mm256_set1_pd(C1)));
+        let p2 = _mm256_fmadd_pd(z, _mm256_set1_pd(C6), _mm256_set1_pd(C5));
+        let p2 = _mm256_fmadd_pd(z, p2, _mm256_set1_pd(C4));
+        let p = _mm256_fmadd_pd(_mm256_mul_pd(w, w), p2, p1);
+        let hz = _mm256_mul_pd(half, z);
+        let w = _mm256_sub_pd(one, hz);
+        let tail = _mm256_fmsub_pd(z, p, _mm256_mul_pd(r, lo));
+        let cos = _mm256_add_pd(
+            w,
+            _mm256_add_pd(_mm256_sub_pd(_mm256_sub_pd(one, w), hz), tail),
+        );
+
+        (sin, cos)
+    }
+}
+
+/// Returns `(sin(x), cos(x))` for `|x| <= LIMIT` and NaN.
+#[inline(always)]
+unsafe fn sincos_kernel_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let q = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::FRAC_2_PI)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+
+        // Each part of pi/2 has 33 bits, so the products are exact and `r + lo` is
+        // `x - q * pi/2` in double-double.
+        let a = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_1), x);
+        let (s, e1) = two_sum_pd(a, _mm256_mul_pd(q, _mm256_set1_pd(-PIO2_2)));
+        let (hi, e2) = two_sum_pd(s, _mm256_mul_pd(q, _mm256_set1_pd(-PIO2_3)));
+        let lo = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_3T), _mm256_add_pd(e1, e2));
+        let r = _mm256_add_pd(hi, lo);
+        let lo = _mm256_sub_pd(lo, _mm256_sub_pd(r, hi));
+        let (sin, cos) = sincos_poly_pd(r, lo);
+        quadrant_pd(x, q, sin, cos)
+    }
+}
+
+/// Returns `|sin(pi * x)|` for finite `x`. Unlike `sin` of the rounded product, it is exactly zero
+/// at integers and keeps its relative accuracy next to them.
+#[inline(always)]
+pub(super) unsafe fn sinpi_abs_pd(x: __m256d) -> __m256d {
+    unsafe {
+        const PI_LO: f64 = 1.224_646_799_147_353_2e-16;
+
+        // |sin(pi * x)| = sin(pi * r) = cos(pi * (1/2 - r)) for the exact r = |x - round(x)|.
+        let r = _mm256_andnot_pd(
+            _mm256_set1_pd(-0.0),
+            _mm256_sub_pd(
+                x,
+                _mm256_round_pd(x, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC),
+            ),
+        );
+        let upper = _mm256_cmp_pd(r, _mm256_set1_pd(0.25), _CMP_GT_OQ);
+        let r = _mm256_blendv_pd(r, _mm256_sub_pd(_mm256_set1_pd(0.5), r), upper);
+        let pi = _mm256_set1_pd(core::f64::consts::PI);
+        let hi = _mm256_mul_pd(pi, r);
+        let lo = _mm256_fmadd_pd(_mm256_set1_pd(PI_LO), r, _mm256_fmsub_pd(pi, r, hi));
+        let (sin, cos) = sincos_poly_pd(hi, lo);
+        _mm256_blendv_pd(sin, cos, upper)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn sincos_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        let abs = _mm256_andnot_ps(_mm256_set1_ps(-0.0), x);
+        let large = _mm256_cmp_ps(abs, _mm256_set1_ps(LIMIT as f32), _CMP_GT_OQ);
+        let large_bits = _mm256_movemask_ps(large);
+        if large_bits != 0 {
+            // The kernel gets zero in place of the large lanes, which are replaced afterwards.
+            let (sin, cos) = sincos_kernel_ps(_mm256_andnot_ps(large, x));
+            patch_sincos_ps(x, large_bits, sin, cos)
+        } else {
+            sincos_kernel_ps(x)
+        }
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn sincos_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let abs = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+        let large = _mm256_cmp_pd(abs, _mm256_set1_pd(LIMIT), _CMP_GT_OQ);
+        let large_bits = _mm256_movemask_pd(large);
+        if large_bits != 0 {
+            // The kernel gets zero in place of the large lanes, which are replaced afterwards.
+            let (sin, cos) = sincos_kernel_pd(_mm256_andnot_pd(large, x));
+            patch_sincos_pd(x, large_bits, sin, cos)
+        } else {
+            sincos_kernel_pd(x)
+        }
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tan_ps(x: __m256) -> __m256 {
+    unsafe {
+        let abs = _mm256_andnot_ps(_mm256_set1_ps(-0.0), x);
+        let large = _mm256_cmp_ps(abs, _mm256_set1_ps(LIMIT as f32), _CMP_GT_OQ);
+        let large_bits = _mm256_movemask_ps(large);
+        if large_bits != 0 {
+            // The kernel gets zero in place of the large lanes, which are replaced afterwards.
+            let tan = tan_kernel_ps(_mm256_andnot_ps(large, x));
+            patch_tan_ps(x, large_bits, tan)
+        } else {
+            tan_kernel_ps(x)
+        }
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn tan_pd(x: __m256d) -> __m256d {
+    unsafe {
+        let (sin, cos) = sincos_pd(x);
+        _mm256_div_pd(sin, cos)
+    }
+}
+
+/// Largest `|x|` handled by the `f32` fast path, larger lanes use the accurate one.
+const FAST_LIMIT: f32 = 65_536.0;
+
+/// Maps `sin(r)` and `cos(r)` to `sin(x)` and `cos(x)` for `x = q * pi/2 + r`.
+#[inline(always)]
+unsafe fn quadrant_ps(x: __m256, q: __m256, sin: __m256, cos: __m256) -> (__m256, __m256) {
+    unsafe {
+        let q = _mm256_cvtps_epi32(q);
+
+        // Odd quadrants swap sin and cos, and the sign follows the quadrant.
+        let one = _mm256_set1_epi32(1);
+        let two = _mm256_set1_epi32(2);
+        let swap = _mm256_castsi256_ps(_mm256_cmpeq_epi32(_mm256_and_si256(q, one), one));
+        let sin_sign = _mm256_castsi256_ps(_mm256_slli_epi32(_mm256_and_si256(q, two), 30));
+        let cos_sign = _mm256_castsi256_ps(_mm256_slli_epi32(
+            _mm256_and_si256(_mm256_add_epi32(q, one), two),
+            30,
+        ));
+
+        let sin_x = _mm256_xor_ps(_mm256_blendv_ps(sin, cos, swap), sin_sign);
+        let cos_x = _mm256_xor_ps(_mm256_blendv_ps(cos, sin, swap), cos_sign);
+
+        // Keeps the sign of zero, which the reduction loses.
+        let zero = _mm256_cmp_ps(x, _mm256_setzero_ps(), _CMP_EQ_OQ);
+        (_mm256_blendv_ps(sin_x, x, zero), cos_x)
+    }
+}
+
+/// Returns `(sin(x), cos(x))` computed in `f32`, for `|x| <= FAST_LIMIT` and NaN.
+#[inline(always)]
+unsafe fn sincos_fast_kernel_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        const PIO2_1: f32 = core::f32::consts::FRAC_PI_2;
+        const PIO2_2: f32 = -4.371_139e-8;
+        const PIO2_3: f32 = -1.715_124_5e-15;
+        const S1: f32 = -1.666_666_7e-1;
+        const S2: f32 = 8.333_329e-3;
+        const S3: f32 = -1.983_933_5e-4;
+        const S4: f32 = 2.718_311_4e-6;
+        const C0: f32 = -0.5;
+        const C1: f32 = 4.166_662_3e-2;
+        const C2: f32 = -1.388_676_3e-3;
+        const C3: f32 = 2.439_045e-5;
+
+        let q = _mm256_round_ps(
+            _mm256_mul_ps(x, _mm256_set1_ps(core::f32::consts::FRAC_2_PI)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+
+        // The first step is exact since `x` and `q * PIO2_1` are both multiples of the ULP of the
+        // result.
+        let r = _mm256_fnmadd_ps(q, _mm256_set1_ps(PIO2_1), x);
+        let r = _mm256_fnmadd_ps(q, _mm256_set1_ps(PIO2_2), r);
+        let r = _mm256_fnmadd_ps(q, _mm256_set1_ps(PIO2_3), r);
+        let z = _mm256_mul_ps(r, r);
+        let w = _mm256_mul_ps(z, z);
+
+        let p = _mm256_fmadd_ps(z, _mm256_set1_ps(S4), _mm256_set1_ps(S3));
+        let p = _mm256_fmadd_ps(
+            w,
+            p,
+            _mm256_fmadd_ps(z, _mm256_set1_ps(S2), _mm256_set1_ps(S1)),
+        );
+        let sin = _mm256_fmadd_ps(_mm256_mul_ps(z, r), p, r);
+
+        let p = _mm256_fmadd_ps(z, _mm256_set1_ps(C3), _mm256_set1_ps(C2));
+        let cos = _mm256_fmadd_ps(z, _mm256_set1_ps(C0), _mm256_set1_ps(1.0));
+        let cos = _mm256_fmadd_ps(w, _mm256_set1_ps(C1), cos);
+        let cos = _mm256_fmadd_ps(_mm256_mul_ps(w, z), p, cos);
+
+        quadrant_ps(x, q, sin, cos)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn sincos_fast_ps(x: __m256) -> (__m256, __m256) {
+    unsafe {
+        let abs = _mm256_andnot_ps(_mm256_set1_ps(-0.0), x);
+        let large = _mm256_cmp_ps(abs, _mm256_set1_ps(FAST_LIMIT), _CMP_GT_OQ);
+        if _mm256_movemask_ps(large) != 0 {
+            // Only the large lanes are taken from the accurate path.
+            let (sin, cos) = sincos_fast_kernel_ps(_mm256_andnot_ps(large, x));
+            let (sin_large, cos_large) = sincos_ps(x);
+            (
+                _mm256_blendv_ps(sin, sin_large, large),
+                _mm256_blendv_ps(cos, cos_large, large),
+            )
+        } else {
+            sincos_fast_kernel_ps(x)
+        }
+    }
+}
+
+/// Returns `(sin(x), cos(x))` for `|x| <= LIMIT` and NaN, without carrying the reduced argument
+/// in double-double.
+#[inline(always)]
+unsafe fn sincos_fast_kernel_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        const PIO2_HI: f64 = core::f64::consts::FRAC_PI_2;
+        const PIO2_MID: f64 = 6.123_233_995_736_766e-17;
+        const PIO2_LO: f64 = -1.497_384_904_859_169_8e-33;
+        const S1: f64 = -1.666_666_666_666_663_2e-1;
+        const S2: f64 = 8.333_333_333_322_49e-3;
+        const S3: f64 = -1.984_126_982_985_795e-4;
+        const S4: f64 = 2.755_731_370_707_006_8e-6;
+        const S5: f64 = -2.505_076_025_340_686_3e-8;
+        const S6: f64 = 1.589_690_995_211_55e-10;
+        const C1: f64 = 4.166_666_666_666_66e-2;
+        const C2: f64 = -1.388_888_888_887_411e-3;
+        const C3: f64 = 2.480_158_728_947_673e-5;
+        const C4: f64 = -2.755_731_435_139_066_3e-7;
+        const C5: f64 = 2.087_572_321_298_175e-9;
+        const C6: f64 = -1.135_964_755_778_819_5e-11;
+
+        let q = _mm256_round_pd(
+            _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::FRAC_2_PI)),
+            _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC,
+        );
+
+        // The first step is exact since `x` and `q * PIO2_HI` are both multiples of the ULP of
+        // the result, which keeps the reduced argument within about an ULP.
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_HI), x);
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_MID), r);
+        let r = _mm256_fnmadd_pd(q, _mm256_set1_pd(PIO2_LO), r);
+        let z = _mm256_mul_pd(r, r);
+        let w = _mm256_mul_pd(z, z);
+
+        let p = _mm256_fmadd_pd(z, _mm256_set1_pd(S6), _mm256_set1_pd(S5));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S4));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S3));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S2));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(S1));
+        let sin = _mm256_fmadd_pd(_mm256_mul_pd(z, r), p, r);
+
+        let p = _mm256_fmadd_pd(z, _mm256_set1_pd(C6), _mm256_set1_pd(C5));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(C4));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(C3));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(C2));
+        let p = _mm256_fmadd_pd(z, p, _mm256_set1_pd(C1));
+        let cos = _mm256_fmadd_pd(z, _mm256_set1_pd(-0.5), _mm256_set1_pd(1.0));
+        let cos = _mm256_fmadd_pd(w, p, cos);
+
+        quadrant_pd(x, q, sin, cos)
+    }
+}
+
+#[inline(always)]
+pub(super) unsafe fn sincos_fast_pd(x: __m256d) -> (__m256d, __m256d) {
+    unsafe {
+        let abs = _mm256_andnot_pd(_mm256_set1_pd(-0.0), x);
+        let large = _mm256_cmp_pd(abs, _mm256_set1_pd(LIMIT), _CMP_GT_OQ);
+        if _mm256_movemask_pd(large) != 0 {
+            // Only the large lanes are taken from the accurate path.
+            let (sin, cos) = sincos_fast_kernel_pd(_mm256_andnot_pd(large, x));
+            let (sin_large, cos_large) = sincos_pd(x);
+            (
+                _mm256_blendv_pd(sin, sin_large, large),
+                _mm256_blendv_pd(cos, cos_large, large),
+            )
+        } else {
+            sincos_fast_kernel_pd(x)
+        }
+    }
+}
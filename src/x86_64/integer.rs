@@ -1,4 +1,4 @@
-use crate::SimdIntegerElement;
+use crate::*;
 use core::arch::x86_64::*;
 
 unsafe impl SimdIntegerElement for i8 {
@@ -24,6 +24,64 @@ unsafe impl SimdIntegerElement for i8 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi8(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, result),
+                _mm256_xor_si256(right, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi8(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, right),
+                _mm256_xor_si256(left, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let low_mask = _mm256_set1_epi16(0x00ff);
+            let left_even = _mm256_srai_epi16(_mm256_slli_epi16(left, 8), 8);
+            let right_even = _mm256_srai_epi16(_mm256_slli_epi16(right, 8), 8);
+            let left_odd = _mm256_srai_epi16(left, 8);
+            let right_odd = _mm256_srai_epi16(right, 8);
+
+            let even = _mm256_mullo_epi16(left_even, right_even);
+            let odd = _mm256_mullo_epi16(left_odd, right_odd);
+            let result =
+                _mm256_or_si256(_mm256_and_si256(even, low_mask), _mm256_slli_epi16(odd, 8));
+
+            let even_fits =
+                _mm256_cmpeq_epi16(even, _mm256_srai_epi16(_mm256_slli_epi16(even, 8), 8));
+            let odd_fits = _mm256_cmpeq_epi16(odd, _mm256_srai_epi16(_mm256_slli_epi16(odd, 8), 8));
+            let overflow = _mm256_or_si256(
+                _mm256_andnot_si256(even_fits, low_mask),
+                _mm256_andnot_si256(odd_fits, _mm256_slli_epi16(low_mask, 8)),
+            );
+            (result, overflow)
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for u8 {
@@ -49,6 +107,62 @@ unsafe impl SimdIntegerElement for u8 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi8(left, right);
+            let carry = _mm256_or_si256(
+                _mm256_and_si256(left, right),
+                _mm256_andnot_si256(result, _mm256_or_si256(left, right)),
+            );
+            (result, carry)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi8(left, right);
+            let borrow = _mm256_or_si256(
+                _mm256_andnot_si256(left, right),
+                _mm256_andnot_si256(_mm256_xor_si256(left, right), result),
+            );
+            (result, borrow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let zero = _mm256_setzero_si256();
+            let low_mask = _mm256_set1_epi16(0x00ff);
+            let even = _mm256_mullo_epi16(
+                _mm256_and_si256(left, low_mask),
+                _mm256_and_si256(right, low_mask),
+            );
+            let odd = _mm256_mullo_epi16(_mm256_srli_epi16(left, 8), _mm256_srli_epi16(right, 8));
+            let result =
+                _mm256_or_si256(_mm256_and_si256(even, low_mask), _mm256_slli_epi16(odd, 8));
+
+            let even_fits = _mm256_cmpeq_epi16(_mm256_srli_epi16(even, 8), zero);
+            let odd_fits = _mm256_cmpeq_epi16(_mm256_srli_epi16(odd, 8), zero);
+            let overflow = _mm256_or_si256(
+                _mm256_andnot_si256(even_fits, low_mask),
+                _mm256_andnot_si256(odd_fits, _mm256_slli_epi16(low_mask, 8)),
+            );
+            (result, overflow)
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for i16 {
@@ -74,6 +188,49 @@ unsafe impl SimdIntegerElement for i16 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi16(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, result),
+                _mm256_xor_si256(right, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi16(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, right),
+                _mm256_xor_si256(left, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_mullo_epi16(left, right);
+            let high = _mm256_mulhi_epi16(left, right);
+            let fits = _mm256_cmpeq_epi16(high, _mm256_srai_epi16(result, 15));
+            (result, <Self as SimdIntegerElement>::not(fits))
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for u16 {
@@ -99,6 +256,49 @@ unsafe impl SimdIntegerElement for u16 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi16(left, right);
+            let carry = _mm256_or_si256(
+                _mm256_and_si256(left, right),
+                _mm256_andnot_si256(result, _mm256_or_si256(left, right)),
+            );
+            (result, carry)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi16(left, right);
+            let borrow = _mm256_or_si256(
+                _mm256_andnot_si256(left, right),
+                _mm256_andnot_si256(_mm256_xor_si256(left, right), result),
+            );
+            (result, borrow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_mullo_epi16(left, right);
+            let high = _mm256_mulhi_epu16(left, right);
+            let fits = _mm256_cmpeq_epi16(high, _mm256_setzero_si256());
+            (result, <Self as SimdIntegerElement>::not(fits))
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for i32 {
@@ -124,6 +324,51 @@ unsafe impl SimdIntegerElement for i32 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi32(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, result),
+                _mm256_xor_si256(right, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi32(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, right),
+                _mm256_xor_si256(left, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_mullo_epi32(left, right);
+            let even = _mm256_mul_epi32(left, right);
+            let odd = _mm256_mul_epi32(_mm256_srli_epi64(left, 32), _mm256_srli_epi64(right, 32));
+            let high = _mm256_blend_epi32(_mm256_srli_epi64(even, 32), odd, 0b10101010);
+            let fits = _mm256_cmpeq_epi32(high, _mm256_srai_epi32(result, 31));
+            (result, <Self as SimdIntegerElement>::not(fits))
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for u32 {
@@ -149,6 +394,51 @@ unsafe impl SimdIntegerElement for u32 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi32(left, right);
+            let carry = _mm256_or_si256(
+                _mm256_and_si256(left, right),
+                _mm256_andnot_si256(result, _mm256_or_si256(left, right)),
+            );
+            (result, carry)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi32(left, right);
+            let borrow = _mm256_or_si256(
+                _mm256_andnot_si256(left, right),
+                _mm256_andnot_si256(_mm256_xor_si256(left, right), result),
+            );
+            (result, borrow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_mullo_epi32(left, right);
+            let even = _mm256_mul_epu32(left, right);
+            let odd = _mm256_mul_epu32(_mm256_srli_epi64(left, 32), _mm256_srli_epi64(right, 32));
+            let high = _mm256_blend_epi32(_mm256_srli_epi64(even, 32), odd, 0b10101010);
+            let fits = _mm256_cmpeq_epi32(high, _mm256_setzero_si256());
+            (result, <Self as SimdIntegerElement>::not(fits))
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for i64 {
@@ -174,6 +464,63 @@ unsafe impl SimdIntegerElement for i64 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi64(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, result),
+                _mm256_xor_si256(right, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi64(left, right);
+            let overflow = _mm256_and_si256(
+                _mm256_xor_si256(left, right),
+                _mm256_xor_si256(left, result),
+            );
+            (result, overflow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        // AVX2 has no 64-bit high multiplication, so overflow is detected per lane.
+        let mut left_buff = [0i64; Self::VECTOR_LEN];
+        let mut right_buff = [0i64; Self::VECTOR_LEN];
+        let mut overflow_buff = [0i64; Self::VECTOR_LEN];
+
+        unsafe {
+            Self::store(left_buff.as_mut_ptr(), left);
+            Self::store(right_buff.as_mut_ptr(), right);
+
+            for i in 0..Self::VECTOR_LEN {
+                let (result, overflowed) = left_buff[i].overflowing_mul(right_buff[i]);
+                left_buff[i] = result;
+                overflow_buff[i] = -(overflowed as i64);
+            }
+
+            (
+                Self::load(left_buff.as_ptr()),
+                _mm256_loadu_si256(overflow_buff.as_ptr() as _),
+            )
+        }
+    }
 }
 
 unsafe impl SimdIntegerElement for u64 {
@@ -199,4 +546,61 @@ unsafe impl SimdIntegerElement for u64 {
     unsafe fn xor(left: Self::Vector, right: Self::Vector) -> Self::Vector {
         unsafe { _mm256_xor_si256(left, right) }
     }
+
+    #[inline(always)]
+    unsafe fn overflowing_add(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_add_epi64(left, right);
+            let carry = _mm256_or_si256(
+                _mm256_and_si256(left, right),
+                _mm256_andnot_si256(result, _mm256_or_si256(left, right)),
+            );
+            (result, carry)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_sub(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        unsafe {
+            let result = _mm256_sub_epi64(left, right);
+            let borrow = _mm256_or_si256(
+                _mm256_andnot_si256(left, right),
+                _mm256_andnot_si256(_mm256_xor_si256(left, right), result),
+            );
+            (result, borrow)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn overflowing_mul(
+        left: Self::Vector,
+        right: Self::Vector,
+    ) -> (Self::Vector, Self::Vector) {
+        // AVX2 has no 64-bit high multiplication, so overflow is detected per lane.
+        let mut left_buff = [0u64; Self::VECTOR_LEN];
+        let mut right_buff = [0u64; Self::VECTOR_LEN];
+        let mut overflow_buff = [0i64; Self::VECTOR_LEN];
+
+        unsafe {
+            Self::store(left_buff.as_mut_ptr(), left);
+            Self::store(right_buff.as_mut_ptr(), right);
+
+            for i in 0..Self::VECTOR_LEN {
+                let (result, overflowed) = left_buff[i].overflowing_mul(right_buff[i]);
+                left_buff[i] = result;
+                overflow_buff[i] = -(overflowed as i64);
+            }
+
+            (
+                Self::load(left_buff.as_ptr()),
+                _mm256_loadu_si256(overflow_buff.as_ptr() as _),
+            )
+        }
+    }
 }
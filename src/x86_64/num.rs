@@ -14,18 +14,11 @@ unsafe impl SimdNumElement for i8 {
 
     #[inline(always)]
     unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector {
-        let mut left_buff = [0i8; Self::VECTOR_LEN];
-        let mut right_buff = [0i8; Self::VECTOR_LEN];
-
         unsafe {
-            Self::store(left_buff.as_mut_ptr(), left);
-            Self::store(right_buff.as_mut_ptr(), right);
-
-            for i in 0..Self::VECTOR_LEN {
-                left_buff[i] *= right_buff[i];
-            }
-
-            Self::load(left_buff.as_ptr())
+            let low_mask = _mm256_set1_epi16(0x00ff);
+            let even = _mm256_mullo_epi16(left, right);
+            let odd = _mm256_mullo_epi16(_mm256_srli_epi16(left, 8), _mm256_srli_epi16(right, 8));
+            _mm256_or_si256(_mm256_and_si256(even, low_mask), _mm256_slli_epi16(odd, 8))
         }
     }
 
@@ -40,13 +33,43 @@ unsafe impl SimdNumElement for i8 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0i8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, i8::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0i8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, i8::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 1));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 2));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_shuffle_epi8(x, _mm256_set1_epi8(15));
+            _mm256_add_epi8(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for u8 {
@@ -62,18 +85,11 @@ unsafe impl SimdNumElement for u8 {
 
     #[inline(always)]
     unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector {
-        let mut left_buff = [0u8; Self::VECTOR_LEN];
-        let mut right_buff = [0u8; Self::VECTOR_LEN];
-
         unsafe {
-            Self::store(left_buff.as_mut_ptr(), left);
-            Self::store(right_buff.as_mut_ptr(), right);
-
-            for i in 0..Self::VECTOR_LEN {
-                left_buff[i] *= right_buff[i];
-            }
-
-            Self::load(left_buff.as_ptr())
+            let low_mask = _mm256_set1_epi16(0x00ff);
+            let even = _mm256_mullo_epi16(left, right);
+            let odd = _mm256_mullo_epi16(_mm256_srli_epi16(left, 8), _mm256_srli_epi16(right, 8));
+            _mm256_or_si256(_mm256_and_si256(even, low_mask), _mm256_slli_epi16(odd, 8))
         }
     }
 
@@ -88,13 +104,43 @@ unsafe impl SimdNumElement for u8 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0u8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, u8::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0u8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, u8::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 1));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 2));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi8(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_shuffle_epi8(x, _mm256_set1_epi8(15));
+            _mm256_add_epi8(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for i16 {
@@ -124,13 +170,45 @@ unsafe impl SimdNumElement for i16 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0i16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, i16::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0i16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, i16::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 2));
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_unpackhi_epi64(
+                _mm256_shufflehi_epi16(x, 0xff),
+                _mm256_shufflehi_epi16(x, 0xff),
+            );
+            _mm256_add_epi16(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for u16 {
@@ -160,13 +238,45 @@ unsafe impl SimdNumElement for u16 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0u16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, u16::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0u16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, u16::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 2));
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi16(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_unpackhi_epi64(
+                _mm256_shufflehi_epi16(x, 0xff),
+                _mm256_shufflehi_epi16(x, 0xff),
+            );
+            _mm256_add_epi16(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for i32 {
@@ -196,13 +306,41 @@ unsafe impl SimdNumElement for i32 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0i32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, i32::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0i32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, i32::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi32(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi32(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_shuffle_epi32(x, 0xff);
+            _mm256_add_epi32(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for u32 {
@@ -232,13 +370,41 @@ unsafe impl SimdNumElement for u32 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0u32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, u32::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0u32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, u32::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi32(x, _mm256_bslli_epi128(x, 4));
+            let x = _mm256_add_epi32(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_shuffle_epi32(x, 0xff);
+            _mm256_add_epi32(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for i64 {
@@ -254,18 +420,13 @@ unsafe impl SimdNumElement for i64 {
 
     #[inline(always)]
     unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector {
-        let mut left_buff = [0i64; 4];
-        let mut right_buff = [0i64; 4];
-
         unsafe {
-            Self::store(left_buff.as_mut_ptr(), left);
-            Self::store(right_buff.as_mut_ptr(), right);
-
-            for i in 0..4 {
-                left_buff[i] *= right_buff[i];
-            }
-
-            Self::load(left_buff.as_ptr())
+            let low = _mm256_mul_epu32(left, right);
+            let cross = _mm256_add_epi64(
+                _mm256_mul_epu32(_mm256_srli_epi64(left, 32), right),
+                _mm256_mul_epu32(left, _mm256_srli_epi64(right, 32)),
+            );
+            _mm256_add_epi64(low, _mm256_slli_epi64(cross, 32))
         }
     }
 
@@ -280,13 +441,40 @@ unsafe impl SimdNumElement for i64 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0i64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, i64::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0i64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, i64::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi64(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_unpackhi_epi64(x, x);
+            _mm256_add_epi64(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for u64 {
@@ -302,18 +490,13 @@ unsafe impl SimdNumElement for u64 {
 
     #[inline(always)]
     unsafe fn mul(left: Self::Vector, right: Self::Vector) -> Self::Vector {
-        let mut left_buff = [0u64; 4];
-        let mut right_buff = [0u64; 4];
-
         unsafe {
-            Self::store(left_buff.as_mut_ptr(), left);
-            Self::store(right_buff.as_mut_ptr(), right);
-
-            for i in 0..4 {
-                left_buff[i] *= right_buff[i];
-            }
-
-            Self::load(left_buff.as_ptr())
+            let low = _mm256_mul_epu32(left, right);
+            let cross = _mm256_add_epi64(
+                _mm256_mul_epu32(_mm256_srli_epi64(left, 32), right),
+                _mm256_mul_epu32(left, _mm256_srli_epi64(right, 32)),
+            );
+            _mm256_add_epi64(low, _mm256_slli_epi64(cross, 32))
         }
     }
 
@@ -328,13 +511,40 @@ unsafe impl SimdNumElement for u64 {
 
             for i in 0..Self::VECTOR_LEN {
                 if right_buff[i] != 0 {
-                    left_buff[i] /= right_buff[i];
+                    left_buff[i] = left_buff[i].wrapping_div(right_buff[i]);
                 }
             }
 
             Self::load(left_buff.as_ptr())
         }
     }
+
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        let mut buff = [0u64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(0, u64::wrapping_add)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        let mut buff = [0u64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(1, u64::wrapping_mul)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_epi64(x, _mm256_bslli_epi128(x, 8));
+            let last = _mm256_unpackhi_epi64(x, x);
+            _mm256_add_epi64(x, _mm256_permute2x128_si256(last, last, 0x08))
+        }
+    }
 }
 
 unsafe impl SimdNumElement for f32 {
@@ -358,6 +568,32 @@ unsafe impl SimdNumElement for f32 {
         unsafe { _mm256_div_ps(left, right) }
     }
 
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        unsafe { super::reduce_ps(x, |left, right| _mm256_add_ps(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        unsafe { super::reduce_ps(x, |left, right| _mm256_mul_ps(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_ps(
+                x,
+                _mm256_castsi256_ps(_mm256_bslli_epi128(_mm256_castps_si256(x), 4)),
+            );
+            let x = _mm256_add_ps(
+                x,
+                _mm256_castsi256_ps(_mm256_bslli_epi128(_mm256_castps_si256(x), 8)),
+            );
+            let last = _mm256_permute_ps(x, 0xff);
+            _mm256_add_ps(x, _mm256_permute2f128_ps(last, last, 0x08))
+        }
+    }
+
     #[inline(always)]
     unsafe fn fma(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self::Vector {
         unsafe { _mm256_fmadd_ps(a, b, c) }
@@ -385,6 +621,28 @@ unsafe impl SimdNumElement for f64 {
         unsafe { _mm256_div_pd(left, right) }
     }
 
+    #[inline(always)]
+    unsafe fn reduce_add(x: Self::Vector) -> Self {
+        unsafe { super::reduce_pd(x, |left, right| _mm256_add_pd(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_mul(x: Self::Vector) -> Self {
+        unsafe { super::reduce_pd(x, |left, right| _mm256_mul_pd(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let x = _mm256_add_pd(
+                x,
+                _mm256_castsi256_pd(_mm256_bslli_epi128(_mm256_castpd_si256(x), 8)),
+            );
+            let last = _mm256_permute_pd(x, 0b1111);
+            _mm256_add_pd(x, _mm256_permute2f128_pd(last, last, 0x08))
+        }
+    }
+
     #[inline(always)]
     unsafe fn fma(a: Self::Vector, b: Self::Vector, c: Self::Vector) -> Self::Vector {
         unsafe { _mm256_fmadd_pd(a, b, c) }
@@ -0,0 +1,502 @@
+use crate::*;
+use core::arch::x86_64::*;
+
+unsafe impl SimdOrdElement for i8 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epi8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epi8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpgt_epi8(right, left) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_si256(_mm256_cmpgt_epi8(left, right), _mm256_set1_epi8(-1)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0i8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i8::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0i8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i8::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for u8 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epu8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epu8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi8(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // AVX2 has only signed comparison, so `left < right` is `!(max(left, right) == left)`.
+            let greater_or_equal = _mm256_cmpeq_epi8(_mm256_max_epu8(left, right), left);
+            _mm256_xor_si256(greater_or_equal, _mm256_set1_epi8(-1))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi8(_mm256_min_epu8(left, right), left) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0u8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u8::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0u8; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u8::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for i16 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epi16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epi16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpgt_epi16(right, left) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_si256(_mm256_cmpgt_epi16(left, right), _mm256_set1_epi8(-1)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0i16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i16::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0i16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i16::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for u16 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epu16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epu16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi16(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // AVX2 has only signed comparison, so `left < right` is `!(max(left, right) == left)`.
+            let greater_or_equal = _mm256_cmpeq_epi16(_mm256_max_epu16(left, right), left);
+            _mm256_xor_si256(greater_or_equal, _mm256_set1_epi8(-1))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi16(_mm256_min_epu16(left, right), left) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0u16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u16::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0u16; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u16::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for i32 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epi32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epi32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpgt_epi32(right, left) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_si256(_mm256_cmpgt_epi32(left, right), _mm256_set1_epi8(-1)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0i32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i32::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0i32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i32::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for u32 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_min_epu32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_epu32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi32(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // AVX2 has only signed comparison, so `left < right` is `!(max(left, right) == left)`.
+            let greater_or_equal = _mm256_cmpeq_epi32(_mm256_max_epu32(left, right), left);
+            _mm256_xor_si256(greater_or_equal, _mm256_set1_epi8(-1))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi32(_mm256_min_epu32(left, right), left) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0u32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u32::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0u32; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u32::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for i64 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let greater = _mm256_cmpgt_epi64(left, right);
+            _mm256_blendv_epi8(left, right, greater)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let greater = _mm256_cmpgt_epi64(left, right);
+            _mm256_blendv_epi8(right, left, greater)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi64(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpgt_epi64(right, left) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_si256(_mm256_cmpgt_epi64(left, right), _mm256_set1_epi8(-1)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0i64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i64::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0i64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(i64::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for u64 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            let greater =
+                _mm256_cmpgt_epi64(_mm256_xor_si256(left, sign), _mm256_xor_si256(right, sign));
+            _mm256_blendv_epi8(left, right, greater)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            let greater =
+                _mm256_cmpgt_epi64(_mm256_xor_si256(left, sign), _mm256_xor_si256(right, sign));
+            _mm256_blendv_epi8(right, left, greater)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmpeq_epi64(left, right) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            _mm256_cmpgt_epi64(_mm256_xor_si256(right, sign), _mm256_xor_si256(left, sign))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            let greater =
+                _mm256_cmpgt_epi64(_mm256_xor_si256(left, sign), _mm256_xor_si256(right, sign));
+            _mm256_xor_si256(greater, _mm256_set1_epi8(-1))
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        let mut buff = [0u64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u64::MAX, Ord::min)
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        let mut buff = [0u64; Self::VECTOR_LEN];
+        unsafe {
+            Self::store(buff.as_mut_ptr(), x);
+        }
+        buff.into_iter().fold(u64::MIN, Ord::max)
+    }
+}
+
+unsafe impl SimdOrdElement for f32 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // `_mm256_min_ps` returns `right` if either is NaN.
+            let min = _mm256_min_ps(left, right);
+            let right_nan = _mm256_cmp_ps(right, right, _CMP_UNORD_Q);
+            _mm256_blendv_ps(min, left, right_nan)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // `_mm256_max_ps` returns `right` if either is NaN.
+            let max = _mm256_max_ps(left, right);
+            let right_nan = _mm256_cmp_ps(right, right, _CMP_UNORD_Q);
+            _mm256_blendv_ps(max, left, right_nan)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_ps(left, right, _CMP_EQ_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_ps(left, right, _CMP_LT_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_ps(left, right, _CMP_LE_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        unsafe { super::reduce_ps(x, |left, right| <Self as SimdOrdElement>::min(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        unsafe { super::reduce_ps(x, |left, right| <Self as SimdOrdElement>::max(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn clamp(x: Self::Vector, min: Self::Vector, max: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_ps(min, _mm256_min_ps(max, x)) }
+    }
+}
+
+unsafe impl SimdOrdElement for f64 {
+    #[inline(always)]
+    unsafe fn min(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // `_mm256_min_pd` returns `right` if either is NaN.
+            let min = _mm256_min_pd(left, right);
+            let right_nan = _mm256_cmp_pd(right, right, _CMP_UNORD_Q);
+            _mm256_blendv_pd(min, left, right_nan)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn max(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe {
+            // `_mm256_max_pd` returns `right` if either is NaN.
+            let max = _mm256_max_pd(left, right);
+            let right_nan = _mm256_cmp_pd(right, right, _CMP_UNORD_Q);
+            _mm256_blendv_pd(max, left, right_nan)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn eq(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_pd(left, right, _CMP_EQ_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn lt(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_pd(left, right, _CMP_LT_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn le(left: Self::Vector, right: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_cmp_pd(left, right, _CMP_LE_OQ) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_min(x: Self::Vector) -> Self {
+        unsafe { super::reduce_pd(x, |left, right| <Self as SimdOrdElement>::min(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn reduce_max(x: Self::Vector) -> Self {
+        unsafe { super::reduce_pd(x, |left, right| <Self as SimdOrdElement>::max(left, right)) }
+    }
+
+    #[inline(always)]
+    unsafe fn clamp(x: Self::Vector, min: Self::Vector, max: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_max_pd(min, _mm256_min_pd(max, x)) }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::*;
+use core::arch::x86_64::*;
+
+unsafe impl SimdSignedElement for i8 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_epi8(_mm256_setzero_si256(), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_abs_epi8(x) }
+    }
+}
+
+unsafe impl SimdSignedElement for i16 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_epi16(_mm256_setzero_si256(), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_abs_epi16(x) }
+    }
+}
+
+unsafe impl SimdSignedElement for i32 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_epi32(_mm256_setzero_si256(), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_abs_epi32(x) }
+    }
+}
+
+unsafe impl SimdSignedElement for i64 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_sub_epi64(_mm256_setzero_si256(), x) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe {
+            let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), x);
+            _mm256_sub_epi64(_mm256_xor_si256(x, sign), sign)
+        }
+    }
+}
+
+unsafe impl SimdSignedElement for f32 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_ps(x, Self::set(-0.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_andnot_ps(Self::set(-0.0), x) }
+    }
+}
+
+unsafe impl SimdSignedElement for f64 {
+    #[inline(always)]
+    unsafe fn neg(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_xor_pd(x, Self::set(-0.0)) }
+    }
+
+    #[inline(always)]
+    unsafe fn abs(x: Self::Vector) -> Self::Vector {
+        unsafe { _mm256_andnot_pd(Self::set(-0.0), x) }
+    }
+}
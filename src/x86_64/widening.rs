@@ -0,0 +1,41 @@
+use crate::*;
+use core::arch::x86_64::*;
+
+unsafe impl SimdWideningSum<u64> for u8 {
+    #[inline(always)]
+    unsafe fn widening_sum(x: Self::Vector) -> __m256i {
+        unsafe { _mm256_sad_epu8(x, _mm256_setzero_si256()) }
+    }
+}
+
+unsafe impl SimdWideningDot<i8, i32> for u8 {
+    #[inline(always)]
+    unsafe fn widening_dot(left: Self::Vector, right: __m256i) -> __m256i {
+        // `_mm256_maddubs_epi16` saturates when adding two products such as `255 * 127`,
+        // so the bytes are widened to `i16` first.
+        unsafe {
+            let left_low = _mm256_cvtepu8_epi16(_mm256_castsi256_si128(left));
+            let left_high = _mm256_cvtepu8_epi16(_mm256_extracti128_si256(left, 1));
+            let right_low = _mm256_cvtepi8_epi16(_mm256_castsi256_si128(right));
+            let right_high = _mm256_cvtepi8_epi16(_mm256_extracti128_si256(right, 1));
+            _mm256_add_epi32(
+                _mm256_madd_epi16(left_low, right_low),
+                _mm256_madd_epi16(left_high, right_high),
+            )
+        }
+    }
+}
+
+unsafe impl SimdWideningSum<i32> for i16 {
+    #[inline(always)]
+    unsafe fn widening_sum(x: Self::Vector) -> __m256i {
+        unsafe { _mm256_madd_epi16(x, _mm256_set1_epi16(1)) }
+    }
+}
+
+unsafe impl SimdWideningDot<i16, i32> for i16 {
+    #[inline(always)]
+    unsafe fn widening_dot(left: Self::Vector, right: __m256i) -> __m256i {
+        unsafe { _mm256_madd_epi16(left, right) }
+    }
+}